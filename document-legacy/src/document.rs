@@ -148,7 +148,7 @@ impl Document {
 			.decendants(&self.metadata)
 			.filter(|&layer| self.layer_visible(layer))
 			.filter_map(|layer| self.metadata.click_target(layer).map(|targets| (layer, targets)))
-			.filter(move |(layer, target)| target.iter().any(|target: &ClickTarget| target.intersect_point(point, self.metadata.transform_to_document(*layer))))
+			.filter(move |(layer, targets)| ClickTarget::targets_intersect_point(targets, point, self.metadata.transform_to_document(*layer)))
 			.map(|(layer, _)| layer)
 	}
 
@@ -8,7 +8,7 @@ use crate::messages::tool::utility_types::HintData;
 use document_legacy::LayerId;
 use graph_craft::document::NodeId;
 use graphene_core::raster::color::Color;
-use graphene_core::text::Font;
+use graphene_core::text::{Font, TextAlignment};
 
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,11 @@ pub enum FrontendMessage {
 		line_width: Option<f64>,
 		#[serde(rename = "fontSize")]
 		font_size: f64,
+		#[serde(rename = "lineHeightRatio")]
+		line_height_ratio: f64,
+		#[serde(rename = "letterSpacing")]
+		letter_spacing: f64,
+		alignment: TextAlignment,
 		color: Color,
 		url: String,
 		transform: [f64; 6],
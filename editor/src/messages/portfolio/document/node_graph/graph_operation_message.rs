@@ -5,7 +5,7 @@ use document_legacy::document_metadata::LayerNodeIdentifier;
 use graph_craft::document::DocumentNode;
 use graph_craft::document::NodeId;
 use graphene_core::raster::ImageFrame;
-use graphene_core::text::Font;
+use graphene_core::text::{Font, TypesettingConfig};
 use graphene_core::uuid::ManipulatorGroupId;
 use graphene_core::vector::brush_stroke::BrushStroke;
 use graphene_core::vector::style::{Fill, Stroke};
@@ -85,7 +85,7 @@ pub enum GraphOperationMessage {
 		id: NodeId,
 		text: String,
 		font: Font,
-		size: f64,
+		typesetting: TypesettingConfig,
 		parent: LayerNodeIdentifier,
 		insert_index: isize,
 	},
@@ -8,10 +8,11 @@ use document_legacy::{LayerId, Operation};
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{generate_uuid, DocumentNode, NodeId, NodeInput, NodeNetwork, NodeOutput};
 use graphene_core::raster::ImageFrame;
-use graphene_core::text::Font;
+use graphene_core::text::{Font, TypesettingConfig};
 use graphene_core::uuid::ManipulatorGroupId;
 use graphene_core::vector::brush_stroke::BrushStroke;
 use graphene_core::vector::style::{Fill, FillType, Stroke};
+use graphene_core::vector::VectorData;
 use graphene_core::{Artboard, Color};
 use transform_utils::LayerBounds;
 
@@ -208,13 +209,20 @@ impl<'a> ModifyInputsContext<'a> {
 		self.responses.add(NodeGraphMessage::SendGraph { should_rerender: true });
 	}
 
-	fn insert_text(&mut self, text: String, font: Font, size: f64, layer: NodeId) {
+	fn insert_text(&mut self, text: String, font: Font, typesetting: TypesettingConfig, layer: NodeId) {
 		let text = resolve_document_node_type("Text").expect("Text node does not exist").to_document_node(
 			[
 				NodeInput::Network(graph_craft::concrete!(graphene_std::wasm_application_io::WasmEditorApi)),
 				NodeInput::value(TaggedValue::String(text), false),
 				NodeInput::value(TaggedValue::Font(font), false),
-				NodeInput::value(TaggedValue::F64(size), false),
+				NodeInput::value(TaggedValue::F64(typesetting.font_size), false),
+				NodeInput::value(TaggedValue::F64(typesetting.line_height_ratio), false),
+				NodeInput::value(TaggedValue::F64(typesetting.letter_spacing), false),
+				NodeInput::value(TaggedValue::F64(typesetting.paragraph_spacing), false),
+				NodeInput::value(TaggedValue::TextAlignment(typesetting.alignment), false),
+				NodeInput::value(TaggedValue::Bool(typesetting.kerning), false),
+				NodeInput::value(TaggedValue::Bool(typesetting.ligatures), false),
+				NodeInput::value(TaggedValue::String(typesetting.features), false),
 			],
 			Default::default(),
 		);
@@ -447,8 +455,8 @@ impl<'a> ModifyInputsContext<'a> {
 		let mut empty = false;
 
 		self.modify_inputs("Shape", false, |inputs, _node_id, _metadata| {
-			let [subpaths, mirror_angle_groups] = inputs.as_mut_slice() else {
-				panic!("Shape does not have subpath and mirror angle inputs");
+			let [subpaths, mirror_angle_groups, attributes] = inputs.as_mut_slice() else {
+				panic!("Shape does not have subpath, mirror angle and attribute inputs");
 			};
 
 			let NodeInput::Value {
@@ -465,10 +473,27 @@ impl<'a> ModifyInputsContext<'a> {
 			else {
 				return;
 			};
+			let NodeInput::Value {
+				tagged_value: TaggedValue::VectorAttributes(attributes),
+				..
+			} = attributes
+			else {
+				return;
+			};
 
 			[old_bounds_min, old_bounds_max] = transform_utils::nonzero_subpath_bounds(subpaths);
 
-			transform_utils::VectorModificationState { subpaths, mirror_angle_groups }.modify(modification);
+			// The shape is edited as the vector data that the Shape node generates, so its attributes stay in sync with the manipulator groups
+			let mut vector_data = VectorData {
+				subpaths: std::mem::take(subpaths),
+				mirror_angle: std::mem::take(mirror_angle_groups),
+				attributes: std::mem::take(attributes),
+				..VectorData::empty()
+			};
+			transform_utils::VectorModificationState { vector_data: &mut vector_data }.modify(modification);
+			*subpaths = vector_data.subpaths;
+			*mirror_angle_groups = vector_data.mirror_angle;
+			*attributes = vector_data.attributes;
 			empty = !subpaths.iter().any(|subpath| !subpath.is_empty());
 
 			[new_bounds_min, new_bounds_max] = transform_utils::nonzero_subpath_bounds(subpaths);
@@ -698,13 +723,13 @@ impl MessageHandler<GraphOperationMessage, (&mut Document, &mut NodeGraphMessage
 				id,
 				text,
 				font,
-				size,
+				typesetting,
 				parent,
 				insert_index,
 			} => {
 				let mut modify_inputs = ModifyInputsContext::new(document, node_graph, responses);
 				if let Some(layer) = modify_inputs.create_layer_with_insert_index(id, insert_index, parent) {
-					modify_inputs.insert_text(text, font, size, layer);
+					modify_inputs.insert_text(text, font, typesetting, layer);
 				}
 				document.load_network_structure();
 			}
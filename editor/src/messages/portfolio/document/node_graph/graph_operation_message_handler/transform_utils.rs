@@ -5,7 +5,7 @@ use document_legacy::document::Document;
 use document_legacy::document_metadata::LayerNodeIdentifier;
 use graph_craft::document::{value::TaggedValue, NodeInput};
 use graphene_core::uuid::ManipulatorGroupId;
-use graphene_core::vector::{ManipulatorPointId, SelectedType};
+use graphene_core::vector::{ManipulatorPointId, SelectedType, VectorData};
 
 use glam::{DAffine2, DVec2};
 
@@ -199,46 +199,49 @@ pub fn nonzero_subpath_bounds(subpaths: &[Subpath<ManipulatorGroupId>]) -> [DVec
 	clamp_bounds(bounds_min, bounds_max)
 }
 
+/// Applies edits to the subpaths of a shape through [VectorData], which keeps the attributes and handle mirroring of its manipulator groups in sync with them.
 pub struct VectorModificationState<'a> {
-	pub subpaths: &'a mut Vec<Subpath<ManipulatorGroupId>>,
-	pub mirror_angle_groups: &'a mut Vec<ManipulatorGroupId>,
+	pub vector_data: &'a mut VectorData,
 }
 impl<'a> VectorModificationState<'a> {
 	fn insert_start(&mut self, subpath_index: usize, manipulator_group: ManipulatorGroup<ManipulatorGroupId>) {
-		self.subpaths[subpath_index].insert_manipulator_group(0, manipulator_group)
+		self.vector_data.insert_manipulator_group(subpath_index, 0, manipulator_group, 0.)
 	}
 
 	fn insert_end(&mut self, subpath_index: usize, manipulator_group: ManipulatorGroup<ManipulatorGroupId>) {
-		let subpath = &mut self.subpaths[subpath_index];
-		subpath.insert_manipulator_group(subpath.len(), manipulator_group)
+		let index = self.vector_data.subpaths[subpath_index].len();
+		self.vector_data.insert_manipulator_group(subpath_index, index, manipulator_group, 1.)
 	}
 
 	fn insert(&mut self, manipulator_group: ManipulatorGroup<ManipulatorGroupId>, after_id: ManipulatorGroupId) {
-		for subpath in self.subpaths.iter_mut() {
-			if let Some(index) = subpath.manipulator_index_from_id(after_id) {
-				subpath.insert_manipulator_group(index + 1, manipulator_group);
-				break;
-			}
-		}
+		let subpaths = &self.vector_data.subpaths;
+		let Some((subpath_index, index)) = subpaths
+			.iter()
+			.enumerate()
+			.find_map(|(subpath_index, subpath)| subpath.manipulator_index_from_id(after_id).map(|index| (subpath_index, index + 1)))
+		else {
+			return;
+		};
+
+		// The attribute values of the new group are taken from where its anchor lies along the segment that it splits
+		let subpath = &subpaths[subpath_index];
+		let groups = subpath.manipulator_groups();
+		let next = groups.get(index).or_else(|| subpath.closed().then(|| groups.first()).flatten());
+		let t = next.map_or(1., |next| groups[index - 1].to_bezier(next).project(manipulator_group.anchor, None));
+		self.vector_data.insert_manipulator_group(subpath_index, index, manipulator_group, t);
 	}
 
 	fn remove_group(&mut self, id: ManipulatorGroupId) {
-		for subpath in self.subpaths.iter_mut() {
-			if let Some(index) = subpath.manipulator_index_from_id(id) {
-				subpath.remove_manipulator_group(index);
-				break;
-			}
-		}
+		self.vector_data.remove_manipulator_group(id);
 	}
 
 	fn remove_point(&mut self, point: ManipulatorPointId) {
-		for subpath in self.subpaths.iter_mut() {
-			if point.manipulator_type == SelectedType::Anchor {
-				if let Some(index) = subpath.manipulator_index_from_id(point.group) {
-					subpath.remove_manipulator_group(index);
-					break;
-				}
-			} else if let Some(group) = subpath.manipulator_mut_from_id(point.group) {
+		if point.manipulator_type == SelectedType::Anchor {
+			self.vector_data.remove_manipulator_group(point.group);
+			return;
+		}
+		for subpath in self.vector_data.subpaths.iter_mut() {
+			if let Some(group) = subpath.manipulator_mut_from_id(point.group) {
 				if point.manipulator_type == SelectedType::InHandle {
 					group.in_handle = None;
 				} else if point.manipulator_type == SelectedType::OutHandle {
@@ -250,30 +253,30 @@ impl<'a> VectorModificationState<'a> {
 
 	fn set_mirror(&mut self, id: ManipulatorGroupId, mirror_angle: bool) {
 		if !mirror_angle {
-			self.mirror_angle_groups.retain(|&mirrored_id| mirrored_id != id);
-		} else if !self.mirror_angle_groups.contains(&id) {
-			self.mirror_angle_groups.push(id);
+			self.vector_data.mirror_angle.retain(|&mirrored_id| mirrored_id != id);
+		} else if !self.vector_data.mirror_angle.contains(&id) {
+			self.vector_data.mirror_angle.push(id);
 		}
 	}
 
 	fn toggle_mirror(&mut self, id: ManipulatorGroupId) {
-		if self.mirror_angle_groups.contains(&id) {
-			self.mirror_angle_groups.retain(|&mirrored_id| mirrored_id != id);
+		if self.vector_data.mirror_angle.contains(&id) {
+			self.vector_data.mirror_angle.retain(|&mirrored_id| mirrored_id != id);
 		} else {
-			self.mirror_angle_groups.push(id);
+			self.vector_data.mirror_angle.push(id);
 		}
 	}
 
 	fn set_position(&mut self, point: ManipulatorPointId, position: DVec2) {
 		assert!(position.is_finite(), "Point position should be finite");
-		for subpath in self.subpaths.iter_mut() {
+		for subpath in self.vector_data.subpaths.iter_mut() {
 			if let Some(manipulator) = subpath.manipulator_mut_from_id(point.group) {
 				match point.manipulator_type {
 					SelectedType::Anchor => manipulator.anchor = position,
 					SelectedType::InHandle => manipulator.in_handle = Some(position),
 					SelectedType::OutHandle => manipulator.out_handle = Some(position),
 				}
-				if point.manipulator_type != SelectedType::Anchor && self.mirror_angle_groups.contains(&point.group) {
+				if point.manipulator_type != SelectedType::Anchor && self.vector_data.mirror_angle.contains(&point.group) {
 					let reflect = |opposite: DVec2| {
 						(manipulator.anchor - position)
 							.try_normalize()
@@ -299,11 +302,14 @@ impl<'a> VectorModificationState<'a> {
 			VectorDataModification::AddManipulatorGroup { manipulator_group, after_id } => self.insert(manipulator_group, after_id),
 			VectorDataModification::RemoveManipulatorGroup { id } => self.remove_group(id),
 			VectorDataModification::RemoveManipulatorPoint { point } => self.remove_point(point),
-			VectorDataModification::SetClosed { index, closed } => self.subpaths[index].set_closed(closed),
+			VectorDataModification::SetClosed { index, closed } => self.vector_data.subpaths[index].set_closed(closed),
 			VectorDataModification::SetManipulatorHandleMirroring { id, mirror_angle } => self.set_mirror(id, mirror_angle),
 			VectorDataModification::SetManipulatorPosition { point, position } => self.set_position(point, position),
 			VectorDataModification::ToggleManipulatorHandleMirroring { id } => self.toggle_mirror(id),
-			VectorDataModification::UpdateSubpaths { subpaths } => *self.subpaths = subpaths,
+			VectorDataModification::UpdateSubpaths { subpaths } => {
+				self.vector_data.subpaths = subpaths;
+				self.vector_data.retain_existing_ids();
+			}
 		}
 	}
 }
@@ -11,7 +11,9 @@ use graph_craft::NodeIdentifier;
 #[cfg(feature = "gpu")]
 use graphene_core::application_io::SurfaceHandle;
 use graphene_core::raster::brush_cache::BrushCache;
-use graphene_core::raster::{BlendMode, Color, Image, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
+use graphene_core::raster::filters::EdgeDetectOperator;
+use graphene_core::raster::interpolation::InterpolationMode;
+use graphene_core::raster::{BlendMode, Color, FractalType, Image, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
 use graphene_core::text::Font;
 use graphene_core::transform::Footprint;
 use graphene_core::vector::VectorData;
@@ -2008,11 +2010,12 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Rectangle",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::RectangleGenerator<_, _>"),
+			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::RectangleGenerator<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::none(),
 				DocumentInputType::value("Size X", TaggedValue::F32(100.), false),
 				DocumentInputType::value("Size Y", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Corner Radius", TaggedValue::F32(0.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			properties: node_properties::rectangle_properties,
@@ -2021,11 +2024,12 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Regular Polygon",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::RegularPolygonGenerator<_, _>"),
+			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::RegularPolygonGenerator<_, _, _>"),
 			inputs: vec![
 				DocumentInputType::none(),
 				DocumentInputType::value("Sides", TaggedValue::U32(6), false),
 				DocumentInputType::value("Radius", TaggedValue::F32(50.), false),
+				DocumentInputType::value("Corner Radius", TaggedValue::F32(0.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			properties: node_properties::regular_polygon_properties,
@@ -2034,12 +2038,13 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Star",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::StarGenerator<_, _, _>"),
+			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::StarGenerator<_, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::none(),
 				DocumentInputType::value("Sides", TaggedValue::U32(5), false),
 				DocumentInputType::value("Radius", TaggedValue::F32(50.), false),
 				DocumentInputType::value("Inner Radius", TaggedValue::F32(25.), false),
+				DocumentInputType::value("Corner Radius", TaggedValue::F32(0.), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			properties: node_properties::star_properties,
@@ -2073,10 +2078,11 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Shape",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::PathGenerator<_>"),
+			identifier: NodeImplementation::proto("graphene_core::vector::generator_nodes::PathGenerator<_, _>"),
 			inputs: vec![
 				DocumentInputType::value("Path Data", TaggedValue::Subpaths(vec![]), false),
 				DocumentInputType::value("Mirror", TaggedValue::ManipulatorGroupIds(vec![]), false),
+				DocumentInputType::value("Attributes", TaggedValue::VectorAttributes(graphene_core::vector::VectorAttributes::new()), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			..Default::default()
@@ -2084,10 +2090,14 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Sample",
 			category: "Structural",
-			identifier: NodeImplementation::proto("graphene_std::raster::SampleNode<_>"),
+			identifier: NodeImplementation::proto("graphene_std::raster::SampleNode<_, _>"),
 			manual_composition: Some(concrete!(Footprint)),
-			inputs: vec![DocumentInputType::value("Raseter Data", TaggedValue::ImageFrame(ImageFrame::empty()), true)],
+			inputs: vec![
+				DocumentInputType::value("Raseter Data", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Interpolation", TaggedValue::InterpolationMode(InterpolationMode::Auto), false),
+			],
 			outputs: vec![DocumentOutputType::new("Raster", FrontendGraphDataType::Raster)],
+			properties: node_properties::sample_properties,
 			..Default::default()
 		},
 		DocumentNodeBlueprint {
@@ -2099,6 +2109,24 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			outputs: vec![DocumentOutputType::new("Raster", FrontendGraphDataType::Raster)],
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Noise Pattern",
+			category: "Generators",
+			identifier: NodeImplementation::proto("graphene_std::raster::NoisePatternNode<_, _, _, _, _, _, _>"),
+			manual_composition: Some(concrete!(Footprint)),
+			inputs: vec![
+				DocumentInputType::value("Dimensions", TaggedValue::DVec2(DVec2::new(512., 512.)), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+				DocumentInputType::value("Scale", TaggedValue::F64(64.), false),
+				DocumentInputType::value("Noise Type", TaggedValue::NoiseType(NoiseType::Perlin), false),
+				DocumentInputType::value("Fractal Type", TaggedValue::FractalType(FractalType::Fbm), false),
+				DocumentInputType::value("Octaves", TaggedValue::U32(4), false),
+				DocumentInputType::value("Tileable", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Raster", FrontendGraphDataType::Raster)],
+			properties: node_properties::noise_pattern_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Cull",
 			category: "Vector",
@@ -2111,15 +2139,44 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 		DocumentNodeBlueprint {
 			name: "Text",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::text::TextGenerator<_, _, _>"),
+			identifier: NodeImplementation::proto("graphene_core::text::TextGenerator<_, _, _, _, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::none(),
 				DocumentInputType::value("Text", TaggedValue::String("hello world".to_string()), false),
 				DocumentInputType::value("Font", TaggedValue::Font(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into())), false),
 				DocumentInputType::value("Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Line Height", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Letter Spacing", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Paragraph Spacing", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Alignment", TaggedValue::TextAlignment(graphene_core::text::TextAlignment::Left), false),
+				DocumentInputType::value("Kerning", TaggedValue::Bool(true), false),
+				DocumentInputType::value("Ligatures", TaggedValue::Bool(true), false),
+				DocumentInputType::value("OpenType Features", TaggedValue::String(String::new()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::text_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Text on Path",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::text::TextOnPathGenerator<_, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType {
+					name: "Editor Api",
+					data_type: FrontendGraphDataType::General,
+					default: NodeInput::Network(concrete!(WasmEditorApi)),
+				},
+				DocumentInputType::value("Text", TaggedValue::String("hello world".to_string()), false),
+				DocumentInputType::value("Font", TaggedValue::Font(Font::new(DEFAULT_FONT_FAMILY.into(), DEFAULT_FONT_STYLE.into())), false),
+				DocumentInputType::value("Size", TaggedValue::F64(24.), false),
+				DocumentInputType::value("Path", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Start Offset", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Alignment", TaggedValue::TextPathAlignment(graphene_core::text::TextPathAlignment::Start), false),
+				DocumentInputType::value("Overflow", TaggedValue::TextPathOverflow(graphene_core::text::TextPathOverflow::Hide), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
-			properties: node_properties::node_section_font,
+			properties: node_properties::text_on_path_properties,
 			..Default::default()
 		},
 		DocumentNodeBlueprint {
@@ -2178,10 +2235,24 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			outputs: vec![DocumentOutputType::new("Data", FrontendGraphDataType::Subpath)],
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Resample Image",
+			category: "Transform",
+			identifier: NodeImplementation::proto("graphene_core::raster::resample::ResampleImageNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Resolution", TaggedValue::DVec2(DVec2::ZERO), false),
+				DocumentInputType::value("Interpolation", TaggedValue::InterpolationMode(InterpolationMode::Auto), false),
+				DocumentInputType::value("Antialiasing", TaggedValue::Bool(true), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::resample_image_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Fill",
 			category: "Vector",
-			identifier: NodeImplementation::proto("graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>"),
+			identifier: NodeImplementation::proto("graphene_core::vector::SetFillNode<_, _, _, _, _, _, _, _>"),
 			inputs: vec![
 				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
 				DocumentInputType::value("Fill Type", TaggedValue::FillType(vector::style::FillType::None), false),
@@ -2191,6 +2262,7 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 				DocumentInputType::value("End", TaggedValue::DVec2(DVec2::new(1., 0.5)), false),
 				DocumentInputType::value("Transform", TaggedValue::DAffine2(DAffine2::IDENTITY), false),
 				DocumentInputType::value("Positions", TaggedValue::GradientPositions(vec![(0., Some(Color::BLACK)), (1., Some(Color::WHITE))]), false),
+				DocumentInputType::value("Fill Rule", TaggedValue::FillRule(vector::style::FillRule::NonZero), false),
 			],
 			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
 			properties: node_properties::fill_properties,
@@ -2250,6 +2322,29 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::circular_repeat_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Copy to Points",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::CopyToPointsNode<_, _, _, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Points", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType {
+					name: "Instance",
+					data_type: FrontendGraphDataType::General,
+					default: NodeInput::value(TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				},
+				DocumentInputType::value("Align to Tangent", TaggedValue::Bool(false), false),
+				DocumentInputType::value("Scale Attribute", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Rotation Attribute", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Random Scale Min", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Scale Max", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Random Rotation", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Random Seed", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Copies", FrontendGraphDataType::General)],
+			properties: node_properties::copy_to_points_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Resample Points",
 			category: "Vector",
@@ -2262,6 +2357,84 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::resample_points_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Roughen",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::RoughenNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Amplitude", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Frequency", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Seed", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::roughen_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Zig Zag",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::ZigZagNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Amplitude", TaggedValue::F64(5.), false),
+				DocumentInputType::value("Frequency", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::zig_zag_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Twist",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::TwistNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Angle", TaggedValue::F64(90.), false),
+				DocumentInputType::value("Frequency", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::twist_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Pucker & Bloat",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::PuckerBloatNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Amount", TaggedValue::F64(50.), false),
+				DocumentInputType::value("Frequency", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::pucker_bloat_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Simplify Path",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::SimplifyPathNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Tolerance", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Corner Angle", TaggedValue::F64(60.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::simplify_path_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Reduce Segments",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::ReduceSegmentsNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Tolerance", TaggedValue::F64(0.1), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::reduce_segments_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Spline from Points",
 			category: "Vector",
@@ -2271,6 +2444,123 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::no_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Dash Stroke",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::DashStrokeNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Outline Stroke",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::OutlineStrokeNode"),
+			inputs: vec![DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true)],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::no_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Offset Path",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::OffsetPathNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Distance", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Line Join", TaggedValue::LineJoin(graphene_core::vector::style::LineJoin::Miter), false),
+				DocumentInputType::value("Miter Limit", TaggedValue::F64(4.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::offset_path_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Boolean Operation",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::BooleanOperationNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Lower Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Upper Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Operation", TaggedValue::BooleanOperation(graphene_core::vector::BooleanOperation::Union), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::boolean_operation_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Morph",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::MorphNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Source", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Target", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Time", TaggedValue::F64(0.5), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::morph_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Round Corners",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::RoundCornersNode<_, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(10.), false),
+				DocumentInputType::value("Fillet Type", TaggedValue::FilletType(graphene_core::vector::FilletType::Circular), false),
+				DocumentInputType::value("Min Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Selection Attribute", TaggedValue::String(String::new()), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::round_corners_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Set Attribute",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::SetAttributeNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Domain", TaggedValue::AttributeDomain(graphene_core::vector::AttributeDomain::Point), false),
+				DocumentInputType::value("Value", TaggedValue::F64(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::set_attribute_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Read Attribute",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::ReadAttributeNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("Index", TaggedValue::U32(0), false),
+			],
+			outputs: vec![DocumentOutputType::new("Value", FrontendGraphDataType::Number)],
+			properties: node_properties::read_attribute_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Map Attribute",
+			category: "Vector",
+			identifier: NodeImplementation::proto("graphene_core::vector::MapAttributeNode<_, _, _, _, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Vector Data", TaggedValue::VectorData(graphene_core::vector::VectorData::empty()), true),
+				DocumentInputType::value("Name", TaggedValue::String(String::new()), false),
+				DocumentInputType::value("From Min", TaggedValue::F64(0.), false),
+				DocumentInputType::value("From Max", TaggedValue::F64(1.), false),
+				DocumentInputType::value("To Min", TaggedValue::F64(0.), false),
+				DocumentInputType::value("To Max", TaggedValue::F64(1.), false),
+				DocumentInputType::value("Clamp", TaggedValue::Bool(false), false),
+			],
+			outputs: vec![DocumentOutputType::new("Vector", FrontendGraphDataType::Subpath)],
+			properties: node_properties::map_attribute_properties,
+			..Default::default()
+		},
 		DocumentNodeBlueprint {
 			name: "Image Segmentation",
 			category: "Image Adjustments",
@@ -2321,6 +2611,94 @@ fn static_nodes() -> Vec<DocumentNodeBlueprint> {
 			properties: node_properties::color_overlay_properties,
 			..Default::default()
 		},
+		DocumentNodeBlueprint {
+			name: "Gaussian Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::blur::GaussianBlurNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Box Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::blur::BoxBlurNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(5.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Motion Blur",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::blur::MotionBlurNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Angle", TaggedValue::F64(0.), false),
+				DocumentInputType::value("Distance", TaggedValue::F64(20.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::motion_blur_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Unsharp Mask",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::filters::UnsharpMaskNode<_, _, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Amount", TaggedValue::F32(100.), false),
+				DocumentInputType::value("Radius", TaggedValue::F64(2.), false),
+				DocumentInputType::value("Threshold", TaggedValue::F32(0.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::unsharp_mask_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "High Pass",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::filters::HighPassNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Radius", TaggedValue::F64(10.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::high_pass_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Edge Detect",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::filters::EdgeDetectNode<_>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Operator", TaggedValue::EdgeDetectOperator(EdgeDetectOperator::Sobel), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::edge_detect_properties,
+			..Default::default()
+		},
+		DocumentNodeBlueprint {
+			name: "Emboss",
+			category: "Image Adjustments",
+			identifier: NodeImplementation::proto("graphene_core::raster::filters::EmbossNode<_, _>"),
+			inputs: vec![
+				DocumentInputType::value("Image", TaggedValue::ImageFrame(ImageFrame::empty()), true),
+				DocumentInputType::value("Angle", TaggedValue::F64(-135.), false),
+				DocumentInputType::value("Amount", TaggedValue::F32(100.), false),
+			],
+			outputs: vec![DocumentOutputType::new("Image", FrontendGraphDataType::Raster)],
+			properties: node_properties::emboss_properties,
+			..Default::default()
+		},
 	]
 }
 
@@ -2558,6 +2936,7 @@ pub fn new_image_network(output_offset: i32, output_node_id: NodeId) -> NodeNetw
 	network
 }
 
+/// Creates the network of a text layer, whose typesetting inputs other than the font size start out at their defaults.
 pub fn new_text_network(text: String, font: Font, size: f64) -> NodeNetwork {
 	let text_generator = resolve_document_node_type("Text").expect("Text node does not exist");
 	let transform = resolve_document_node_type("Transform").expect("Transform node does not exist");
@@ -2569,12 +2948,12 @@ pub fn new_text_network(text: String, font: Font, size: f64) -> NodeNetwork {
 		inputs: vec![0],
 		..Default::default()
 	};
-	network.push_node(text_generator.to_document_node(
+	network.push_node(text_generator.to_document_node_default_inputs(
 		[
-			NodeInput::Network(concrete!(WasmEditorApi)),
-			NodeInput::value(TaggedValue::String(text), false),
-			NodeInput::value(TaggedValue::Font(font), false),
-			NodeInput::value(TaggedValue::F64(size), false),
+			Some(NodeInput::Network(concrete!(WasmEditorApi))),
+			Some(NodeInput::value(TaggedValue::String(text), false)),
+			Some(NodeInput::value(TaggedValue::Font(font), false)),
+			Some(NodeInput::value(TaggedValue::F64(size), false)),
 		],
 		DocumentNodeMetadata::position((0, 4)),
 	));
@@ -10,9 +10,12 @@ use graph_craft::concrete;
 use graph_craft::document::value::TaggedValue;
 use graph_craft::document::{DocumentNode, NodeId, NodeInput};
 use graph_craft::imaginate_input::{ImaginateMaskStartingFill, ImaginateSamplingMethod, ImaginateServerStatus, ImaginateStatus};
-use graphene_core::raster::{BlendMode, Color, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
-use graphene_core::text::Font;
-use graphene_core::vector::style::{FillType, GradientType, LineCap, LineJoin};
+use graphene_core::raster::filters::EdgeDetectOperator;
+use graphene_core::raster::interpolation::InterpolationMode;
+use graphene_core::raster::{BlendMode, Color, FractalType, ImageFrame, LuminanceCalculation, NoiseType, RedGreenBlue, RelativeAbsolute, SelectiveColorChoice};
+use graphene_core::text::{Font, TextAlignment, TextPathAlignment, TextPathOverflow};
+use graphene_core::vector::style::{FillRule, FillType, GradientType, LineCap, LineJoin};
+use graphene_core::vector::{AttributeDomain, BooleanOperation, FilletType};
 
 use glam::{DVec2, IVec2};
 
@@ -368,6 +371,50 @@ fn noise_type(document_node: &DocumentNode, node_id: u64, index: usize, name: &s
 	LayoutGroup::Row { widgets }.with_tooltip("Type of Noise")
 }
 
+fn fractal_type(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::FractalType(fractal_type),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let fractal_types = FractalType::list();
+		let mut entries = Vec::with_capacity(fractal_types.len());
+		for fractal_type in fractal_types {
+			entries.push(MenuListEntry::new(fractal_type.to_string()).on_update(update_value(move |_| TaggedValue::FractalType(fractal_type), node_id, index)));
+		}
+		let entries = vec![entries];
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries).selected_index(Some(fractal_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("How octaves of finer noise are layered on top of each other")
+}
+
+fn interpolation_mode(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::InterpolationMode(interpolation),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let modes = InterpolationMode::list();
+		let mut entries = Vec::with_capacity(modes.len());
+		for mode in modes {
+			entries.push(MenuListEntry::new(mode.to_string()).on_update(update_value(move |_| TaggedValue::InterpolationMode(mode), node_id, index)));
+		}
+		let entries = vec![entries];
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries).selected_index(Some(interpolation as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Filter used when the image is scaled, where Auto picks one based on the render quality")
+}
+
 //TODO Use generalized Version of this as soon as it's available
 fn blend_mode(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
@@ -457,6 +504,203 @@ fn line_join_widget(document_node: &DocumentNode, node_id: u64, index: usize, na
 	LayoutGroup::Row { widgets }
 }
 
+fn fill_rule_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::FillRule(fill_rule),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Non-Zero", FillRule::NonZero), ("Even-Odd", FillRule::EvenOdd)]
+			.into_iter()
+			.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::FillRule(val), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(fill_rule as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+fn boolean_operation_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::BooleanOperation(boolean_operation),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let operations = BooleanOperation::list();
+		let mut entries = Vec::with_capacity(operations.len());
+		for operation in operations {
+			entries.push(MenuListEntry::new(operation.to_string()).on_update(update_value(move |_| TaggedValue::BooleanOperation(operation), node_id, index)));
+		}
+		let entries = vec![entries];
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			DropdownInput::new(entries).selected_index(Some(boolean_operation as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Method used to combine the filled areas of the shapes")
+}
+
+fn fillet_type_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::FilletType(fillet_type),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = FilletType::list()
+			.into_iter()
+			.map(|fillet_type| RadioEntryData::new(fillet_type.to_string()).on_update(update_value(move |_| TaggedValue::FilletType(fillet_type), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(fillet_type as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Shape of the curve which replaces each corner")
+}
+
+fn edge_detect_operator_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::EdgeDetectOperator(operator),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = EdgeDetectOperator::list()
+			.into_iter()
+			.map(|operator| RadioEntryData::new(operator.to_string()).on_update(update_value(move |_| TaggedValue::EdgeDetectOperator(operator), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(operator as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Sobel finds the strength of the gradient, Laplacian finds where the gradient changes and gives thinner lines")
+}
+
+fn text_alignment_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TextAlignment(alignment),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [
+			("Left", TextAlignment::Left),
+			("Center", TextAlignment::Center),
+			("Right", TextAlignment::Right),
+			("Justify", TextAlignment::Justify),
+		]
+		.into_iter()
+		.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::TextAlignment(val), node_id, index)))
+		.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(alignment as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+fn text_path_alignment_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TextPathAlignment(alignment),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Start", TextPathAlignment::Start), ("Center", TextPathAlignment::Center), ("End", TextPathAlignment::End)]
+			.into_iter()
+			.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::TextPathAlignment(val), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(alignment as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }
+}
+
+fn text_path_overflow_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::TextPathOverflow(overflow),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Hide", TextPathOverflow::Hide), ("Extend", TextPathOverflow::Extend), ("Shrink", TextPathOverflow::Shrink)]
+			.into_iter()
+			.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::TextPathOverflow(val), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(overflow as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("How glyphs past the ends of an open path are handled, while the text wraps around closed paths")
+}
+
+fn attribute_domain_widget(document_node: &DocumentNode, node_id: u64, index: usize, name: &str, blank_assist: bool) -> LayoutGroup {
+	let mut widgets = start_widgets(document_node, node_id, index, name, FrontendGraphDataType::General, blank_assist);
+	if let &NodeInput::Value {
+		tagged_value: TaggedValue::AttributeDomain(domain),
+		exposed: false,
+	} = &document_node.inputs[index]
+	{
+		let entries = [("Point", AttributeDomain::Point), ("Segment", AttributeDomain::Segment)]
+			.into_iter()
+			.map(|(name, val)| RadioEntryData::new(name).on_update(update_value(move |_| TaggedValue::AttributeDomain(val), node_id, index)))
+			.collect();
+
+		widgets.extend_from_slice(&[
+			Separator::new(SeparatorType::Unrelated).widget_holder(),
+			RadioInput::new(entries).selected_index(Some(domain as u32)).widget_holder(),
+		]);
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("Whether the attribute has a value for each anchor point or for each segment")
+}
+
+/// Picks the type of the value that the Set Attribute node writes, replacing the value with the default of the chosen type.
+fn attribute_type_widget(document_node: &DocumentNode, node_id: NodeId, index: usize) -> LayoutGroup {
+	let mut widgets = vec![TextLabel::new("Type").widget_holder(), Separator::new(SeparatorType::Unrelated).widget_holder()];
+	add_blank_assist(&mut widgets);
+	let selected = match &document_node.inputs[index] {
+		NodeInput::Value {
+			tagged_value: TaggedValue::F64(_),
+			exposed: false,
+		} => Some(0),
+		NodeInput::Value {
+			tagged_value: TaggedValue::DVec2(_),
+			exposed: false,
+		} => Some(1),
+		NodeInput::Value {
+			tagged_value: TaggedValue::Color(_),
+			exposed: false,
+		} => Some(2),
+		_ => None,
+	};
+	if let Some(selected) = selected {
+		let entries = vec![
+			RadioEntryData::new("Number").on_update(update_value(|_| TaggedValue::F64(0.), node_id, index)),
+			RadioEntryData::new("Vector").on_update(update_value(|_| TaggedValue::DVec2(DVec2::ZERO), node_id, index)),
+			RadioEntryData::new("Color").on_update(update_value(|_| TaggedValue::Color(Color::BLACK), node_id, index)),
+		];
+		widgets.push(RadioInput::new(entries).selected_index(Some(selected)).widget_holder());
+	}
+	LayoutGroup::Row { widgets }.with_tooltip("The type of the values stored in the attribute")
+}
+
 fn fill_type_widget(document_node: &DocumentNode, node_id: u64, index: usize) -> LayoutGroup {
 	let mut widgets = start_widgets(document_node, node_id, index, "Fill Type", FrontendGraphDataType::General, true);
 	if let &NodeInput::Value {
@@ -809,6 +1053,7 @@ pub fn pixel_noise_properties(document_node: &DocumentNode, node_id: NodeId, _co
 	let width = number_widget(document_node, node_id, 0, "Width", NumberInput::default().unit("px").min(1.), true);
 	let height = number_widget(document_node, node_id, 1, "Height", NumberInput::default().unit("px").min(1.), true);
 	let seed = number_widget(document_node, node_id, 2, "Seed", NumberInput::default().min(0.), true);
+	// Only white noise is generated per pixel, the coherent noise types are offered by the Noise Pattern node
 	let _noise_type = noise_type(document_node, node_id, 3, "Noise Type", true);
 
 	vec![
@@ -819,6 +1064,42 @@ pub fn pixel_noise_properties(document_node: &DocumentNode, node_id: NodeId, _co
 	]
 }
 
+pub fn sample_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	vec![interpolation_mode(document_node, node_id, 1, "Interpolation", true)]
+}
+
+pub fn resample_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let resolution = vec2_widget(document_node, node_id, 1, "Resolution", "W", "H", " px", add_blank_assist);
+	let interpolation = interpolation_mode(document_node, node_id, 2, "Interpolation", true);
+	let antialiasing = bool_widget(document_node, node_id, 3, "Antialiasing", true);
+
+	vec![
+		resolution.with_tooltip("Size of the new image, where an axis left at zero keeps the aspect ratio or the pixel density of the source image"),
+		interpolation,
+		LayoutGroup::Row { widgets: antialiasing }.with_tooltip("Make pixels along the edges of a rotated or skewed image partially transparent"),
+	]
+}
+
+pub fn noise_pattern_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let dimensions = vec2_widget(document_node, node_id, 0, "Dimensions", "W", "H", " px", add_blank_assist);
+	let seed = number_widget(document_node, node_id, 1, "Seed", NumberInput::default().min(0.).int(), true);
+	let scale = number_widget(document_node, node_id, 2, "Scale", NumberInput::default().min(1.).unit(" px"), true);
+	let noise_type = noise_type(document_node, node_id, 3, "Noise Type", true);
+	let fractal_type = fractal_type(document_node, node_id, 4, "Fractal Type", true);
+	let octaves = number_widget(document_node, node_id, 5, "Octaves", NumberInput::default().min(1.).max(16.).int(), true);
+	let tileable = bool_widget(document_node, node_id, 6, "Tileable", true);
+
+	vec![
+		dimensions,
+		LayoutGroup::Row { widgets: seed },
+		LayoutGroup::Row { widgets: scale }.with_tooltip("Approximate size of the largest features"),
+		noise_type,
+		fractal_type,
+		LayoutGroup::Row { widgets: octaves }.with_tooltip("Number of layers of finer noise, which only applies with a fractal type"),
+		LayoutGroup::Row { widgets: tileable }.with_tooltip("Fit the noise to the dimensions so that it repeats seamlessly"),
+	]
+}
+
 pub fn adjust_hsl_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let hue_shift = number_widget(document_node, node_id, 1, "Hue Shift", NumberInput::default().min(-180.).max(180.).unit("°"), true);
 	let saturation_shift = number_widget(document_node, node_id, 2, "Saturation Shift", NumberInput::default().min(-100.).max(100.).unit("%"), true);
@@ -849,6 +1130,56 @@ pub fn curves_properties(document_node: &DocumentNode, node_id: NodeId, _context
 	vec![curves]
 }
 
+pub fn blur_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).unit(" px"), true);
+
+	vec![LayoutGroup::Row { widgets: radius }.with_tooltip("Blur radius in layer space, which stays the same at any image resolution or zoom level")]
+}
+
+pub fn motion_blur_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let angle = number_widget(document_node, node_id, 1, "Angle", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let distance = number_widget(document_node, node_id, 2, "Distance", NumberInput::default().min(0.).unit(" px"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Direction of the streaks in layer space"),
+		LayoutGroup::Row { widgets: distance }.with_tooltip("Length of the streaks in layer space"),
+	]
+}
+
+pub fn unsharp_mask_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let amount = number_widget(document_node, node_id, 1, "Amount", NumberInput::default().min(0.).max(500.).unit("%"), true);
+	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default().min(0.).unit(" px"), true);
+	let threshold = number_widget(document_node, node_id, 3, "Threshold", NumberInput::default().min(0.).max(100.).unit("%"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: amount }.with_tooltip("How much of the difference from the blurred image is added back"),
+		LayoutGroup::Row { widgets: radius }.with_tooltip("Size of the details which are sharpened, in layer space"),
+		LayoutGroup::Row { widgets: threshold }.with_tooltip("Minimum difference from the blurred image for a pixel to be sharpened"),
+	]
+}
+
+pub fn high_pass_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).unit(" px"), true);
+
+	vec![LayoutGroup::Row { widgets: radius }.with_tooltip("Details smaller than this radius in layer space are kept")]
+}
+
+pub fn edge_detect_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let operator = edge_detect_operator_widget(document_node, node_id, 1, "Operator", true);
+
+	vec![operator]
+}
+
+pub fn emboss_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let angle = number_widget(document_node, node_id, 1, "Angle", NumberInput::default().min(-180.).max(180.).unit("°"), true);
+	let amount = number_widget(document_node, node_id, 2, "Amount", NumberInput::default().min(0.).max(500.).unit("%"), true);
+
+	vec![
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Direction the light comes from in layer space"),
+		LayoutGroup::Row { widgets: amount }.with_tooltip("Height of the relief"),
+	]
+}
+
 pub fn _blur_image_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).max(20.).int(), true);
 	let sigma = number_widget(document_node, node_id, 2, "Sigma", NumberInput::default().min(0.).max(10000.), true);
@@ -1138,22 +1469,31 @@ pub fn rectangle_properties(document_node: &DocumentNode, node_id: NodeId, _cont
 
 		LayoutGroup::Row { widgets }
 	};
-	vec![operand("Size X", 1), operand("Size Y", 2)]
+	let corner_radius = number_widget(document_node, node_id, 3, "Corner Radius", NumberInput::default().min(0.), true);
+
+	vec![operand("Size X", 1), operand("Size Y", 2), LayoutGroup::Row { widgets: corner_radius }]
 }
 
 pub fn regular_polygon_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let points = number_widget(document_node, node_id, 1, "Points", NumberInput::default().min(3.), true);
 	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true);
+	let corner_radius = number_widget(document_node, node_id, 3, "Corner Radius", NumberInput::default().min(0.), true);
 
-	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }]
+	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: corner_radius }]
 }
 
 pub fn star_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let points = number_widget(document_node, node_id, 1, "Points", NumberInput::default().min(2.), true);
 	let radius = number_widget(document_node, node_id, 2, "Radius", NumberInput::default(), true);
 	let inner_radius = number_widget(document_node, node_id, 3, "Inner Radius", NumberInput::default(), true);
+	let corner_radius = number_widget(document_node, node_id, 4, "Corner Radius", NumberInput::default().min(0.), true);
 
-	vec![LayoutGroup::Row { widgets: points }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: inner_radius }]
+	vec![
+		LayoutGroup::Row { widgets: points },
+		LayoutGroup::Row { widgets: radius },
+		LayoutGroup::Row { widgets: inner_radius },
+		LayoutGroup::Row { widgets: corner_radius },
+	]
 }
 
 pub fn line_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
@@ -1240,6 +1580,38 @@ pub fn node_section_font(document_node: &DocumentNode, node_id: NodeId, _context
 	result
 }
 
+pub fn text_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mut result = node_section_font(document_node, node_id, context);
+	let line_height = number_widget(document_node, node_id, 4, "Line Height", NumberInput::default().min(0.).step(0.1), true);
+	let letter_spacing = number_widget(document_node, node_id, 5, "Letter Spacing", NumberInput::default().unit(" px"), true);
+	let paragraph_spacing = number_widget(document_node, node_id, 6, "Paragraph Spacing", NumberInput::default().unit(" px"), true);
+	let alignment = text_alignment_widget(document_node, node_id, 7, "Alignment", true);
+	let kerning = bool_widget(document_node, node_id, 8, "Kerning", true);
+	let ligatures = bool_widget(document_node, node_id, 9, "Ligatures", true);
+	let features = text_widget(document_node, node_id, 10, "OpenType Features", true);
+
+	result.extend([
+		LayoutGroup::Row { widgets: line_height }.with_tooltip("Distance between the baselines of consecutive lines, as a multiple of the font size"),
+		LayoutGroup::Row { widgets: letter_spacing },
+		LayoutGroup::Row { widgets: paragraph_spacing }.with_tooltip("Extra space between paragraphs, added to the line height"),
+		alignment,
+		LayoutGroup::Row { widgets: kerning },
+		LayoutGroup::Row { widgets: ligatures },
+		LayoutGroup::Row { widgets: features }.with_tooltip("Further OpenType features such as \"smcp, onum, -calt\", where a leading - turns a feature off"),
+	]);
+	result
+}
+
+pub fn text_on_path_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let mut result = node_section_font(document_node, node_id, context);
+	let start_offset = number_widget(document_node, node_id, 5, "Start Offset", NumberInput::default().unit(" px"), true);
+	let alignment = text_path_alignment_widget(document_node, node_id, 6, "Alignment", true);
+	let overflow = text_path_overflow_widget(document_node, node_id, 7, "Overflow", true);
+
+	result.extend([LayoutGroup::Row { widgets: start_offset }, alignment, overflow]);
+	result
+}
+
 pub fn imaginate_properties(document_node: &DocumentNode, node_id: NodeId, context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let imaginate_node = [context.nested_path, &[node_id]].concat();
 
@@ -1831,18 +2203,176 @@ pub fn circular_repeat_properties(document_node: &DocumentNode, node_id: NodeId,
 	vec![LayoutGroup::Row { widgets: angle_offset }, LayoutGroup::Row { widgets: radius }, LayoutGroup::Row { widgets: count }]
 }
 
+pub fn copy_to_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let align_to_tangent = bool_widget(document_node, node_id, 2, "Align to Tangent", true);
+	let scale_attribute = text_widget(document_node, node_id, 3, "Scale Attribute", true);
+	let rotation_attribute = text_widget(document_node, node_id, 4, "Rotation Attribute", true);
+	let random_scale_min = number_widget(document_node, node_id, 5, "Random Scale Min", NumberInput::default().min(0.), true);
+	let random_scale_max = number_widget(document_node, node_id, 6, "Random Scale Max", NumberInput::default().min(0.), true);
+	let random_rotation = number_widget(document_node, node_id, 7, "Random Rotation", NumberInput::default().min(0.).max(180.).unit("°"), true);
+	let random_seed = number_widget(document_node, node_id, 8, "Random Seed", NumberInput::default().int().min(0.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: align_to_tangent },
+		LayoutGroup::Row { widgets: scale_attribute },
+		LayoutGroup::Row { widgets: rotation_attribute },
+		LayoutGroup::Row { widgets: random_scale_min },
+		LayoutGroup::Row { widgets: random_scale_max },
+		LayoutGroup::Row { widgets: random_rotation },
+		LayoutGroup::Row { widgets: random_seed },
+	]
+}
+
 pub fn resample_points_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let spacing = number_widget(document_node, node_id, 1, "Spacing", NumberInput::default().min(1.), true);
 
 	vec![LayoutGroup::Row { widgets: spacing }]
 }
 
+pub fn roughen_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let amplitude = number_widget(document_node, node_id, 1, "Amplitude", NumberInput::default().min(0.).unit(" px"), true);
+	let frequency = number_widget(document_node, node_id, 2, "Frequency", NumberInput::default().min(0.), true);
+	let seed = number_widget(document_node, node_id, 3, "Seed", NumberInput::default().min(0.).int(), true);
+
+	vec![
+		LayoutGroup::Row { widgets: amplitude }.with_tooltip("Furthest distance each point is moved on either axis"),
+		LayoutGroup::Row { widgets: frequency }.with_tooltip("Number of points along every 100 px of the path"),
+		LayoutGroup::Row { widgets: seed },
+	]
+}
+
+pub fn zig_zag_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let amplitude = number_widget(document_node, node_id, 1, "Amplitude", NumberInput::default().unit(" px"), true);
+	let frequency = number_widget(document_node, node_id, 2, "Frequency", NumberInput::default().min(0.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: amplitude }.with_tooltip("Distance the points are moved to either side of the path"),
+		LayoutGroup::Row { widgets: frequency }.with_tooltip("Number of ridges along every 100 px of the path"),
+	]
+}
+
+pub fn twist_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let angle = number_widget(document_node, node_id, 1, "Angle", NumberInput::default().unit("°"), true);
+	let frequency = number_widget(document_node, node_id, 2, "Frequency", NumberInput::default().min(0.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: angle }.with_tooltip("Rotation at the center, which fades out toward the corners of the bounding box"),
+		LayoutGroup::Row { widgets: frequency }.with_tooltip("Number of points along every 100 px of the path"),
+	]
+}
+
+pub fn pucker_bloat_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let amount = number_widget(document_node, node_id, 1, "Amount", NumberInput::default().min(-100.).max(100.).mode_range().unit("%"), true);
+	let frequency = number_widget(document_node, node_id, 2, "Frequency", NumberInput::default().min(0.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: amount }.with_tooltip("Positive amounts bloat the segments outward and negative amounts pucker them inward"),
+		LayoutGroup::Row { widgets: frequency }.with_tooltip("Number of points along every 100 px of the path"),
+	]
+}
+
+pub fn simplify_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let tolerance = number_widget(document_node, node_id, 1, "Tolerance", NumberInput::default().min(0.01), true);
+	let corner_angle = number_widget(document_node, node_id, 2, "Corner Angle", NumberInput::default().min(0.).max(180.).unit("°"), true);
+
+	vec![LayoutGroup::Row { widgets: tolerance }, LayoutGroup::Row { widgets: corner_angle }]
+}
+
+pub fn reduce_segments_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let tolerance = number_widget(document_node, node_id, 1, "Tolerance", NumberInput::default().min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: tolerance }]
+}
+
+pub fn boolean_operation_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let operation = boolean_operation_widget(document_node, node_id, 2, "Operation", true);
+
+	vec![operation]
+}
+
+pub fn morph_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let time = number_widget(document_node, node_id, 2, "Time", NumberInput::default().min(0.).max(1.).mode_range(), true);
+
+	vec![LayoutGroup::Row { widgets: time }.with_tooltip("Blend from the source at 0 to the target at 1")]
+}
+
+pub fn round_corners_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let radius = number_widget(document_node, node_id, 1, "Radius", NumberInput::default().min(0.).unit(" px"), true);
+	let fillet_type = fillet_type_widget(document_node, node_id, 2, "Fillet Type", true);
+	let min_angle = number_widget(document_node, node_id, 3, "Min Angle", NumberInput::default().min(0.).max(180.).unit("°"), true);
+	let selection_attribute = text_widget(document_node, node_id, 4, "Selection Attribute", true);
+
+	vec![
+		LayoutGroup::Row { widgets: radius },
+		fillet_type,
+		LayoutGroup::Row { widgets: min_angle }.with_tooltip("Corners where the path turns by less than this angle are left sharp"),
+		LayoutGroup::Row { widgets: selection_attribute }.with_tooltip("If named, only anchors where this point attribute is above 0.5 are rounded"),
+	]
+}
+
+pub fn offset_path_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let distance = number_widget(document_node, node_id, 1, "Distance", NumberInput::default().unit(" px"), true);
+	let line_join = line_join_widget(document_node, node_id, 2, "Line Join", true);
+	let miter_limit = number_widget(document_node, node_id, 3, "Miter Limit", NumberInput::default().min(0.), true);
+
+	vec![
+		LayoutGroup::Row { widgets: distance }.with_tooltip("Positive distances grow closed shapes and negative distances shrink them"),
+		line_join,
+		LayoutGroup::Row { widgets: miter_limit },
+	]
+}
+
+pub fn set_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let domain = attribute_domain_widget(document_node, node_id, 2, "Domain", true);
+	let value_type = attribute_type_widget(document_node, node_id, 3);
+	let value = match &document_node.inputs[3] {
+		NodeInput::Value {
+			tagged_value: TaggedValue::DVec2(_), ..
+		} => vec2_widget(document_node, node_id, 3, "Value", "X", "Y", "", add_blank_assist),
+		NodeInput::Value {
+			tagged_value: TaggedValue::Color(_), ..
+		} => color_widget(document_node, node_id, 3, "Value", ColorButton::default(), true),
+		_ => LayoutGroup::Row {
+			widgets: number_widget(document_node, node_id, 3, "Value", NumberInput::default(), true),
+		},
+	};
+
+	vec![LayoutGroup::Row { widgets: name }, domain, value_type, value]
+}
+
+pub fn read_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let index = number_widget(document_node, node_id, 2, "Index", NumberInput::default().int().min(0.), true);
+
+	vec![LayoutGroup::Row { widgets: name }, LayoutGroup::Row { widgets: index }]
+}
+
+pub fn map_attribute_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
+	let name = text_widget(document_node, node_id, 1, "Name", true);
+	let from_min = number_widget(document_node, node_id, 2, "From Min", NumberInput::default(), true);
+	let from_max = number_widget(document_node, node_id, 3, "From Max", NumberInput::default(), true);
+	let to_min = number_widget(document_node, node_id, 4, "To Min", NumberInput::default(), true);
+	let to_max = number_widget(document_node, node_id, 5, "To Max", NumberInput::default(), true);
+	let clamp = bool_widget(document_node, node_id, 6, "Clamp", true);
+
+	vec![
+		LayoutGroup::Row { widgets: name },
+		LayoutGroup::Row { widgets: from_min },
+		LayoutGroup::Row { widgets: from_max },
+		LayoutGroup::Row { widgets: to_min },
+		LayoutGroup::Row { widgets: to_max },
+		LayoutGroup::Row { widgets: clamp },
+	]
+}
+
 /// Fill Node Widgets LayoutGroup
 pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context: &mut NodePropertiesContext) -> Vec<LayoutGroup> {
 	let fill_type_index = 1;
 	let solid_color_index = 2;
 	let gradient_type_index = 3;
 	let positions_index = 7;
+	let fill_rule_index = 8;
 
 	let fill_type = if let &NodeInput::Value {
 		tagged_value: TaggedValue::FillType(fill_type),
@@ -1872,6 +2402,8 @@ pub fn fill_properties(document_node: &DocumentNode, node_id: NodeId, _context:
 		gradient_positions(&mut widgets, document_node, "Gradient Positions", node_id, positions_index);
 	}
 
+	widgets.push(fill_rule_widget(document_node, node_id, fill_rule_index, "Fill Rule", true));
+
 	widgets
 }
 
@@ -5,7 +5,7 @@ use bezier_rs::{ManipulatorGroup, Subpath};
 use document_legacy::{document::Document, document_metadata::LayerNodeIdentifier, LayerId, Operation};
 use graph_craft::document::{value::TaggedValue, DocumentNode, NodeId, NodeInput, NodeNetwork};
 use graphene_core::raster::ImageFrame;
-use graphene_core::text::Font;
+use graphene_core::text::{Font, TypesettingConfig};
 use graphene_core::uuid::ManipulatorGroupId;
 use graphene_core::vector::style::{FillType, Gradient};
 use graphene_core::Color;
@@ -145,33 +145,52 @@ pub fn get_fill_id(layer: LayerNodeIdentifier, document: &Document) -> Option<No
 }
 
 /// Gets properties from the text node
-pub fn get_text(layer: LayerNodeIdentifier, document: &Document) -> Option<(&String, &Font, f64)> {
+pub fn get_text(layer: LayerNodeIdentifier, document: &Document) -> Option<(&String, &Font, TypesettingConfig)> {
 	let inputs = NodeGraphLayer::new(layer, document)?.find_node_inputs("Text")?;
-	let NodeInput::Value {
-		tagged_value: TaggedValue::String(text),
-		..
-	} = &inputs[1]
-	else {
+	let TaggedValue::String(text) = inputs.get(1)?.as_value()? else {
 		return None;
 	};
-
-	let NodeInput::Value {
-		tagged_value: TaggedValue::Font(font),
-		..
-	} = &inputs[2]
-	else {
+	let TaggedValue::Font(font) = inputs.get(2)?.as_value()? else {
 		return None;
 	};
-
-	let NodeInput::Value {
-		tagged_value: TaggedValue::F64(font_size),
-		..
-	} = inputs[3]
-	else {
+	let TaggedValue::F64(font_size) = inputs.get(3)?.as_value()? else {
+		return None;
+	};
+	let TaggedValue::F64(line_height_ratio) = inputs.get(4)?.as_value()? else {
+		return None;
+	};
+	let TaggedValue::F64(letter_spacing) = inputs.get(5)?.as_value()? else {
+		return None;
+	};
+	let TaggedValue::F64(paragraph_spacing) = inputs.get(6)?.as_value()? else {
+		return None;
+	};
+	let TaggedValue::TextAlignment(alignment) = inputs.get(7)?.as_value()? else {
+		return None;
+	};
+	let TaggedValue::Bool(kerning) = inputs.get(8)?.as_value()? else {
+		return None;
+	};
+	let TaggedValue::Bool(ligatures) = inputs.get(9)?.as_value()? else {
 		return None;
 	};
+	let TaggedValue::String(features) = inputs.get(10)?.as_value()? else {
+		return None;
+	};
+
+	let typesetting = TypesettingConfig {
+		font_size: *font_size,
+		line_height_ratio: *line_height_ratio,
+		letter_spacing: *letter_spacing,
+		paragraph_spacing: *paragraph_spacing,
+		max_width: None,
+		alignment: *alignment,
+		kerning: *kerning,
+		ligatures: *ligatures,
+		features: features.clone(),
+	};
 
-	Some((text, font, font_size))
+	Some((text, font, typesetting))
 }
 
 /// Is a specified layer an artboard?
@@ -1,4 +1,5 @@
 use super::tool_prelude::*;
+use crate::consts::FREEHAND_FITTING_TOLERANCE;
 use crate::messages::portfolio::document::node_graph::VectorDataModification;
 use crate::messages::tool::common_functionality::color_selector::{ToolColorOptions, ToolColorType};
 use crate::messages::tool::common_functionality::graph_modification_utils;
@@ -8,7 +9,7 @@ use graphene_core::uuid::generate_uuid;
 use graphene_core::vector::style::{Fill, Stroke};
 use graphene_core::Color;
 
-use bezier_rs::ManipulatorGroup;
+use bezier_rs::{ManipulatorGroup, Subpath};
 use glam::DVec2;
 use serde::{Deserialize, Serialize};
 
@@ -178,6 +179,7 @@ impl ToolTransition for FreehandTool {
 #[derive(Clone, Debug, Default)]
 struct FreehandToolData {
 	last_point: DVec2,
+	points: Vec<DVec2>,
 	dragged: bool,
 	weight: f64,
 	layer: Option<LayerNodeIdentifier>,
@@ -206,10 +208,11 @@ impl Fsm for FreehandToolFsmState {
 
 				tool_data.dragged = false;
 				tool_data.last_point = pos;
+				tool_data.points = vec![pos];
 
 				tool_data.weight = tool_options.line_weight;
 
-				let subpath = bezier_rs::Subpath::from_anchors([pos], false);
+				let subpath = Subpath::from_anchors([pos], false);
 
 				let layer = graph_modification_utils::new_vector_layer(vec![subpath], generate_uuid(), document.new_layer_parent(), responses);
 				tool_data.layer = Some(layer);
@@ -236,6 +239,7 @@ impl Fsm for FreehandToolFsmState {
 						responses.add(GraphOperationMessage::Vector { layer: layer.to_path(), modification });
 						tool_data.dragged = true;
 						tool_data.last_point = pos;
+						tool_data.points.push(pos);
 					}
 				}
 
@@ -243,12 +247,21 @@ impl Fsm for FreehandToolFsmState {
 			}
 			(FreehandToolFsmState::Drawing, FreehandToolMessage::DragStop | FreehandToolMessage::Abort) => {
 				if tool_data.dragged {
+					// Replace the polyline of raw mouse positions with a smooth curve fitted within a fixed tolerance in viewport pixels
+					if let Some(layer) = tool_data.layer {
+						let tolerance = FREEHAND_FITTING_TOLERANCE / transform.matrix2.determinant().abs().sqrt();
+						let subpath = Subpath::new_fitted_curve(&tool_data.points, tolerance, None);
+						let modification = VectorDataModification::UpdateSubpaths { subpaths: vec![subpath] };
+						responses.add(GraphOperationMessage::Vector { layer: layer.to_path(), modification });
+					}
+
 					responses.add(DocumentMessage::CommitTransaction);
 				} else {
 					responses.add(DocumentMessage::AbortTransaction);
 				}
 
 				tool_data.layer = None;
+				tool_data.points.clear();
 
 				FreehandToolFsmState::Ready
 			}
@@ -265,7 +278,7 @@ impl Fsm for FreehandToolFsmState {
 
 	fn update_hints(&self, responses: &mut VecDeque<Message>) {
 		let hint_data = match self {
-			FreehandToolFsmState::Ready => HintData(vec![HintGroup(vec![HintInfo::mouse(MouseMotion::LmbDrag, "Draw Polyline")])]),
+			FreehandToolFsmState::Ready => HintData(vec![HintGroup(vec![HintInfo::mouse(MouseMotion::LmbDrag, "Draw Curve")])]),
 			FreehandToolFsmState::Drawing => HintData(vec![]),
 		};
 
@@ -11,7 +11,7 @@ use document_legacy::layers::style::{self, Fill, RenderData, Stroke};
 use document_legacy::LayerId;
 use document_legacy::Operation;
 use graph_craft::document::value::TaggedValue;
-use graphene_core::text::{load_face, Font};
+use graphene_core::text::{load_face, Font, TextAlignment, TypesettingConfig};
 use graphene_core::Color;
 
 #[derive(Default)]
@@ -25,6 +25,12 @@ pub struct TextOptions {
 	font_size: u32,
 	font_name: String,
 	font_style: String,
+	line_height_ratio: f64,
+	letter_spacing: f64,
+	paragraph_spacing: f64,
+	alignment: TextAlignment,
+	kerning: bool,
+	ligatures: bool,
 	fill: ToolColorOptions,
 }
 
@@ -34,6 +40,12 @@ impl Default for TextOptions {
 			font_size: 24,
 			font_name: "Merriweather".into(),
 			font_style: "Normal (400)".into(),
+			line_height_ratio: 1.,
+			letter_spacing: 0.,
+			paragraph_spacing: 0.,
+			alignment: TextAlignment::default(),
+			kerning: true,
+			ligatures: true,
 			fill: ToolColorOptions::new_primary(),
 		}
 	}
@@ -67,10 +79,16 @@ pub enum TextToolMessage {
 #[remain::sorted]
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize, specta::Type)]
 pub enum TextOptionsUpdate {
+	Alignment(TextAlignment),
 	FillColor(Option<Color>),
 	FillColorType(ToolColorType),
 	Font { family: String, style: String },
 	FontSize(u32),
+	Kerning(bool),
+	LetterSpacing(f64),
+	Ligatures(bool),
+	LineHeightRatio(f64),
+	ParagraphSpacing(f64),
 	WorkingColors(Option<Color>, Option<Color>),
 }
 
@@ -124,12 +142,71 @@ fn create_text_widgets(tool: &TextTool) -> Vec<WidgetHolder> {
 	]
 }
 
+fn create_typesetting_widgets(tool: &TextTool) -> Vec<WidgetHolder> {
+	let alignment = RadioInput::new(
+		[
+			("Left", TextAlignment::Left),
+			("Center", TextAlignment::Center),
+			("Right", TextAlignment::Right),
+			("Justify", TextAlignment::Justify),
+		]
+		.into_iter()
+		.map(|(name, alignment)| RadioEntryData::new(name).on_update(move |_| TextToolMessage::UpdateOptions(TextOptionsUpdate::Alignment(alignment)).into()))
+		.collect(),
+	)
+	.selected_index(Some(tool.options.alignment as u32))
+	.widget_holder();
+	let line_height = NumberInput::new(Some(tool.options.line_height_ratio))
+		.label("Line Height")
+		.min(0.)
+		.step(0.1)
+		.on_update(|number_input: &NumberInput| TextToolMessage::UpdateOptions(TextOptionsUpdate::LineHeightRatio(number_input.value.unwrap())).into())
+		.widget_holder();
+	let letter_spacing = NumberInput::new(Some(tool.options.letter_spacing))
+		.unit(" px")
+		.label("Letter Spacing")
+		.on_update(|number_input: &NumberInput| TextToolMessage::UpdateOptions(TextOptionsUpdate::LetterSpacing(number_input.value.unwrap())).into())
+		.widget_holder();
+	let paragraph_spacing = NumberInput::new(Some(tool.options.paragraph_spacing))
+		.unit(" px")
+		.label("Paragraph Spacing")
+		.on_update(|number_input: &NumberInput| TextToolMessage::UpdateOptions(TextOptionsUpdate::ParagraphSpacing(number_input.value.unwrap())).into())
+		.widget_holder();
+	let kerning = CheckboxInput::new(tool.options.kerning)
+		.tooltip("Kerning")
+		.on_update(|checkbox: &CheckboxInput| TextToolMessage::UpdateOptions(TextOptionsUpdate::Kerning(checkbox.checked)).into())
+		.widget_holder();
+	let ligatures = CheckboxInput::new(tool.options.ligatures)
+		.tooltip("Ligatures")
+		.on_update(|checkbox: &CheckboxInput| TextToolMessage::UpdateOptions(TextOptionsUpdate::Ligatures(checkbox.checked)).into())
+		.widget_holder();
+	vec![
+		alignment,
+		Separator::new(SeparatorType::Related).widget_holder(),
+		line_height,
+		Separator::new(SeparatorType::Related).widget_holder(),
+		letter_spacing,
+		Separator::new(SeparatorType::Related).widget_holder(),
+		paragraph_spacing,
+		Separator::new(SeparatorType::Unrelated).widget_holder(),
+		TextLabel::new("Kerning").widget_holder(),
+		kerning,
+		Separator::new(SeparatorType::Related).widget_holder(),
+		TextLabel::new("Ligatures").widget_holder(),
+		ligatures,
+	]
+}
+
 impl LayoutHolder for TextTool {
 	fn layout(&self) -> Layout {
 		let mut widgets = create_text_widgets(self);
 
 		widgets.push(Separator::new(SeparatorType::Section).widget_holder());
 
+		widgets.append(&mut create_typesetting_widgets(self));
+
+		widgets.push(Separator::new(SeparatorType::Section).widget_holder());
+
 		widgets.append(&mut self.options.fill.create_widgets(
 			"Fill",
 			true,
@@ -156,6 +233,12 @@ impl<'a> MessageHandler<ToolMessage, &mut ToolActionHandlerData<'a>> for TextToo
 				self.send_layout(responses, LayoutTarget::ToolOptions);
 			}
 			TextOptionsUpdate::FontSize(font_size) => self.options.font_size = font_size,
+			TextOptionsUpdate::LineHeightRatio(line_height_ratio) => self.options.line_height_ratio = line_height_ratio,
+			TextOptionsUpdate::LetterSpacing(letter_spacing) => self.options.letter_spacing = letter_spacing,
+			TextOptionsUpdate::ParagraphSpacing(paragraph_spacing) => self.options.paragraph_spacing = paragraph_spacing,
+			TextOptionsUpdate::Alignment(alignment) => self.options.alignment = alignment,
+			TextOptionsUpdate::Kerning(kerning) => self.options.kerning = kerning,
+			TextOptionsUpdate::Ligatures(ligatures) => self.options.ligatures = ligatures,
 			TextOptionsUpdate::FillColor(color) => {
 				self.options.fill.custom_color = color;
 				self.options.fill.color_type = ToolColorType::Custom;
@@ -208,7 +291,7 @@ enum TextToolFsmState {
 pub struct EditingText {
 	text: String,
 	font: Font,
-	font_size: f64,
+	typesetting: TypesettingConfig,
 	color: Option<Color>,
 	transform: DAffine2,
 }
@@ -232,7 +315,10 @@ impl TextToolData {
 			responses.add(FrontendMessage::DisplayEditableTextbox {
 				text: editing_text.text.clone(),
 				line_width: None,
-				font_size: editing_text.font_size,
+				font_size: editing_text.typesetting.font_size,
+				line_height_ratio: editing_text.typesetting.line_height_ratio,
+				letter_spacing: editing_text.typesetting.letter_spacing,
+				alignment: editing_text.typesetting.alignment,
 				color: editing_text.color.unwrap_or(Color::BLACK),
 				url: render_data.font_cache.get_preview_url(&editing_text.font).cloned().unwrap_or_default(),
 				transform: editing_text.transform.to_cols_array(),
@@ -245,11 +331,11 @@ impl TextToolData {
 	fn load_layer_text_node(&mut self, document: &DocumentMessageHandler) -> Option<()> {
 		let transform = document.metadata().transform_to_viewport(self.layer);
 		let color = graph_modification_utils::get_fill_color(self.layer, &document.document_legacy).unwrap_or(Color::BLACK);
-		let (text, font, font_size) = graph_modification_utils::get_text(self.layer, &document.document_legacy)?;
+		let (text, font, typesetting) = graph_modification_utils::get_text(self.layer, &document.document_legacy)?;
 		self.editing_text = Some(EditingText {
 			text: text.clone(),
 			font: font.clone(),
-			font_size,
+			typesetting,
 			color: Some(color),
 			transform,
 		});
@@ -293,7 +379,7 @@ impl TextToolData {
 				id: self.layer.to_node(),
 				text: String::new(),
 				font: editing_text.font.clone(),
-				size: editing_text.font_size,
+				typesetting: editing_text.typesetting.clone(),
 				parent: LayerNodeIdentifier::ROOT,
 				insert_index: -1,
 			});
@@ -328,7 +414,7 @@ impl TextToolData {
 
 		let editing_text = self.editing_text.as_ref()?;
 		let buzz_face = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data));
-		let far = graphene_core::text::bounding_box(&self.new_text, buzz_face, editing_text.font_size, None);
+		let far = graphene_core::text::bounding_box(&self.new_text, buzz_face, &editing_text.typesetting);
 		let quad = Quad::from_box([DVec2::ZERO, far]);
 
 		let transformed_quad = document.metadata().transform_to_viewport(self.layer) * quad;
@@ -345,7 +431,7 @@ impl TextToolData {
 	fn get_bounds(&self, text: &str, render_data: &RenderData) -> Option<[DVec2; 2]> {
 		let editing_text = self.editing_text.as_ref()?;
 		let buzz_face = render_data.font_cache.get(&editing_text.font).map(|data| load_face(data));
-		let subpaths = graphene_core::text::to_path(text, buzz_face, editing_text.font_size, None);
+		let subpaths = graphene_core::text::to_path(text, buzz_face, &editing_text.typesetting);
 		let bounds = subpaths.iter().filter_map(|subpath| subpath.bounding_box());
 		let combined_bounds = bounds.reduce(|a, b| [a[0].min(b[0]), a[1].max(b[1])]).unwrap_or_default();
 		Some(combined_bounds)
@@ -386,9 +472,9 @@ fn resize_overlays(overlays: &mut Vec<Vec<LayerId>>, responses: &mut VecDeque<Me
 
 fn update_overlays(document: &DocumentMessageHandler, tool_data: &mut TextToolData, responses: &mut VecDeque<Message>, render_data: &RenderData) {
 	let get_bounds = |layer: LayerNodeIdentifier, document: &DocumentMessageHandler, render_data: &RenderData| {
-		let (text, font, font_size) = graph_modification_utils::get_text(layer, &document.document_legacy)?;
+		let (text, font, typesetting) = graph_modification_utils::get_text(layer, &document.document_legacy)?;
 		let buzz_face = render_data.font_cache.get(font).map(|data| load_face(data));
-		let far = graphene_core::text::bounding_box(text, buzz_face, font_size, None);
+		let far = graphene_core::text::bounding_box(text, buzz_face, &typesetting);
 		let quad = Quad::from_box([DVec2::ZERO, far]);
 		let multiplied = document.metadata().transform_to_viewport(layer) * quad;
 		Some(multiplied.bounding_box())
@@ -456,7 +542,17 @@ impl Fsm for TextToolFsmState {
 				tool_data.editing_text = Some(EditingText {
 					text: String::new(),
 					transform: DAffine2::from_translation(input.mouse.position),
-					font_size: tool_options.font_size as f64,
+					typesetting: TypesettingConfig {
+						font_size: tool_options.font_size as f64,
+						line_height_ratio: tool_options.line_height_ratio,
+						letter_spacing: tool_options.letter_spacing,
+						paragraph_spacing: tool_options.paragraph_spacing,
+						max_width: None,
+						alignment: tool_options.alignment,
+						kerning: tool_options.kerning,
+						ligatures: tool_options.ligatures,
+						features: String::new(),
+					},
 					font: Font::new(tool_options.font_name.clone(), tool_options.font_style.clone()),
 					color: tool_options.fill.active_color(),
 				});
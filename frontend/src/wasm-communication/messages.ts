@@ -642,6 +642,8 @@ export function newUpdateDocumentLayerTreeStructure(input: { dataBuffer: DataBuf
 	return currentFolder;
 }
 
+export type TextAlignment = "Left" | "Center" | "Right" | "Justify";
+
 export class DisplayEditableTextbox extends JsMessage {
 	readonly text!: string;
 
@@ -649,6 +651,12 @@ export class DisplayEditableTextbox extends JsMessage {
 
 	readonly fontSize!: number;
 
+	readonly lineHeightRatio!: number;
+
+	readonly letterSpacing!: number;
+
+	readonly alignment!: TextAlignment;
+
 	@Type(() => Color)
 	readonly color!: Color;
 
@@ -260,23 +260,36 @@ impl Bezier {
 
 		// Check if the bounding boxes overlap
 		if utils::do_rectangles_overlap(bounding_box1, bounding_box2) {
+			let self_is_small = (bounding_box1[1] - bounding_box1[0]).cmplt(error_threshold).all();
+			let other_is_small = (bounding_box2[1] - bounding_box2[0]).cmplt(error_threshold).all();
+
 			// If bounding boxes are within the error threshold (i.e. are small enough), we have found an intersection
-			if (bounding_box1[1] - bounding_box1[0]).cmplt(error_threshold).all() && (bounding_box2[1] - bounding_box2[0]).cmplt(error_threshold).all() {
+			if self_is_small && other_is_small {
 				// Use the middle t value, return the corresponding `t` value for `self` and `other`
 				return vec![[self_mid_t, other_mid_t]];
 			}
 
-			// Split curves in half and repeat with the combinations of the two halves of each curve
-			let [split_1_a, split_1_b] = self.split(TValue::Parametric(0.5));
-			let [split_2_a, split_2_b] = other.split(TValue::Parametric(0.5));
-
-			[
-				split_1_a.intersections_between_subcurves(self_start_t..self_mid_t, &split_2_a, other_start_t..other_mid_t, error),
-				split_1_a.intersections_between_subcurves(self_start_t..self_mid_t, &split_2_b, other_mid_t..other_end_t, error),
-				split_1_b.intersections_between_subcurves(self_mid_t..self_end_t, &split_2_a, other_start_t..other_mid_t, error),
-				split_1_b.intersections_between_subcurves(self_mid_t..self_end_t, &split_2_b, other_mid_t..other_end_t, error),
-			]
-			.concat()
+			// Split the curves in half and repeat with the combinations of the two halves of each curve
+			// A curve that is already within the error is kept whole, so it isn't duplicated at every level while the other curve is still being subdivided
+			let halves = |curve: &Bezier, is_small: bool, start_t: f64, mid_t: f64, end_t: f64| {
+				if is_small {
+					vec![(*curve, start_t..end_t)]
+				} else {
+					let [first, second] = curve.split(TValue::Parametric(0.5));
+					vec![(first, start_t..mid_t), (second, mid_t..end_t)]
+				}
+			};
+			let self_halves = halves(self, self_is_small, self_start_t, self_mid_t, self_end_t);
+			let other_halves = halves(other, other_is_small, other_start_t, other_mid_t, other_end_t);
+
+			self_halves
+				.iter()
+				.flat_map(|(self_half, self_t_interval)| {
+					other_halves
+						.iter()
+						.flat_map(move |(other_half, other_t_interval)| self_half.intersections_between_subcurves(self_t_interval.clone(), other_half, other_t_interval.clone(), error))
+				})
+				.collect()
 		} else {
 			vec![]
 		}
@@ -910,4 +923,15 @@ mod tests {
 		assert!(Bezier::from_linear_coordinates(160., 180., 170., 10.).self_intersections(None).is_empty());
 		assert!(Bezier::from_quadratic_coordinates(160., 180., 170., 10., 30., 90.).self_intersections(None).is_empty());
 	}
+	#[test]
+	fn test_intersect_curve_with_point_sized_curve() {
+		// A curve already smaller than the error is kept whole while the other curve is subdivided, rather than splitting it along with the other curve at every step
+		let point_sized = Bezier::from_cubic_coordinates(100., 100., 100.001, 100., 100.002, 100.001, 100.001, 100.002);
+		let diagonal = Bezier::from_cubic_coordinates(0., 0., 50., 50., 150., 150., 200., 200.);
+		let intersections = point_sized.intersections_between_subcurves(0. ..1., &diagonal, 0. ..1., 0.01);
+
+		assert!(!intersections.is_empty());
+		assert!(intersections.len() <= 2, "Found {} intersections", intersections.len());
+		assert!(diagonal.evaluate(TValue::Parametric(intersections[0][1])).abs_diff_eq(DVec2::splat(100.), 0.01));
+	}
 }
@@ -12,6 +12,24 @@ pub const SCALABLE_CURVE_MAX_ENDPOINT_NORMAL_ANGLE: f64 = std::f64::consts::PI /
 pub const MIN_SEPARATION_VALUE: f64 = 5. * 1e-3;
 /// Default error bound for `t_value_to_parametric` function when TValue argument is Euclidean
 pub const DEFAULT_EUCLIDEAN_ERROR_BOUND: f64 = 0.001;
+/// Maximum distance between two points for them to be considered the same vertex when computing boolean operations.
+pub const BOOLEAN_OPERATION_VERTEX_TOLERANCE: f64 = 1e-5;
+/// Bounding box size at which curve subdivision stops when searching for intersections in boolean operations.
+pub const BOOLEAN_OPERATION_INTERSECTION_ERROR: f64 = 1e-7;
+/// Distance away from an edge at which the filled state is sampled on either side when classifying edges in boolean operations.
+pub const BOOLEAN_OPERATION_SAMPLE_DISTANCE: f64 = 1e-3;
+/// Nodes and weights of the 5-point Gauss-Legendre quadrature on the interval [-1, 1], which integrates polynomials up to degree 9 exactly.
+pub const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+	(0., 0.568_888_888_888_888_9),
+	(-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+	(0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+	(-0.906_179_845_938_664, 0.236_926_885_056_189_08),
+	(0.906_179_845_938_664, 0.236_926_885_056_189_08),
+];
+/// Maximum number of cubic segments used to approximate an elliptical arc, regardless of the requested accuracy.
+pub const MAX_ELLIPTICAL_ARC_SEGMENTS: usize = 1024;
+/// Maximum number of dashes that a subpath is broken into, so a pattern that is tiny compared to the subpath can't produce an unbounded amount of geometry.
+pub const MAX_DASHES: usize = 10_000;
 
 // Method argument defaults
 
@@ -21,6 +39,20 @@ pub const DEFAULT_T_VALUE: f64 = 0.5;
 pub const DEFAULT_LUT_STEP_SIZE: usize = 10;
 /// Default number of subdivisions used in `length` calculation.
 pub const DEFAULT_LENGTH_SUBDIVISIONS: usize = 1000;
+/// Default angle in radians by which the direction of a point sequence must turn for a corner to be kept when fitting curves to it.
+pub const DEFAULT_FITTING_CORNER_ANGLE: f64 = std::f64::consts::FRAC_PI_3;
+/// Maximum number of times the parameters of the points are improved before splitting a section when fitting curves to it.
+pub const FITTING_REPARAMETERIZATION_ITERATIONS: usize = 4;
+/// Number of samples taken along each segment of a subpath to measure how far a simplified segment deviates from it.
+pub const SIMPLIFICATION_SAMPLES_PER_SEGMENT: usize = 16;
+/// Number of samples of a simplified segment from which the closest positions on it to other points are refined.
+pub const SIMPLIFICATION_PROJECTION_SAMPLES: usize = 64;
+/// Number of Newton's method iterations used to refine the closest positions on a simplified segment to other points.
+pub const SIMPLIFICATION_PROJECTION_ITERATIONS: usize = 8;
+/// Default number of samples taken along each segment of a subpath when building an arc length table.
+pub const DEFAULT_ARC_LENGTH_SAMPLES_PER_SEGMENT: usize = 100;
+/// Default maximum distance between an elliptical arc and the cubic segments approximating it.
+pub const DEFAULT_ELLIPTICAL_ARC_ERROR: f64 = 1e-3;
 /// Default step size for `reduce` function.
 pub const DEFAULT_REDUCE_STEP_SIZE: f64 = 0.01;
 
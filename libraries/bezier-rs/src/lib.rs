@@ -9,4 +9,4 @@ mod utils;
 
 pub use bezier::*;
 pub use subpath::*;
-pub use utils::{Cap, Join, SubpathTValue, TValue, TValueType};
+pub use utils::{BooleanOperation, Cap, FillRule, FilletType, Join, SubpathTValue, TValue, TValueType};
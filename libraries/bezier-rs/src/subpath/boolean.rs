@@ -0,0 +1,700 @@
+use super::*;
+use crate::consts::{BOOLEAN_OPERATION_INTERSECTION_ERROR, BOOLEAN_OPERATION_SAMPLE_DISTANCE, BOOLEAN_OPERATION_VERTEX_TOLERANCE};
+use crate::utils::{BooleanOperation, FillRule, TValue};
+use crate::BezierHandles;
+
+use glam::DVec2;
+use std::f64::consts::TAU;
+
+/// Number of samples taken along a curve to find the initial guess when searching for the closest point on it.
+const CLOSEST_POINT_SAMPLES: usize = 32;
+/// Number of refinement iterations used when searching for the closest point on a curve.
+const CLOSEST_POINT_ITERATIONS: usize = 64;
+
+/// A piece of the boundary of one of the input shapes, bounded by two vertices of the planar graph built from both shapes.
+#[derive(Clone)]
+struct Edge {
+	bezier: Bezier,
+	start_vertex: usize,
+	end_vertex: usize,
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Combines the filled areas of two sets of subpaths with the given boolean `operation`, returning the closed subpaths that bound the resulting area.
+	/// - `subpaths_a` and `subpaths_b` - The two shapes to combine. Each subpath is treated as closed, so open subpaths are implicitly closed with a line segment.
+	/// - `operation` - The way in which the two filled areas are combined. For [BooleanOperation::Difference], `subpaths_b` is subtracted from `subpaths_a`.
+	/// - `fill_rule` - The rule used to decide which areas are filled by each of the two shapes.
+	///
+	/// Edges that are shared by both shapes are handled, so shapes that touch along a side are merged into a single subpath by a union.
+	/// Every returned subpath is closed and is wound counterclockwise (with +y as up) around filled areas and clockwise around holes,
+	/// such that the result is filled correctly under both fill rules.
+	pub fn boolean_operation(
+		subpaths_a: &[Subpath<ManipulatorGroupId>],
+		subpaths_b: &[Subpath<ManipulatorGroupId>],
+		operation: BooleanOperation,
+		fill_rule: FillRule,
+	) -> Vec<Subpath<ManipulatorGroupId>> {
+		let segments_a = closed_segments(subpaths_a);
+		let segments_b = closed_segments(subpaths_b);
+
+		let segments: Vec<Bezier> = segments_a.iter().chain(segments_b.iter()).copied().collect();
+
+		filled_area_boundary(&segments, |point| {
+			let inside_a = fill_rule.is_inside(segments_a.iter().map(|bezier| bezier.winding(point)).sum());
+			let inside_b = fill_rule.is_inside(segments_b.iter().map(|bezier| bezier.winding(point)).sum());
+			operation.combine(inside_a, inside_b)
+		})
+	}
+
+	/// Resolves the self-intersections of the subpath, returning the closed subpaths which bound the area it winds around in one direction.
+	/// Overlapping loops which wind in that direction are merged, while loops which wind backwards are removed, such as the ones left behind by offsetting a shape inward past its narrower parts.
+	/// - `positive` - Whether to keep the area with a positive winding number, which is wound counterclockwise (with +y as up), instead of the area with a negative winding number.
+	///
+	/// The subpath is treated as closed, and every returned subpath is wound in the direction of the kept area.
+	pub fn resolve_self_intersections(&self, positive: bool) -> Vec<Subpath<ManipulatorGroupId>> {
+		Self::resolve_intersections(std::slice::from_ref(self), positive)
+	}
+
+	/// Resolves the intersections within and between the subpaths, returning the closed subpaths which bound the area they wind around in one direction, like [Subpath::resolve_self_intersections].
+	/// The winding numbers of all of the subpaths are added up, so overlapping areas that wind in the kept direction are merged into their union.
+	pub fn resolve_intersections(subpaths: &[Subpath<ManipulatorGroupId>], positive: bool) -> Vec<Subpath<ManipulatorGroupId>> {
+		let segments = closed_segments(subpaths);
+
+		let resolved = filled_area_boundary(&segments, |point| {
+			let winding: i32 = segments.iter().map(|bezier| bezier.winding(point)).sum();
+			if positive {
+				winding > 0
+			} else {
+				winding < 0
+			}
+		});
+		if positive {
+			resolved
+		} else {
+			resolved.iter().map(|subpath| subpath.reverse()).collect()
+		}
+	}
+}
+
+/// Splits the segments at their intersections and traces the closed loops that bound the area where `is_filled` returns true, wound counterclockwise around it.
+fn filled_area_boundary<ManipulatorGroupId: crate::Identifier>(segments: &[Bezier], is_filled: impl Fn(DVec2) -> bool) -> Vec<Subpath<ManipulatorGroupId>> {
+	let edges = build_edges(segments);
+
+	// Keep the edges which separate a filled area from an unfilled one, oriented such that the filled area lies on their left
+	let boundary_edges: Vec<Edge> = edges
+		.into_iter()
+		.filter_map(|edge| {
+			let point = edge.bezier.evaluate(TValue::Parametric(0.5));
+			let normal = edge_direction_at(&edge.bezier, 0.5).perp();
+			let filled_left = is_filled(point + normal * BOOLEAN_OPERATION_SAMPLE_DISTANCE);
+			let filled_right = is_filled(point - normal * BOOLEAN_OPERATION_SAMPLE_DISTANCE);
+			match (filled_left, filled_right) {
+				(true, false) => Some(edge),
+				(false, true) => Some(Edge {
+					bezier: edge.bezier.reverse(),
+					start_vertex: edge.end_vertex,
+					end_vertex: edge.start_vertex,
+				}),
+				_ => None,
+			}
+		})
+		.collect();
+
+	trace_loops(&boundary_edges).into_iter().filter_map(|loop_edges| subpath_from_loop(&loop_edges)).collect()
+}
+
+/// Collects the segments of all the subpaths, closing each subpath with a line segment if needed.
+/// Quadratic segments are converted to cubic segments and segments that are only a single point are discarded.
+fn closed_segments<ManipulatorGroupId: crate::Identifier>(subpaths: &[Subpath<ManipulatorGroupId>]) -> Vec<Bezier> {
+	let mut segments = Vec::new();
+	for subpath in subpaths {
+		let Some(first) = subpath.manipulator_groups().first() else { continue };
+		let last = subpath.manipulator_groups().last().unwrap();
+
+		segments.extend(subpath.iter());
+		if !subpath.closed && first.anchor.distance(last.anchor) > BOOLEAN_OPERATION_VERTEX_TOLERANCE {
+			segments.push(Bezier::from_linear_dvec2(last.anchor, first.anchor));
+		}
+	}
+
+	segments
+		.into_iter()
+		.filter(|bezier| !bezier.is_point())
+		.map(|bezier| match bezier.handles {
+			BezierHandles::Quadratic { .. } => bezier.to_cubic(),
+			_ => bezier,
+		})
+		.collect()
+}
+
+/// Splits the segments at all of their intersections and returns the resulting edges, with coincident edges only included once.
+fn build_edges(segments: &[Bezier]) -> Vec<Edge> {
+	let mut split_points: Vec<Vec<f64>> = vec![Vec::new(); segments.len()];
+
+	for (index, segment) in segments.iter().enumerate() {
+		split_points[index].extend(segment.self_intersections(Some(BOOLEAN_OPERATION_INTERSECTION_ERROR)).into_iter().flatten());
+	}
+
+	for index_a in 0..segments.len() {
+		for index_b in index_a + 1..segments.len() {
+			let (a, b) = (&segments[index_a], &segments[index_b]);
+			let [min_a, max_a] = a.bounding_box();
+			let [min_b, max_b] = b.bounding_box();
+			let tolerance = DVec2::splat(BOOLEAN_OPERATION_VERTEX_TOLERANCE);
+			if !crate::utils::do_rectangles_overlap([min_a - tolerance, max_a + tolerance], [min_b - tolerance, max_b + tolerance]) {
+				continue;
+			}
+
+			// Overlapping segments have infinitely many intersections, so only their endpoints are used to split each other
+			if let Some((splits_a, splits_b)) = coincident_split_points(a, b) {
+				split_points[index_a].extend(splits_a);
+				split_points[index_b].extend(splits_b);
+				continue;
+			}
+
+			for [t_a, t_b] in segment_intersections(a, b) {
+				split_points[index_a].push(t_a);
+				split_points[index_b].push(t_b);
+			}
+		}
+	}
+
+	let mut vertices: Vec<DVec2> = Vec::new();
+	let mut vertex_index = |point: DVec2| {
+		if let Some(index) = vertices.iter().position(|vertex| vertex.distance(point) < BOOLEAN_OPERATION_VERTEX_TOLERANCE) {
+			return (index, vertices[index]);
+		}
+		vertices.push(point);
+		(vertices.len() - 1, point)
+	};
+
+	let mut edges: Vec<Edge> = Vec::new();
+	for (segment, t_values) in segments.iter().zip(split_points) {
+		for mut bezier in split_at(segment, t_values) {
+			let (start_vertex, start) = vertex_index(bezier.start());
+			let (end_vertex, end) = vertex_index(bezier.end());
+			bezier.set_start(start);
+			bezier.set_end(end);
+
+			// Discard edges that have collapsed into a single point
+			if start_vertex == end_vertex && bezier.get_points().all(|point| point.distance(start) < BOOLEAN_OPERATION_VERTEX_TOLERANCE) {
+				continue;
+			}
+
+			// Discard edges that overlap an edge which has already been added
+			let midpoint = bezier.evaluate(TValue::Parametric(0.5));
+			let is_duplicate = edges.iter().any(|edge| {
+				let same_vertices = (edge.start_vertex == start_vertex && edge.end_vertex == end_vertex) || (edge.start_vertex == end_vertex && edge.end_vertex == start_vertex);
+				same_vertices && distance_to_curve(&edge.bezier, midpoint) < BOOLEAN_OPERATION_VERTEX_TOLERANCE * 10.
+			});
+			if !is_duplicate {
+				edges.push(Edge { bezier, start_vertex, end_vertex });
+			}
+		}
+	}
+
+	edges
+}
+
+/// If the two segments overlap along some nonzero length, returns the parametric `t` values at which each one should be split so that the overlapping parts become separate edges.
+fn coincident_split_points(a: &Bezier, b: &Bezier) -> Option<(Vec<f64>, Vec<f64>)> {
+	let on_curve = |curve: &Bezier, point: DVec2| {
+		let t = closest_parametric(curve, point);
+		(curve.evaluate(TValue::Parametric(t)).distance(point) < BOOLEAN_OPERATION_VERTEX_TOLERANCE).then_some(t)
+	};
+
+	// Each entry is a point lying on both segments together with its `t` value along each of them
+	let mut shared_points: Vec<(DVec2, f64, f64)> = Vec::new();
+	for (point, t_a) in [(a.start(), 0.), (a.end(), 1.)] {
+		if let Some(t_b) = on_curve(b, point) {
+			shared_points.push((point, t_a, t_b));
+		}
+	}
+	for (point, t_b) in [(b.start(), 0.), (b.end(), 1.)] {
+		if shared_points.iter().all(|(shared, _, _)| shared.distance(point) >= BOOLEAN_OPERATION_VERTEX_TOLERANCE) {
+			if let Some(t_a) = on_curve(a, point) {
+				shared_points.push((point, t_a, t_b));
+			}
+		}
+	}
+	if shared_points.len() < 2 {
+		return None;
+	}
+
+	// The segments only overlap if the curve between the shared points also lies on the other segment
+	shared_points.sort_by(|first, second| first.1.total_cmp(&second.1));
+	let (_, first_t_a, _) = shared_points[0];
+	let (_, last_t_a, _) = shared_points[shared_points.len() - 1];
+	let overlapping = [0.25, 0.5, 0.75].into_iter().all(|ratio| {
+		let t_a = first_t_a + (last_t_a - first_t_a) * ratio;
+		on_curve(b, a.evaluate(TValue::Parametric(t_a))).is_some()
+	});
+	if !overlapping {
+		return None;
+	}
+
+	Some(shared_points.iter().map(|&(_, t_a, t_b)| (t_a, t_b)).unzip())
+}
+
+/// Finds the intersections between two segments which don't overlap, returning pairs of parametric `t` values along the first and second segment.
+fn segment_intersections(a: &Bezier, b: &Bezier) -> Vec<[f64; 2]> {
+	if let (BezierHandles::Linear, BezierHandles::Linear) = (&a.handles, &b.handles) {
+		let direction_a = a.end() - a.start();
+		let direction_b = b.end() - b.start();
+		let denominator = direction_a.perp_dot(direction_b);
+		if denominator.abs() < f64::EPSILON {
+			return Vec::new();
+		}
+		let offset = b.start() - a.start();
+		let t_a = offset.perp_dot(direction_b) / denominator;
+		let t_b = offset.perp_dot(direction_a) / denominator;
+		let range = -1e-9..=1. + 1e-9;
+		if range.contains(&t_a) && range.contains(&t_b) {
+			return vec![[t_a.clamp(0., 1.), t_b.clamp(0., 1.)]];
+		}
+		return Vec::new();
+	}
+
+	let mut intersections = a.intersections_between_subcurves(0. ..1., b, 0. ..1., BOOLEAN_OPERATION_INTERSECTION_ERROR);
+	intersections.sort_by(|first, second| first[0].total_cmp(&second[0]));
+
+	// Subdivision reports clusters of neighboring results for each intersection, so only one result is kept per cluster
+	let mut filtered: Vec<[f64; 2]> = Vec::new();
+	for intersection in intersections {
+		let point = a.evaluate(TValue::Parametric(intersection[0]));
+		let is_new = filtered
+			.last()
+			.map_or(true, |previous| a.evaluate(TValue::Parametric(previous[0])).distance(point) >= BOOLEAN_OPERATION_VERTEX_TOLERANCE);
+		if is_new {
+			filtered.push(intersection);
+		}
+	}
+	filtered
+}
+
+/// Splits the bezier at each of the provided parametric `t` values, ignoring those that would create pieces shorter than the vertex tolerance.
+fn split_at(bezier: &Bezier, mut t_values: Vec<f64>) -> Vec<Bezier> {
+	t_values.sort_by(|a, b| a.total_cmp(b));
+
+	let mut pieces = Vec::new();
+	let mut remaining = *bezier;
+	let mut remaining_start_t = 0.;
+	let mut last_point = bezier.start();
+	for t in t_values {
+		let point = bezier.evaluate(TValue::Parametric(t));
+		if t <= remaining_start_t || point.distance(last_point) < BOOLEAN_OPERATION_VERTEX_TOLERANCE || point.distance(bezier.end()) < BOOLEAN_OPERATION_VERTEX_TOLERANCE {
+			continue;
+		}
+
+		let [first, second] = remaining.split(TValue::Parametric((t - remaining_start_t) / (1. - remaining_start_t)));
+		pieces.push(first);
+		remaining = second;
+		remaining_start_t = t;
+		last_point = point;
+	}
+	pieces.push(remaining);
+
+	pieces
+}
+
+/// Returns the parametric `t` value of the point on the curve which is closest to the given point.
+fn closest_parametric(bezier: &Bezier, point: DVec2) -> f64 {
+	let distance = |t: f64| bezier.evaluate(TValue::Parametric(t)).distance_squared(point);
+
+	let step = 1. / CLOSEST_POINT_SAMPLES as f64;
+	let closest_sample = (0..=CLOSEST_POINT_SAMPLES)
+		.map(|index| index as f64 * step)
+		.min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+		.unwrap();
+
+	// Refine the guess with a golden section search around the closest sample
+	let inverse_golden_ratio = (5_f64.sqrt() - 1.) / 2.;
+	let (mut low, mut high) = ((closest_sample - step).max(0.), (closest_sample + step).min(1.));
+	for _ in 0..CLOSEST_POINT_ITERATIONS {
+		let left = high - (high - low) * inverse_golden_ratio;
+		let right = low + (high - low) * inverse_golden_ratio;
+		if distance(left) < distance(right) {
+			high = right;
+		} else {
+			low = left;
+		}
+	}
+	(low + high) / 2.
+}
+
+fn distance_to_curve(bezier: &Bezier, point: DVec2) -> f64 {
+	bezier.evaluate(TValue::Parametric(closest_parametric(bezier, point))).distance(point)
+}
+
+/// Returns the normalized direction of travel along the bezier at the parametric `t` value, which is well-defined even where the derivative is zero.
+fn edge_direction_at(bezier: &Bezier, t: f64) -> DVec2 {
+	let tangent = bezier.non_normalized_tangent(t);
+	if tangent.length_squared() > f64::EPSILON {
+		return tangent.normalize();
+	}
+
+	// At the ends of a curve with a handle that coincides with its anchor, the direction is given by the next distinct control point
+	let points: Vec<DVec2> = bezier.get_points().collect();
+	let direction = if t < 0.5 {
+		points.iter().skip(1).map(|point| *point - bezier.start()).find(|direction| direction.length_squared() > f64::EPSILON)
+	} else {
+		points
+			.iter()
+			.rev()
+			.skip(1)
+			.map(|point| bezier.end() - *point)
+			.find(|direction| direction.length_squared() > f64::EPSILON)
+	};
+	direction.unwrap_or(bezier.end() - bezier.start()).normalize_or_zero()
+}
+
+/// Joins the boundary edges into closed loops. At vertices where more than two edges meet, the outgoing edge that turns the most sharply
+/// to the right is chosen, so that regions which only touch at a single point become separate loops.
+fn trace_loops(edges: &[Edge]) -> Vec<Vec<Bezier>> {
+	let mut used = vec![false; edges.len()];
+	let mut loops = Vec::new();
+
+	for first_index in 0..edges.len() {
+		if used[first_index] {
+			continue;
+		}
+		used[first_index] = true;
+
+		let start_vertex = edges[first_index].start_vertex;
+		let mut loop_edges = vec![edges[first_index].bezier];
+		let mut current = first_index;
+		let mut closed = edges[first_index].end_vertex == start_vertex;
+
+		while !closed {
+			let incoming = &edges[current];
+			let reversed_incoming = -edge_direction_at(&incoming.bezier, 1.);
+			let reversed_incoming_chord = incoming.bezier.evaluate(TValue::Parametric(0.5)) - incoming.bezier.end();
+
+			let next = edges
+				.iter()
+				.enumerate()
+				.filter(|(index, edge)| !used[*index] && edge.start_vertex == incoming.end_vertex)
+				.min_by(|(_, first), (_, second)| {
+					let angle = |edge: &Edge| clockwise_angle(reversed_incoming, edge_direction_at(&edge.bezier, 0.));
+					let chord_angle = |edge: &Edge| clockwise_angle(reversed_incoming_chord, edge.bezier.evaluate(TValue::Parametric(0.5)) - edge.bezier.start());
+					let (first_angle, second_angle) = (angle(first), angle(second));
+					if (first_angle - second_angle).abs() < 1e-9 {
+						chord_angle(first).total_cmp(&chord_angle(second))
+					} else {
+						first_angle.total_cmp(&second_angle)
+					}
+				})
+				.map(|(index, _)| index);
+
+			let Some(next) = next else { break };
+			used[next] = true;
+			loop_edges.push(edges[next].bezier);
+			current = next;
+			closed = edges[next].end_vertex == start_vertex;
+		}
+
+		if closed {
+			loops.push(loop_edges);
+		}
+	}
+
+	loops
+}
+
+/// Returns the angle in the range (0, 2π] of the clockwise rotation (with +y as up) from the `from` direction to the `to` direction.
+fn clockwise_angle(from: DVec2, to: DVec2) -> f64 {
+	let counterclockwise = from.perp_dot(to).atan2(from.dot(to));
+	let clockwise = (-counterclockwise).rem_euclid(TAU);
+	if clockwise <= 0. {
+		TAU
+	} else {
+		clockwise
+	}
+}
+
+/// Creates a closed subpath from a loop of beziers where each one starts at the end of the previous one.
+fn subpath_from_loop<ManipulatorGroupId: crate::Identifier>(loop_edges: &[Bezier]) -> Option<Subpath<ManipulatorGroupId>> {
+	let beziers = match loop_edges {
+		[] => return None,
+		[single] => single.split(TValue::Parametric(0.5)).to_vec(),
+		_ => loop_edges.to_vec(),
+	};
+
+	let manipulator_groups = beziers
+		.iter()
+		.enumerate()
+		.map(|(index, bezier)| {
+			let previous = &beziers[(index + beziers.len() - 1) % beziers.len()];
+			ManipulatorGroup::new(bezier.start(), previous.handle_end(), bezier.handle_start())
+		})
+		.collect();
+
+	Some(Subpath::new(manipulator_groups, true))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::compare::compare_points;
+	use crate::utils::Join;
+	use crate::EmptyId;
+
+	fn square(min: DVec2, size: f64) -> Subpath<EmptyId> {
+		Subpath::new_rect(min, min + DVec2::splat(size))
+	}
+
+	fn contains(subpaths: &[Subpath<EmptyId>], point: DVec2) -> bool {
+		subpaths.iter().map(|subpath| subpath.iter().map(|bezier| bezier.winding(point)).sum::<i32>()).sum::<i32>() != 0
+	}
+
+	fn has_anchor(subpath: &Subpath<EmptyId>, point: DVec2) -> bool {
+		subpath.anchors().into_iter().any(|anchor| compare_points(anchor, point))
+	}
+
+	#[test]
+	fn union_of_overlapping_squares() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::splat(5.), 10.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+
+		assert_eq!(result.len(), 1);
+		assert!(result[0].closed());
+		assert_eq!(result[0].len(), 8);
+		for point in [
+			DVec2::ZERO,
+			DVec2::new(10., 0.),
+			DVec2::new(10., 5.),
+			DVec2::new(15., 5.),
+			DVec2::splat(15.),
+			DVec2::new(5., 15.),
+			DVec2::new(5., 10.),
+			DVec2::new(0., 10.),
+		] {
+			assert!(has_anchor(&result[0], point));
+		}
+		assert!(contains(&result, DVec2::splat(2.)));
+		assert!(contains(&result, DVec2::splat(12.)));
+		assert!(!contains(&result, DVec2::new(12., 2.)));
+	}
+
+	#[test]
+	fn intersection_of_overlapping_squares() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::splat(5.), 10.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero);
+
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].len(), 4);
+		for point in [DVec2::splat(5.), DVec2::new(10., 5.), DVec2::splat(10.), DVec2::new(5., 10.)] {
+			assert!(has_anchor(&result[0], point));
+		}
+	}
+
+	#[test]
+	fn difference_of_overlapping_squares() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::splat(5.), 10.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero);
+
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].len(), 6);
+		assert!(contains(&result, DVec2::splat(2.)));
+		assert!(!contains(&result, DVec2::splat(7.)));
+		assert!(!contains(&result, DVec2::splat(12.)));
+	}
+
+	#[test]
+	fn xor_of_overlapping_squares() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::splat(5.), 10.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Xor, FillRule::NonZero);
+
+		assert_eq!(result.len(), 2);
+		assert!(contains(&result, DVec2::splat(2.)));
+		assert!(contains(&result, DVec2::splat(12.)));
+		assert!(!contains(&result, DVec2::splat(7.)));
+	}
+
+	#[test]
+	fn squares_sharing_an_edge() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::new(10., 0.), 10.)];
+
+		let union = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+		assert_eq!(union.len(), 1);
+		assert!(!has_anchor(&union[0], DVec2::new(10., 5.)));
+		assert!(contains(&union, DVec2::new(5., 5.)));
+		assert!(contains(&union, DVec2::new(15., 5.)));
+		assert!(contains(&union, DVec2::new(10., 5.)));
+
+		let intersection = Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero);
+		assert!(intersection.is_empty());
+
+		let difference = Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero);
+		assert_eq!(difference.len(), 1);
+		assert_eq!(difference[0].len(), 4);
+		assert!(contains(&difference, DVec2::new(5., 5.)));
+		assert!(!contains(&difference, DVec2::new(15., 5.)));
+	}
+
+	#[test]
+	fn partially_shared_edge() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::new(10., 5.), 10.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+
+		assert_eq!(result.len(), 1);
+		assert!(has_anchor(&result[0], DVec2::new(10., 5.)));
+		assert!(has_anchor(&result[0], DVec2::new(10., 10.)));
+		assert!(contains(&result, DVec2::new(5., 5.)));
+		assert!(contains(&result, DVec2::new(15., 12.)));
+	}
+
+	#[test]
+	fn difference_creates_hole() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::splat(3.), 4.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero);
+
+		assert_eq!(result.len(), 2);
+		assert!(contains(&result, DVec2::splat(1.)));
+		assert!(!contains(&result, DVec2::splat(5.)));
+
+		// The hole is wound in the opposite direction, so it also works with the even-odd rule
+		let windings: Vec<i32> = result.iter().map(|subpath| subpath.iter().map(|bezier| bezier.winding(DVec2::splat(5.))).sum()).collect();
+		assert_eq!(windings.iter().sum::<i32>(), 0);
+		assert!(windings.iter().all(|winding| *winding != 0));
+	}
+
+	#[test]
+	fn disjoint_shapes() {
+		let a = [square(DVec2::ZERO, 10.)];
+		let b = [square(DVec2::splat(20.), 10.)];
+
+		assert!(Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero).is_empty());
+		assert_eq!(Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero).len(), 2);
+		assert_eq!(Subpath::boolean_operation(&a, &b, BooleanOperation::Difference, FillRule::NonZero).len(), 1);
+	}
+
+	#[test]
+	fn union_of_circles() {
+		let a = [Subpath::<EmptyId>::new_ellipse(DVec2::ZERO, DVec2::splat(20.))];
+		let b = [Subpath::<EmptyId>::new_ellipse(DVec2::new(10., 0.), DVec2::new(30., 20.))];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+
+		assert_eq!(result.len(), 1);
+		assert!(result[0].iter().all(|bezier| !matches!(bezier.handles, BezierHandles::Linear)));
+		assert!(has_anchor(&result[0], DVec2::new(15., 10. - 75_f64.sqrt())));
+		assert!(has_anchor(&result[0], DVec2::new(15., 10. + 75_f64.sqrt())));
+		assert!(contains(&result, DVec2::new(1., 9.)));
+		assert!(contains(&result, DVec2::new(29., 9.)));
+		assert!(!contains(&result, DVec2::new(15., 1.)));
+
+		let intersection = Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero);
+		assert_eq!(intersection.len(), 1);
+		assert!(contains(&intersection, DVec2::new(15., 9.)));
+		assert!(!contains(&intersection, DVec2::new(5., 9.)));
+		assert!(!contains(&intersection, DVec2::new(25., 9.)));
+	}
+
+	#[test]
+	fn fill_rules() {
+		// Two nested squares wound in the same direction, so the inner square is a hole only under the even-odd rule
+		let a = [square(DVec2::ZERO, 10.), square(DVec2::splat(3.), 4.)];
+		let b = [square(DVec2::splat(20.), 1.)];
+
+		let non_zero = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::NonZero);
+		assert_eq!(non_zero.len(), 2);
+		assert!(contains(&non_zero, DVec2::splat(5.)));
+
+		let even_odd = Subpath::boolean_operation(&a, &b, BooleanOperation::Union, FillRule::EvenOdd);
+		assert_eq!(even_odd.len(), 3);
+		assert!(contains(&even_odd, DVec2::splat(1.)));
+		assert!(!contains(&even_odd, DVec2::splat(5.)));
+	}
+
+	#[test]
+	fn open_subpaths_are_closed() {
+		let a = [Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::splat(10.), DVec2::new(0., 10.)], false)];
+		let b = [square(DVec2::splat(5.), 10.)];
+		let result = Subpath::boolean_operation(&a, &b, BooleanOperation::Intersection, FillRule::NonZero);
+
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].len(), 4);
+	}
+
+	#[test]
+	fn resolve_self_intersections_removes_backward_loop() {
+		// A figure eight, whose upper loop is wound counterclockwise and whose lower loop is wound clockwise
+		let figure_eight = Subpath::<EmptyId>::from_anchors([DVec2::ZERO, DVec2::new(10., 10.), DVec2::new(0., 10.), DVec2::new(10., 0.)], true);
+
+		let positive = figure_eight.resolve_self_intersections(true);
+		assert_eq!(positive.len(), 1);
+		assert!(contains(&positive, DVec2::new(5., 8.)));
+		assert!(!contains(&positive, DVec2::new(5., 2.)));
+		assert!(positive[0].signed_area() > 0.);
+
+		let negative = figure_eight.resolve_self_intersections(false);
+		assert_eq!(negative.len(), 1);
+		assert!(contains(&negative, DVec2::new(5., 2.)));
+		assert!(negative[0].signed_area() < 0.);
+	}
+
+	#[test]
+	fn resolve_self_intersections_merges_overlap() {
+		// A square wound twice around, with the second loop shifted so the two overlap
+		let anchors = [(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.), (15., 5.), (15., 15.), (5., 15.)].map(|(x, y)| DVec2::new(x, y));
+		let looped = Subpath::<EmptyId>::from_anchors(anchors, true);
+
+		let resolved = looped.resolve_self_intersections(true);
+		assert_eq!(resolved.len(), 1);
+		assert!(contains(&resolved, DVec2::splat(2.)));
+		assert!(contains(&resolved, DVec2::splat(12.)));
+		assert!(looped.resolve_self_intersections(false).is_empty());
+	}
+
+	#[test]
+	fn resolve_intersections_between_subpaths() {
+		// Two overlapping squares around a smaller square which is wound backwards to cut a hole, plus a backward square on its own
+		let hole = square(DVec2::splat(2.), 2.).reverse();
+		let stray = square(DVec2::splat(30.), 5.).reverse();
+		let subpaths = [square(DVec2::ZERO, 10.), square(DVec2::splat(5.), 10.), hole, stray];
+
+		let resolved = Subpath::resolve_intersections(&subpaths, true);
+		assert_eq!(resolved.len(), 2);
+		assert!(contains(&resolved, DVec2::splat(1.)));
+		assert!(contains(&resolved, DVec2::splat(12.)));
+		assert!(!contains(&resolved, DVec2::splat(3.)));
+		assert!(!contains(&resolved, DVec2::splat(32.)));
+	}
+
+	#[test]
+	fn resolve_inward_offset() {
+		// Two squares joined by a narrow bar, which disappears when the shape is offset inward by more than half of its width
+		let anchors = [
+			(0., 0.),
+			(10., 0.),
+			(10., 4.),
+			(20., 4.),
+			(20., 0.),
+			(30., 0.),
+			(30., 10.),
+			(20., 10.),
+			(20., 6.),
+			(10., 6.),
+			(10., 10.),
+			(0., 10.),
+		]
+		.map(|(x, y)| DVec2::new(x, y));
+		let dumbbell = Subpath::<EmptyId>::from_anchors(anchors, true);
+
+		for join in [Join::Miter(None), Join::Bevel, Join::Round] {
+			let resolved = dumbbell.offset(2., join).resolve_self_intersections(true);
+			assert_eq!(resolved.len(), 2);
+			assert!(contains(&resolved, DVec2::splat(5.)));
+			assert!(contains(&resolved, DVec2::new(25., 5.)));
+			assert!(!contains(&resolved, DVec2::new(15., 5.)));
+			assert!(!contains(&resolved, DVec2::splat(1.)));
+		}
+	}
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::consts::*;
 
-use glam::DVec2;
+use glam::{DAffine2, DVec2};
 use std::fmt::Write;
 
 /// Functionality relating to core `Subpath` operations, such as constructors and `iter`.
@@ -233,6 +233,100 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		Self::new(manipulator_groups, true)
 	}
 
+	/// Constructs an open elliptical arc out of cubic segments, which deviate from the true arc by at most `max_error`.
+	/// - `radii` - The radii of the ellipse along its own x and y axes.
+	/// - `rotation` - The angle in radians by which the x-axis of the ellipse is rotated.
+	/// - `start_angle` - The angle in radians, measured in the unrotated and unscaled frame of the ellipse, at which the arc starts.
+	/// - `sweep_angle` - The angle in radians which the arc spans, towards increasing angles if positive. Its magnitude is clamped to one full turn.
+	/// - `max_error` - The maximum distance between the arc and its approximation. The default value is `0.001`.
+	pub fn new_elliptical_arc(center: DVec2, radii: DVec2, rotation: f64, start_angle: f64, sweep_angle: f64, max_error: Option<f64>) -> Self {
+		let max_error = max_error.unwrap_or(DEFAULT_ELLIPTICAL_ARC_ERROR);
+		let sweep_angle = sweep_angle.clamp(-std::f64::consts::TAU, std::f64::consts::TAU);
+		let radii = radii.abs();
+		let ellipse_transform = DAffine2::from_angle_translation(rotation, center) * DAffine2::from_scale(radii);
+
+		// The maximum radial error of the cubic approximation of a unit circular arc spanning the angle `θ` is `(2 / 27) sin⁶(θ / 4) / cos²(θ / 4)`, which scales with the larger radius.
+		// Segments are never allowed to span more than a quarter turn, beyond which the approximation quickly degrades.
+		let segment_error = |angle: f64| radii.max_element() * 2. / 27. * (angle / 4.).sin().powi(6) / (angle / 4.).cos().powi(2);
+		let mut segment_count = ((sweep_angle.abs() / std::f64::consts::FRAC_PI_2).ceil() as usize).max(1);
+		while segment_error(sweep_angle.abs() / segment_count as f64) > max_error && segment_count < MAX_ELLIPTICAL_ARC_SEGMENTS {
+			segment_count += 1;
+		}
+
+		let segment_angle = sweep_angle / segment_count as f64;
+		let handle_length = 4. / 3. * (segment_angle / 4.).tan();
+		let point_and_handle_offset = |angle: f64| {
+			let (sin, cos) = angle.sin_cos();
+			let point = ellipse_transform.transform_point2(DVec2::new(cos, sin));
+			let handle_offset = ellipse_transform.transform_vector2(DVec2::new(-sin, cos) * handle_length);
+			(point, handle_offset)
+		};
+
+		let manipulator_groups = (0..=segment_count)
+			.map(|index| {
+				let (anchor, handle_offset) = point_and_handle_offset(start_angle + segment_angle * index as f64);
+				let in_handle = (index > 0).then_some(anchor - handle_offset);
+				let out_handle = (index < segment_count).then_some(anchor + handle_offset);
+				ManipulatorGroup::new(anchor, in_handle, out_handle)
+			})
+			.collect();
+		Self::new(manipulator_groups, false)
+	}
+
+	/// Constructs an open elliptical arc from `start` to `end` using the parameters of the SVG path `A` command, as described in <https://www.w3.org/TR/SVG2/paths.html#PathDataEllipticalArcCommands>.
+	/// - `radii` - The radii of the ellipse, which are scaled up if they are too small for the ellipse to reach from `start` to `end`. If either is zero, a straight line is produced.
+	/// - `x_axis_rotation` - The angle in radians by which the x-axis of the ellipse is rotated. Note that SVG specifies this angle in degrees.
+	/// - `large_arc` - Whether the arc spanning more than half of the ellipse is chosen.
+	/// - `sweep` - Whether the arc travels towards increasing angles, which is clockwise as displayed with the y-axis pointing down.
+	/// - `max_error` - The maximum distance between the arc and its approximation. The default value is `0.001`.
+	///
+	/// If `start` and `end` are the same point, the arc is omitted and a `Subpath` with just that point is returned.
+	pub fn from_svg_arc(start: DVec2, end: DVec2, radii: DVec2, x_axis_rotation: f64, large_arc: bool, sweep: bool, max_error: Option<f64>) -> Self {
+		if start.abs_diff_eq(end, f64::EPSILON) {
+			return Self::new(vec![ManipulatorGroup::new(start, None, None)], false);
+		}
+		let mut radii = radii.abs();
+		if radii.x == 0. || radii.y == 0. {
+			return Self::new(vec![ManipulatorGroup::new(start, None, None), ManipulatorGroup::new(end, None, None)], false);
+		}
+
+		// Conversion from endpoint to center parameterization, following <https://www.w3.org/TR/SVG2/implnote.html#ArcConversionEndpointToCenter>
+		let rotation = DAffine2::from_angle(x_axis_rotation);
+		let start_prime = rotation.inverse().transform_vector2((start - end) / 2.);
+
+		// Scale up radii which are too small to span the distance between the endpoints
+		let radii_scale = (start_prime / radii).length_squared();
+		if radii_scale > 1. {
+			radii *= radii_scale.sqrt();
+		}
+
+		let (rx_squared, ry_squared) = (radii.x * radii.x, radii.y * radii.y);
+		let (x_squared, y_squared) = (start_prime.x * start_prime.x, start_prime.y * start_prime.y);
+		let numerator = rx_squared * ry_squared - rx_squared * y_squared - ry_squared * x_squared;
+		let denominator = rx_squared * y_squared + ry_squared * x_squared;
+		let coefficient = (numerator / denominator).max(0.).sqrt() * if large_arc == sweep { -1. } else { 1. };
+		let center_prime = coefficient * DVec2::new(radii.x * start_prime.y / radii.y, -radii.y * start_prime.x / radii.x);
+		let center = rotation.transform_vector2(center_prime) + (start + end) / 2.;
+
+		let start_vector = (start_prime - center_prime) / radii;
+		let end_vector = (-start_prime - center_prime) / radii;
+		let start_angle = start_vector.y.atan2(start_vector.x);
+		let mut sweep_angle = start_vector.perp_dot(end_vector).atan2(start_vector.dot(end_vector));
+		if sweep && sweep_angle < 0. {
+			sweep_angle += std::f64::consts::TAU;
+		} else if !sweep && sweep_angle > 0. {
+			sweep_angle -= std::f64::consts::TAU;
+		}
+
+		let mut arc = Self::new_elliptical_arc(center, radii, x_axis_rotation, start_angle, sweep_angle, max_error);
+
+		// Place the endpoints exactly where they were requested, undoing any floating point drift
+		let last_index = arc.len() - 1;
+		arc.manipulator_groups[0].anchor = start;
+		arc.manipulator_groups[last_index].anchor = end;
+		arc
+	}
+
 	/// Constructs a regular polygon (ngon). Based on `sides` and `radius`, which is the distance from the center to any vertex.
 	pub fn new_regular_polygon(center: DVec2, sides: u64, radius: f64) -> Self {
 		let anchor_positions = (0..sides).map(|i| {
@@ -327,3 +421,92 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		subpath
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::{SubpathTValue, TValue};
+	use crate::{BezierHandles, EmptyId};
+
+	/// Returns the largest distance from the samples of each segment to the circle with the given center and radius.
+	fn max_distance_to_circle(subpath: &Subpath<EmptyId>, center: DVec2, radius: f64) -> f64 {
+		subpath
+			.iter()
+			.flat_map(|bezier| (0..=20).map(move |step| bezier.evaluate(TValue::Parametric(step as f64 / 20.))))
+			.map(|point| (point.distance(center) - radius).abs())
+			.fold(0., f64::max)
+	}
+
+	#[test]
+	fn svg_arc_small_and_large() {
+		let start = DVec2::new(100., 0.);
+		let end = DVec2::new(0., 100.);
+
+		let small: Subpath<EmptyId> = Subpath::from_svg_arc(start, end, DVec2::splat(100.), 0., false, true, None);
+		assert_eq!(small.manipulator_groups()[0].anchor, start);
+		assert_eq!(small.manipulator_groups().last().unwrap().anchor, end);
+		assert!(max_distance_to_circle(&small, DVec2::ZERO, 100.) < DEFAULT_ELLIPTICAL_ARC_ERROR);
+		assert!(small
+			.evaluate(SubpathTValue::GlobalParametric(0.5))
+			.abs_diff_eq(DVec2::splat(100. / 2_f64.sqrt()), MAX_ABSOLUTE_DIFFERENCE));
+
+		let large: Subpath<EmptyId> = Subpath::from_svg_arc(start, end, DVec2::splat(100.), 0., true, true, None);
+		assert!(max_distance_to_circle(&large, DVec2::splat(100.), 100.) < DEFAULT_ELLIPTICAL_ARC_ERROR);
+		assert!(large
+			.evaluate(SubpathTValue::GlobalParametric(0.5))
+			.abs_diff_eq(DVec2::splat(100. + 100. / 2_f64.sqrt()), MAX_ABSOLUTE_DIFFERENCE));
+
+		// Without the sweep flag, the arcs wind the other way around the opposite centers
+		let small_counterclockwise: Subpath<EmptyId> = Subpath::from_svg_arc(start, end, DVec2::splat(100.), 0., false, false, None);
+		assert!(max_distance_to_circle(&small_counterclockwise, DVec2::splat(100.), 100.) < DEFAULT_ELLIPTICAL_ARC_ERROR);
+	}
+
+	#[test]
+	fn svg_arc_with_radii_too_small() {
+		// The radii are scaled up until the ellipse spans the endpoints, resulting in a half circle
+		let arc: Subpath<EmptyId> = Subpath::from_svg_arc(DVec2::ZERO, DVec2::new(10., 0.), DVec2::splat(1.), 0., false, true, None);
+		assert!(max_distance_to_circle(&arc, DVec2::new(5., 0.), 5.) < DEFAULT_ELLIPTICAL_ARC_ERROR);
+		assert!(arc.evaluate(SubpathTValue::GlobalParametric(0.5)).abs_diff_eq(DVec2::new(5., -5.), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn svg_arc_rotated_ellipse_within_error() {
+		let rotation = std::f64::consts::FRAC_PI_6;
+		let radii = DVec2::new(200., 50.);
+		let center = DVec2::new(30., -20.);
+		let ellipse_transform = DAffine2::from_angle_translation(rotation, center) * DAffine2::from_scale(radii);
+		let start = ellipse_transform.transform_point2(DVec2::X);
+		let end = ellipse_transform.transform_point2(DVec2::NEG_X);
+
+		for max_error in [1., 1e-2, 1e-5] {
+			let arc: Subpath<EmptyId> = Subpath::from_svg_arc(start, end, radii, rotation, false, true, Some(max_error));
+			for point in arc.iter().flat_map(|bezier| (0..=20).map(move |step| bezier.evaluate(TValue::Parametric(step as f64 / 20.)))) {
+				// In the frame of the ellipse the arc lies on the unit circle, and distances from it are scaled by at least the smaller radius
+				let local = ellipse_transform.inverse().transform_point2(point);
+				assert!((local.length() - 1.).abs() * radii.y <= max_error);
+				assert!(local.y >= -MAX_ABSOLUTE_DIFFERENCE);
+			}
+		}
+	}
+
+	#[test]
+	fn svg_arc_degenerate() {
+		let start = DVec2::new(1., 2.);
+		let end = DVec2::new(3., 4.);
+
+		let line: Subpath<EmptyId> = Subpath::from_svg_arc(start, end, DVec2::new(0., 5.), 0., false, false, None);
+		assert_eq!(line.anchors(), vec![start, end]);
+		assert!(line.iter().all(|bezier| matches!(bezier.handles, BezierHandles::Linear)));
+
+		let point: Subpath<EmptyId> = Subpath::from_svg_arc(start, start, DVec2::splat(5.), 0., false, false, None);
+		assert_eq!(point.anchors(), vec![start]);
+	}
+
+	#[test]
+	fn elliptical_arc_full_turn() {
+		let circle: Subpath<EmptyId> = Subpath::new_elliptical_arc(DVec2::ZERO, DVec2::ONE, 0., 0., std::f64::consts::TAU, None);
+		assert_eq!(circle.len_segments(), 4);
+		assert!(circle.manipulator_groups()[0].anchor.abs_diff_eq(circle.manipulator_groups()[4].anchor, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(max_distance_to_circle(&circle, DVec2::ZERO, 1.) < DEFAULT_ELLIPTICAL_ARC_ERROR);
+	}
+}
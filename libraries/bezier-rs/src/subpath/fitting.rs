@@ -0,0 +1,654 @@
+use super::*;
+use crate::consts::{
+	DEFAULT_FITTING_CORNER_ANGLE, FITTING_REPARAMETERIZATION_ITERATIONS, SIMPLIFICATION_PROJECTION_ITERATIONS, SIMPLIFICATION_PROJECTION_SAMPLES, SIMPLIFICATION_SAMPLES_PER_SEGMENT,
+	STRICT_MAX_ABSOLUTE_DIFFERENCE,
+};
+use crate::utils::TValue;
+use crate::BezierHandles;
+
+use glam::DVec2;
+
+/// Functionality for approximating sequences of points, and other paths, with fewer smooth curves.
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Constructs an open [Subpath] made of as few cubic segments as possible, which passes within `tolerance` of every point in the sequence.
+	/// This is useful for turning noisy input, like the points of a freehand drawing, into a smooth curve.
+	/// The first and last points are always anchors of the resulting `Subpath`.
+	/// - `corner_angle` - Points where the direction of the sequence turns by more than this angle (in radians) are kept as sharp corners between segments. The default value is `π / 3`.
+	///
+	/// This implements the algorithm described in "An Algorithm for Automatically Fitting Digitized Curves" by Philip J. Schneider (Graphics Gems, 1990).
+	pub fn new_fitted_curve(points: &[DVec2], tolerance: f64, corner_angle: Option<f64>) -> Self {
+		let mut points = points.to_vec();
+		points.dedup();
+
+		match points.len() {
+			0 => return Subpath::new(vec![], false),
+			1 => return Subpath::new(vec![ManipulatorGroup::new(points[0], None, None)], false),
+			_ => {}
+		}
+
+		let tolerance = tolerance.max(f64::EPSILON);
+		let corners = find_corners(&points, tolerance, corner_angle.unwrap_or(DEFAULT_FITTING_CORNER_ANGLE));
+
+		let mut beziers = Vec::new();
+		for section in corners.windows(2) {
+			let section = &points[section[0]..=section[1]];
+			let start_tangent = end_tangent(section.iter(), tolerance);
+			let end_tangent = end_tangent(section.iter().rev(), tolerance);
+			fit_cubics(section, start_tangent, end_tangent, tolerance, &mut beziers);
+		}
+
+		Subpath::new(manipulator_groups_from_beziers(&beziers, false), false)
+	}
+
+	/// Constructs a closed [Subpath] which passes within `tolerance` of every point in the sequence, like [Subpath::new_fitted_curve], but treats the points as a loop that returns to the first point.
+	/// The curve stays smooth where the loop is closed, unless the direction of the sequence turns by more than `corner_angle` there.
+	pub fn new_fitted_closed_curve(points: &[DVec2], tolerance: f64, corner_angle: Option<f64>) -> Self {
+		let mut points = points.to_vec();
+		points.dedup();
+		while points.len() > 1 && points.first() == points.last() {
+			points.pop();
+		}
+		if points.len() < 3 {
+			return Subpath::from_anchors(points, true);
+		}
+
+		let tolerance = tolerance.max(f64::EPSILON);
+		let corner_angle = corner_angle.unwrap_or(DEFAULT_FITTING_CORNER_ANGLE);
+		let turning_angles: Vec<f64> = (0..points.len()).map(|index| cyclic_turning_angle(&points, index, tolerance * 2.).unwrap_or(0.)).collect();
+		let sharpest = (0..points.len()).max_by(|&a, &b| turning_angles[a].total_cmp(&turning_angles[b])).unwrap_or(0);
+
+		if turning_angles[sharpest] > corner_angle {
+			// Starting the loop at its sharpest corner makes every section between corners an open curve, which is fitted as usual
+			points.rotate_left(sharpest);
+			points.push(points[0]);
+			let open = Subpath::<ManipulatorGroupId>::new_fitted_curve(&points, tolerance, Some(corner_angle));
+			let beziers: Vec<Bezier> = open.iter().collect();
+			return Subpath::new(manipulator_groups_from_beziers(&beziers, true), true);
+		}
+
+		// Without any corners, both ends of the curve leave the first point along the same tangent so the loop closes smoothly
+		let window = tolerance * 2.;
+		let previous = cyclic_neighbor(&points, 0, window, false).unwrap_or(points[points.len() - 1]);
+		let next = cyclic_neighbor(&points, 0, window, true).unwrap_or(points[1]);
+		let tangent = (next - previous).try_normalize().unwrap_or_else(|| (points[1] - points[0]).normalize());
+		points.push(points[0]);
+
+		let mut beziers = Vec::new();
+		fit_cubics(&points, tangent, -tangent, tolerance, &mut beziers);
+		Subpath::new(manipulator_groups_from_beziers(&beziers, true), true)
+	}
+
+	/// Returns a copy of the `Subpath` with fewer manipulator groups, which stays within `tolerance` of the original shape.
+	/// Runs of adjacent segments are replaced by a single cubic or linear segment wherever it deviates from them by no more than `tolerance`.
+	/// This also removes anchors which lie on a straight line between their neighbors, and anchors which duplicate their neighbors.
+	/// The manipulator groups that are kept retain their ids, and the first anchor is never removed.
+	pub fn simplify(&self, tolerance: f64) -> Self {
+		let segments: Vec<Bezier> = self.iter().collect();
+		if segments.len() < 2 {
+			return self.clone();
+		}
+		let tolerance = tolerance.max(0.);
+
+		// Greedily extend each run of segments for as long as the merged segment stays within the tolerance
+		let mut runs = Vec::new();
+		let mut start = 0;
+		while start < segments.len() {
+			let mut end = start + 1;
+			let mut replacement = None;
+			while end < segments.len() {
+				let Some(merged) = merge_segments(&segments[start..=end], tolerance) else { break };
+				replacement = Some(merged);
+				end += 1;
+			}
+			runs.push((start, end, replacement));
+			start = end;
+		}
+
+		let groups = self.manipulator_groups();
+		let mut manipulator_groups = Vec::with_capacity(runs.len() + 1);
+		let mut previous_in_handle = None;
+		for (start, end, replacement) in runs {
+			let mut group = groups[start].clone();
+			if let Some(in_handle) = previous_in_handle {
+				group.in_handle = in_handle;
+			}
+			match replacement {
+				Some(bezier) => {
+					group.out_handle = bezier.handle_start();
+					previous_in_handle = Some(bezier.handle_end());
+				}
+				None => previous_in_handle = Some(groups[end % groups.len()].in_handle),
+			}
+			manipulator_groups.push(group);
+		}
+
+		let in_handle = previous_in_handle.flatten();
+		if self.closed() {
+			manipulator_groups[0].in_handle = in_handle;
+		} else {
+			let mut last = groups[groups.len() - 1].clone();
+			last.in_handle = in_handle;
+			manipulator_groups.push(last);
+		}
+
+		Subpath::new(manipulator_groups, self.closed())
+	}
+}
+
+/// Attempts to replace a run of consecutive segments with a single linear or cubic segment that stays within `tolerance` of them.
+/// The cubic segment leaves and arrives in the same directions as the run, so the smoothness at the ends of the run is preserved.
+fn merge_segments(segments: &[Bezier], tolerance: f64) -> Option<Bezier> {
+	let mut points: Vec<DVec2> = segments
+		.iter()
+		.flat_map(|segment| {
+			segment
+				.compute_lookup_table(Some(SIMPLIFICATION_SAMPLES_PER_SEGMENT), None)
+				.into_iter()
+				.take(SIMPLIFICATION_SAMPLES_PER_SEGMENT)
+		})
+		.collect();
+	points.push(segments[segments.len() - 1].end());
+
+	// Each segment is initially given a share of the parameter range proportional to its length, which is spread evenly over its samples
+	let lengths: Vec<f64> = segments.iter().map(|segment| segment.length(None)).collect();
+	let total_length: f64 = lengths.iter().sum();
+	let mut parameters = Vec::with_capacity(points.len());
+	let mut distance = 0.;
+	for length in lengths {
+		parameters.extend((0..SIMPLIFICATION_SAMPLES_PER_SEGMENT).map(|step| (distance + length * step as f64 / SIMPLIFICATION_SAMPLES_PER_SEGMENT as f64) / total_length));
+		distance += length;
+	}
+	parameters.push(1.);
+
+	let first = points[0];
+	let last = points[points.len() - 1];
+	if points.iter().all(|&point| distance_to_line_segment(point, first, last) <= tolerance) {
+		return Some(Bezier::from_linear_dvec2(first, last));
+	}
+
+	// Segments which were split from a single cubic are merged back into it exactly
+	let unsplit = segments[1..].iter().try_fold(segments[0], |merged, segment| unsplit(&merged, segment));
+	if let Some(bezier) = unsplit.filter(|bezier| within_tolerance(bezier, segments, tolerance)) {
+		return Some(bezier);
+	}
+
+	let control_points: Vec<DVec2> = segments.iter().flat_map(|segment| segment.get_points()).collect();
+	let start_tangent = direction_to_first_distinct(first, control_points.iter())?;
+	let end_tangent = direction_to_first_distinct(last, control_points.iter().rev())?;
+
+	let (bezier, _, _, _) = fit_cubic(&points, parameters, start_tangent, end_tangent, tolerance * tolerance);
+	within_tolerance(&bezier, segments, tolerance).then_some(bezier)
+}
+
+/// Reverses the subdivision of a cubic bezier, assuming the two cubic segments were split from it, which is indicated by the lengths of the handles on either side of their shared anchor.
+fn unsplit(first: &Bezier, second: &Bezier) -> Option<Bezier> {
+	let (
+		BezierHandles::Cubic {
+			handle_start,
+			handle_end: first_handle_end,
+		},
+		BezierHandles::Cubic {
+			handle_start: second_handle_start,
+			handle_end,
+		},
+	) = (first.handles, second.handles)
+	else {
+		return None;
+	};
+
+	let incoming = first.end().distance(first_handle_end);
+	let outgoing = second.start().distance(second_handle_start);
+	if incoming < STRICT_MAX_ABSOLUTE_DIFFERENCE || outgoing < STRICT_MAX_ABSOLUTE_DIFFERENCE {
+		return None;
+	}
+
+	// The split happened at the parametric `t` value where the handles on either side of the shared anchor divide the distance between them
+	let t = incoming / (incoming + outgoing);
+	let handle_start = first.start() + (handle_start - first.start()) / t;
+	let handle_end = second.end() + (handle_end - second.end()) / (1. - t);
+	Some(Bezier::from_cubic_dvec2(first.start(), handle_start, handle_end, second.end()))
+}
+
+/// Checks that samples of the segments lie within `tolerance` of the curve, and that the curve between the closest positions to neighboring samples also stays within `tolerance` of the segments.
+/// The latter catches loops and overshoots of the curve in between the samples.
+fn within_tolerance(bezier: &Bezier, segments: &[Bezier], tolerance: f64) -> bool {
+	segments.iter().all(|segment| {
+		let segment = segment.to_cubic();
+		let points = segment.compute_lookup_table(Some(SIMPLIFICATION_SAMPLES_PER_SEGMENT), None);
+		let parameters = closest_parameters(bezier, &points);
+		if points.iter().zip(&parameters).any(|(&point, &t)| bezier.evaluate(TValue::Parametric(t)).distance(point) > tolerance) {
+			return false;
+		}
+
+		let midpoints: Vec<DVec2> = parameters.windows(2).map(|pair| bezier.evaluate(TValue::Parametric((pair[0] + pair[1]) / 2.))).collect();
+		let segment_parameters = closest_parameters(&segment, &midpoints);
+		midpoints
+			.iter()
+			.zip(segment_parameters)
+			.all(|(&midpoint, t)| segment.evaluate(TValue::Parametric(t)).distance(midpoint) <= tolerance)
+	})
+}
+
+/// Finds the parametric `t` value of the closest position on the cubic curve to each point, by refining the closest of a set of samples of the curve with Newton's method.
+fn closest_parameters(bezier: &Bezier, points: &[DVec2]) -> Vec<f64> {
+	let samples = bezier.compute_lookup_table(Some(SIMPLIFICATION_PROJECTION_SAMPLES), None);
+	let mut parameters: Vec<f64> = points
+		.iter()
+		.map(|&point| {
+			let distances = samples.iter().map(|sample| sample.distance_squared(point));
+			let (index, _) = distances
+				.enumerate()
+				.fold((0, f64::INFINITY), |closest, (index, distance)| if distance < closest.1 { (index, distance) } else { closest });
+			index as f64 / SIMPLIFICATION_PROJECTION_SAMPLES as f64
+		})
+		.collect();
+
+	for _ in 0..SIMPLIFICATION_PROJECTION_ITERATIONS {
+		parameters = reparameterize(points, &parameters, bezier);
+	}
+	parameters
+}
+
+/// Returns the normalized direction from `anchor` to the first of the other points that doesn't coincide with it.
+fn direction_to_first_distinct<'a>(anchor: DVec2, mut others: impl Iterator<Item = &'a DVec2>) -> Option<DVec2> {
+	others
+		.find(|other| !other.abs_diff_eq(anchor, STRICT_MAX_ABSOLUTE_DIFFERENCE))
+		.map(|&other| (other - anchor).normalize())
+}
+
+/// Returns the distance from the point to the closest point on the line segment between `start` and `end`.
+fn distance_to_line_segment(point: DVec2, start: DVec2, end: DVec2) -> f64 {
+	let direction = end - start;
+	let length_squared = direction.length_squared();
+	if length_squared == 0. {
+		return point.distance(start);
+	}
+	let t = ((point - start).dot(direction) / length_squared).clamp(0., 1.);
+	point.distance(start + direction * t)
+}
+
+/// Returns the sorted indices of the points where the sequence is split into separately fitted sections, including the first and last points.
+/// A point is a corner if the direction of travel turns sharply there, measured against neighbors at least `tolerance` away to ignore small-scale noise.
+fn find_corners(points: &[DVec2], tolerance: f64, corner_angle: f64) -> Vec<usize> {
+	let window = tolerance * 2.;
+	let turning_angles: Vec<Option<f64>> = (0..points.len())
+		.map(|index| {
+			let point = points[index];
+			let previous = points[..index].iter().rev().find(|other| other.distance(point) >= window)?;
+			let next = points[index + 1..].iter().find(|other| other.distance(point) >= window)?;
+			Some((point - *previous).angle_between(*next - point).abs())
+		})
+		.collect();
+
+	// Among neighboring points that turn sharply, only the sharpest turn is made into a corner
+	let is_sharp = |index: usize| turning_angles[index].map_or(false, |angle| angle > corner_angle);
+	let mut corners = vec![0];
+	let mut index = 1;
+	while index < points.len() - 1 {
+		if !is_sharp(index) {
+			index += 1;
+			continue;
+		}
+
+		let mut sharpest = index;
+		while index < points.len() - 1 && is_sharp(index) {
+			if turning_angles[index] > turning_angles[sharpest] {
+				sharpest = index;
+			}
+			index += 1;
+		}
+		corners.push(sharpest);
+	}
+	corners.push(points.len() - 1);
+
+	corners
+}
+
+/// Returns the first point at least `window` away from the point at `index`, searching forward or backward around the loop of points.
+fn cyclic_neighbor(points: &[DVec2], index: usize, window: f64, forward: bool) -> Option<DVec2> {
+	let point = points[index];
+	(1..points.len())
+		.map(|offset| {
+			if forward {
+				(index + offset) % points.len()
+			} else {
+				(index + points.len() - offset) % points.len()
+			}
+		})
+		.map(|other| points[other])
+		.find(|other| other.distance(point) >= window)
+}
+
+/// Returns how sharply the direction of travel turns at the point at `index` in the loop of points, like the turning angles of [find_corners].
+fn cyclic_turning_angle(points: &[DVec2], index: usize, window: f64) -> Option<f64> {
+	let previous = cyclic_neighbor(points, index, window, false)?;
+	let next = cyclic_neighbor(points, index, window, true)?;
+	Some((points[index] - previous).angle_between(next - points[index]).abs())
+}
+
+/// Joins consecutive fitted beziers into manipulator groups. For a closed loop, the end of the last bezier is merged into the start of the first one.
+fn manipulator_groups_from_beziers<ManipulatorGroupId: crate::Identifier>(beziers: &[Bezier], closed: bool) -> Vec<ManipulatorGroup<ManipulatorGroupId>> {
+	let first_in_handle = if closed { beziers.last().and_then(|last| last.handle_end()) } else { None };
+	let mut manipulator_groups = vec![ManipulatorGroup::new(beziers[0].start(), first_in_handle, beziers[0].handle_start())];
+	let ends = if closed { &beziers[..beziers.len() - 1] } else { beziers };
+	for (index, bezier) in ends.iter().enumerate() {
+		let out_handle = beziers.get(index + 1).and_then(|next| next.handle_start());
+		manipulator_groups.push(ManipulatorGroup::new(bezier.end(), bezier.handle_end(), out_handle));
+	}
+	manipulator_groups
+}
+
+/// Estimates the normalized direction in which the sequence leaves its first point, based on the first point that is at least `tolerance` away from it.
+fn end_tangent<'a>(mut points: impl Iterator<Item = &'a DVec2>, tolerance: f64) -> DVec2 {
+	let first = *points.next().unwrap();
+	let mut last = first;
+	for &point in points {
+		last = point;
+		if point.distance(first) >= tolerance {
+			break;
+		}
+	}
+	(last - first).normalize()
+}
+
+/// Fits cubic beziers to the points, which start and end in the directions of the given tangents, and appends them to `beziers`.
+/// Sections which can't be fitted by a single cubic within the tolerance are split at the point with the largest error and fitted recursively.
+fn fit_cubics(points: &[DVec2], start_tangent: DVec2, end_tangent: DVec2, tolerance: f64, beziers: &mut Vec<Bezier>) {
+	let first = points[0];
+	let last = points[points.len() - 1];
+
+	if points.len() == 2 {
+		let handle_distance = first.distance(last) / 3.;
+		beziers.push(Bezier::from_cubic_dvec2(first, first + start_tangent * handle_distance, last + end_tangent * handle_distance, last));
+		return;
+	}
+
+	// Before resorting to splitting, the fit is improved by moving the parameters of the points closer to their nearest points on the curve
+	let max_squared_error = tolerance * tolerance;
+	let (bezier, _, squared_error, split_index) = fit_cubic(points, chord_length_parameters(points), start_tangent, end_tangent, max_squared_error);
+	if squared_error <= max_squared_error {
+		beziers.push(bezier);
+		return;
+	}
+
+	// Split at the worst fitting point, where both halves share a tangent so the curve remains smooth
+	let center_tangent = (points[split_index - 1] - points[split_index + 1])
+		.try_normalize()
+		.unwrap_or_else(|| (points[split_index - 1] - points[split_index]).normalize());
+	fit_cubics(&points[..=split_index], start_tangent, center_tangent, tolerance, beziers);
+	fit_cubics(&points[split_index..], -center_tangent, end_tangent, tolerance, beziers);
+}
+
+/// Finds the cubic bezier with handles along the given tangents which best fits the points, reparameterizing the points until the largest squared error is within `max_squared_error` or the iteration limit is reached.
+/// Returns the curve, the parameters of the points on it, and the largest squared error along with the index of the point where it occurs.
+fn fit_cubic(points: &[DVec2], mut parameters: Vec<f64>, start_tangent: DVec2, end_tangent: DVec2, max_squared_error: f64) -> (Bezier, Vec<f64>, f64, usize) {
+	let mut bezier = generate_bezier(points, &parameters, start_tangent, end_tangent);
+	let (mut squared_error, mut index) = max_squared_error_point(points, &parameters, &bezier);
+
+	for _ in 0..FITTING_REPARAMETERIZATION_ITERATIONS {
+		if squared_error <= max_squared_error {
+			break;
+		}
+		parameters = reparameterize(points, &parameters, &bezier);
+		bezier = generate_bezier(points, &parameters, start_tangent, end_tangent);
+		(squared_error, index) = max_squared_error_point(points, &parameters, &bezier);
+	}
+
+	(bezier, parameters, squared_error, index)
+}
+
+/// Assigns each point a parametric `t` value proportional to its distance along the polyline through the points.
+fn chord_length_parameters(points: &[DVec2]) -> Vec<f64> {
+	let mut parameters = Vec::with_capacity(points.len());
+	let mut distance = 0.;
+	parameters.push(0.);
+	for pair in points.windows(2) {
+		distance += pair[0].distance(pair[1]);
+		parameters.push(distance);
+	}
+	parameters.iter_mut().for_each(|parameter| *parameter /= distance);
+	parameters
+}
+
+/// Finds the cubic bezier between the first and last points, with handles along the given tangents, which best fits the points at their parameters in the least-squares sense.
+fn generate_bezier(points: &[DVec2], parameters: &[f64], start_tangent: DVec2, end_tangent: DVec2) -> Bezier {
+	let first = points[0];
+	let last = points[points.len() - 1];
+
+	let mut c = [[0.; 2]; 2];
+	let mut x = [0.; 2];
+	for (&point, &t) in points.iter().zip(parameters) {
+		let one_minus_t = 1. - t;
+		let [b0, b1, b2, b3] = [one_minus_t.powi(3), 3. * t * one_minus_t.powi(2), 3. * t.powi(2) * one_minus_t, t.powi(3)];
+		let a = [start_tangent * b1, end_tangent * b2];
+
+		c[0][0] += a[0].dot(a[0]);
+		c[0][1] += a[0].dot(a[1]);
+		c[1][1] += a[1].dot(a[1]);
+
+		let remainder = point - (first * (b0 + b1) + last * (b2 + b3));
+		x[0] += a[0].dot(remainder);
+		x[1] += a[1].dot(remainder);
+	}
+	c[1][0] = c[0][1];
+
+	let determinant = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+	let (alpha_start, alpha_end) = if determinant.abs() > f64::EPSILON {
+		((x[0] * c[1][1] - x[1] * c[0][1]) / determinant, (c[0][0] * x[1] - c[1][0] * x[0]) / determinant)
+	} else {
+		(0., 0.)
+	};
+
+	// Fall back to a heuristic if the solution has handles that are too short or point backwards
+	let segment_length = first.distance(last);
+	let epsilon = 1e-6 * segment_length;
+	let (alpha_start, alpha_end) = if alpha_start < epsilon || alpha_end < epsilon {
+		(segment_length / 3., segment_length / 3.)
+	} else {
+		(alpha_start, alpha_end)
+	};
+
+	Bezier::from_cubic_dvec2(first, first + start_tangent * alpha_start, last + end_tangent * alpha_end, last)
+}
+
+/// Returns the largest squared distance between a point and the curve at the point's parameter, along with the index of that point.
+fn max_squared_error_point(points: &[DVec2], parameters: &[f64], bezier: &Bezier) -> (f64, usize) {
+	let mut max = (0., points.len() / 2);
+	for index in 1..points.len() - 1 {
+		let squared_error = bezier.evaluate(TValue::Parametric(parameters[index])).distance_squared(points[index]);
+		if squared_error >= max.0 {
+			max = (squared_error, index);
+		}
+	}
+	max
+}
+
+/// Improves the parameter of each point with a step of Newton's method towards the parameter of the closest point on the curve.
+fn reparameterize(points: &[DVec2], parameters: &[f64], bezier: &Bezier) -> Vec<f64> {
+	let first_derivative = bezier.derivative().unwrap();
+	let second_derivative = first_derivative.derivative().unwrap();
+
+	points
+		.iter()
+		.zip(parameters)
+		.map(|(&point, &t)| {
+			let offset = bezier.evaluate(TValue::Parametric(t)) - point;
+			let velocity = first_derivative.evaluate(TValue::Parametric(t));
+			let acceleration = second_derivative.evaluate(TValue::Parametric(t));
+
+			let numerator = offset.dot(velocity);
+			let denominator = velocity.dot(velocity) + offset.dot(acceleration);
+			if denominator.abs() < f64::EPSILON {
+				t
+			} else {
+				(t - numerator / denominator).clamp(0., 1.)
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::EmptyId;
+
+	/// Returns the largest distance from any of the points to the closest point on the subpath.
+	fn max_distance(subpath: &Subpath<EmptyId>, points: &[DVec2]) -> f64 {
+		let samples: Vec<DVec2> = subpath.iter().flat_map(|bezier| bezier.compute_lookup_table(Some(1000), None)).collect();
+		points
+			.iter()
+			.map(|point| samples.iter().map(|sample| sample.distance(*point)).fold(f64::INFINITY, f64::min))
+			.fold(0., f64::max)
+	}
+
+	#[test]
+	fn fit_points_on_cubic() {
+		let bezier = Bezier::from_cubic_coordinates(0., 0., 30., 80., 70., 80., 100., 0.);
+		let points: Vec<DVec2> = bezier.compute_lookup_table(Some(50), None);
+		let subpath = Subpath::<EmptyId>::new_fitted_curve(&points, 0.5, None);
+
+		assert_eq!(subpath.len_segments(), 1);
+		assert_eq!(subpath.manipulator_groups()[0].anchor, points[0]);
+		assert_eq!(subpath.manipulator_groups()[1].anchor, points[50]);
+		assert!(max_distance(&subpath, &points) <= 0.5);
+	}
+
+	#[test]
+	fn fit_noisy_points_within_tolerance() {
+		// A sine wave with some deterministic noise added
+		let points: Vec<DVec2> = (0..=200)
+			.map(|index| {
+				let x = index as f64;
+				let noise = ((index * 7919) % 13) as f64 / 13. - 0.5;
+				DVec2::new(x, (x / 20.).sin() * 40. + noise * 0.5)
+			})
+			.collect();
+		let subpath = Subpath::<EmptyId>::new_fitted_curve(&points, 1., None);
+
+		assert!(subpath.len_segments() > 1);
+		assert!(subpath.len_segments() < 20);
+		assert!(max_distance(&subpath, &points) <= 1.);
+	}
+
+	#[test]
+	fn fit_preserves_corners() {
+		// An "L" shape sampled every unit, with its corner at (50, 50)
+		let points: Vec<DVec2> = (0..=50).map(|x| DVec2::new(x as f64, 50.)).chain((0..50).rev().map(|y| DVec2::new(50., y as f64))).collect();
+		let subpath = Subpath::<EmptyId>::new_fitted_curve(&points, 0.5, None);
+
+		let corner = subpath
+			.manipulator_groups()
+			.iter()
+			.find(|group| group.anchor == DVec2::new(50., 50.))
+			.expect("The corner should be an anchor");
+		let in_direction = (corner.anchor - corner.in_handle.unwrap()).normalize();
+		let out_direction = (corner.out_handle.unwrap() - corner.anchor).normalize();
+		assert!(in_direction.abs_diff_eq(DVec2::X, 1e-6));
+		assert!(out_direction.abs_diff_eq(DVec2::NEG_Y, 1e-6));
+		assert!(max_distance(&subpath, &points) <= 0.5);
+	}
+
+	#[test]
+	fn fit_straight_line() {
+		let points: Vec<DVec2> = (0..=20).map(|index| DVec2::new(index as f64 * 5., index as f64 * 2.)).collect();
+		let subpath = Subpath::<EmptyId>::new_fitted_curve(&points, 0.1, None);
+
+		assert_eq!(subpath.len_segments(), 1);
+		assert!(max_distance(&subpath, &points) <= 0.1);
+	}
+
+	#[test]
+	fn fit_closed_loops() {
+		// Points around a circle, starting partway around it so the seam has no special meaning
+		let points: Vec<DVec2> = (0..64).map(|index| DVec2::from_angle(index as f64 / 64. * std::f64::consts::TAU + 0.3) * 50.).collect();
+		let subpath = Subpath::<EmptyId>::new_fitted_closed_curve(&points, 0.5, None);
+
+		assert!(subpath.closed());
+		assert!(max_distance(&subpath, &points) <= 0.5 + 1e-6);
+		let seam = &subpath.manipulator_groups()[0];
+		let in_direction = (seam.anchor - seam.in_handle.unwrap()).normalize();
+		let out_direction = (seam.out_handle.unwrap() - seam.anchor).normalize();
+		assert!(in_direction.abs_diff_eq(out_direction, 1e-6), "The loop should be smooth where it closes");
+
+		// A square keeps its four corners, wherever the sequence starts
+		let side = |start: DVec2, direction: DVec2| (0..10).map(move |step| start + direction * step as f64 * 5.);
+		let square: Vec<DVec2> = side(DVec2::new(25., 0.), DVec2::Y)
+			.chain(side(DVec2::new(25., 50.), -DVec2::X))
+			.chain(side(DVec2::new(-25., 50.), -DVec2::Y))
+			.chain(side(DVec2::new(-25., 0.), DVec2::X))
+			.collect();
+		let subpath = Subpath::<EmptyId>::new_fitted_closed_curve(&square[5..].iter().chain(&square[..5]).copied().collect::<Vec<_>>(), 0.5, None);
+		assert!(subpath.closed());
+		for corner in [DVec2::new(25., 50.), DVec2::new(-25., 50.), DVec2::new(-25., 0.), DVec2::new(25., 0.)] {
+			assert!(subpath.manipulator_groups().iter().any(|group| group.anchor.abs_diff_eq(corner, 1e-6)), "Missing corner {corner}");
+		}
+		assert!(max_distance(&subpath, &square) <= 0.5 + 1e-6);
+	}
+
+	#[test]
+	fn simplify_collinear_and_duplicate_anchors() {
+		let anchors = [DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 0.), DVec2::new(20., 0.), DVec2::new(50., 0.), DVec2::new(50., 30.)];
+		let subpath = Subpath::<EmptyId>::from_anchors(anchors, false).simplify(0.01);
+
+		let anchors: Vec<DVec2> = subpath.anchors();
+		assert_eq!(anchors, vec![DVec2::ZERO, DVec2::new(50., 0.), DVec2::new(50., 30.)]);
+		assert!(matches!(subpath.iter().next().unwrap().handles, BezierHandles::Linear));
+	}
+
+	#[test]
+	fn simplify_merges_split_cubic() {
+		let bezier = Bezier::from_cubic_coordinates(0., 0., 30., 80., 70., 80., 100., 0.);
+		let [first, rest] = bezier.split(TValue::Parametric(0.3));
+		let [second, third] = rest.split(TValue::Parametric(0.6));
+		let original = Subpath::<EmptyId>::from_beziers(&[first, second, third], false);
+		let simplified = original.simplify(0.01);
+
+		assert_eq!(simplified.len_segments(), 1);
+		assert_eq!(simplified.manipulator_groups()[0].id, original.manipulator_groups()[0].id);
+		let points = bezier.compute_lookup_table(Some(100), None);
+		assert!(max_distance(&simplified, &points) <= 0.01);
+	}
+
+	#[test]
+	fn simplify_subdivided_ellipse_within_tolerance() {
+		let ellipse = Subpath::<EmptyId>::new_ellipse(DVec2::ZERO, DVec2::new(100., 60.));
+		let pieces: Vec<Bezier> = ellipse
+			.iter()
+			.flat_map(|bezier| {
+				let [first, rest] = bezier.split(TValue::Parametric(0.25));
+				let [second, rest] = rest.split(TValue::Parametric(1. / 3.));
+				let [third, fourth] = rest.split(TValue::Parametric(0.5));
+				[first, second, third, fourth]
+			})
+			.collect();
+		let original = Subpath::<EmptyId>::from_beziers(&pieces, true);
+		let simplified = original.simplify(0.05);
+
+		assert!(simplified.closed());
+		assert!(simplified.len_segments() <= 8);
+		let points: Vec<DVec2> = original.iter().flat_map(|bezier| bezier.compute_lookup_table(Some(20), None)).collect();
+		assert!(max_distance(&simplified, &points) <= 0.05);
+	}
+
+	#[test]
+	fn simplify_preserves_corners() {
+		let rectangle = Subpath::<EmptyId>::new_rect(DVec2::ZERO, DVec2::new(40., 20.));
+		let simplified = rectangle.simplify(1.);
+
+		assert!(simplified.closed());
+		assert_eq!(simplified.anchors(), rectangle.anchors());
+	}
+
+	#[test]
+	fn fit_degenerate_inputs() {
+		assert!(Subpath::<EmptyId>::new_fitted_curve(&[], 1., None).is_empty());
+
+		let point = DVec2::new(3., 4.);
+		let subpath = Subpath::<EmptyId>::new_fitted_curve(&[point, point, point], 1., None);
+		assert_eq!(subpath.len(), 1);
+		assert_eq!(subpath.manipulator_groups()[0].anchor, point);
+
+		let subpath = Subpath::<EmptyId>::new_fitted_curve(&[DVec2::ZERO, DVec2::new(10., 0.)], 1., None);
+		assert_eq!(subpath.len_segments(), 1);
+	}
+}
@@ -1,7 +1,7 @@
 use super::*;
-use crate::consts::{DEFAULT_EUCLIDEAN_ERROR_BOUND, DEFAULT_LUT_STEP_SIZE};
+use crate::consts::{DEFAULT_ARC_LENGTH_SAMPLES_PER_SEGMENT, DEFAULT_EUCLIDEAN_ERROR_BOUND, DEFAULT_LUT_STEP_SIZE, GAUSS_LEGENDRE_5};
 use crate::utils::{SubpathTValue, TValue, TValueType};
-use crate::ProjectionOptions;
+use crate::{BezierHandles, ProjectionOptions};
 use glam::DVec2;
 
 /// Functionality relating to looking up properties of the `Subpath` or points along the `Subpath`.
@@ -31,6 +31,29 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		self.iter().fold(0., |accumulator, bezier| accumulator + bezier.length(num_subdivisions))
 	}
 
+	/// Return an [ArcLengthTable] which maps between distances along the `Subpath` and parametric positions on its segments.
+	/// - `samples_per_segment` - Number of samples taken along each segment, which determines the accuracy of the table. The default value is `100`.
+	pub fn arc_length_table(&self, samples_per_segment: Option<usize>) -> ArcLengthTable {
+		ArcLengthTable::new(self.iter(), samples_per_segment.unwrap_or(DEFAULT_ARC_LENGTH_SAMPLES_PER_SEGMENT))
+	}
+
+	/// Return an iterator over the points and tangents spaced `spacing` apart along the length of the `Subpath`, starting at its first anchor.
+	/// The end of the `Subpath` is only included if its length is a multiple of `spacing`.
+	/// If `spacing` is not a positive number, only the starting point is produced.
+	pub fn arc_length_samples(&self, spacing: f64) -> ArcLengthSamples<'_, ManipulatorGroupId> {
+		self.arc_length_samples_from_table(self.arc_length_table(None), spacing)
+	}
+
+	/// Like [Subpath::arc_length_samples], but reuses a `table` already built by [Subpath::arc_length_table] for this `Subpath`, such as one used to measure its length first.
+	pub fn arc_length_samples_from_table(&self, table: ArcLengthTable, spacing: f64) -> ArcLengthSamples<'_, ManipulatorGroupId> {
+		ArcLengthSamples {
+			index: 0,
+			spacing,
+			table,
+			subpath: self,
+		}
+	}
+
 	fn global_euclidean_to_local_euclidean(&self, global_t: f64) -> (usize, f64) {
 		let lengths = self.iter().map(|bezier| bezier.length(None)).collect::<Vec<f64>>();
 		let total_length: f64 = lengths.iter().sum();
@@ -92,6 +115,7 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 				let (segment_index, segment_t) = self.global_euclidean_to_local_euclidean(t);
 				(segment_index, self.get_segment(segment_index).unwrap().euclidean_to_parametric(segment_t, error))
 			}
+			SubpathTValue::GlobalEuclideanDistance(distance) => self.arc_length_table(None).distance_to_parametric(distance),
 		}
 	}
 
@@ -116,6 +140,85 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 
 		Some((index, project_t))
 	}
+
+	/// Returns the segments of the `Subpath` followed by the straight line back to its start which is implied when filling an open `Subpath`.
+	fn filled_segments(&self) -> impl Iterator<Item = Bezier> + '_ {
+		let closing_line = match (self.closed, self.manipulator_groups.first(), self.manipulator_groups.last()) {
+			(false, Some(first), Some(last)) => Some(Bezier::from_linear_dvec2(last.anchor, first.anchor)),
+			_ => None,
+		};
+		self.iter().chain(closing_line)
+	}
+
+	/// Return the signed area enclosed by the `Subpath`, computed exactly from its control points with Green's theorem.
+	/// The area is positive when the `Subpath` winds clockwise as displayed with the y-axis pointing down, like in SVG, and negative when it winds counterclockwise.
+	/// Open subpaths are treated as if they were closed by a straight line, like when they are filled.
+	/// Regions of a self-intersecting `Subpath` which wind in opposite directions cancel each other out.
+	pub fn signed_area(&self) -> f64 {
+		self.filled_segments().map(|bezier| segment_signed_area(&bezier)).sum()
+	}
+
+	/// Return the area enclosed by the `Subpath`, regardless of its winding direction.
+	/// Open subpaths are treated as if they were closed by a straight line, like when they are filled.
+	pub fn area(&self) -> f64 {
+		self.signed_area().abs()
+	}
+
+	/// Return the centroid (center of mass) of the area enclosed by the `Subpath`, or `None` if it encloses no area.
+	/// Open subpaths are treated as if they were closed by a straight line, like when they are filled.
+	pub fn centroid(&self) -> Option<DVec2> {
+		let area = self.signed_area();
+		if area.abs() < f64::EPSILON {
+			return None;
+		}
+
+		// By Green's theorem, the first moments of the area are a third of the integral of `p * (p × p')` along the boundary.
+		// That integrand is a polynomial of at most degree 8, so the quadrature is exact.
+		let moment = self
+			.filled_segments()
+			.map(|bezier| {
+				GAUSS_LEGENDRE_5
+					.iter()
+					.map(|(node, weight)| {
+						let t = (node + 1.) / 2.;
+						let point = bezier.evaluate(TValue::Parametric(t));
+						point * point.perp_dot(bezier.non_normalized_tangent(t)) * *weight
+					})
+					.sum::<DVec2>() / 2.
+			})
+			.sum::<DVec2>()
+			/ 3.;
+
+		Some(moment / area)
+	}
+
+	/// Returns true if the `Subpath` winds clockwise as displayed with the y-axis pointing down, like in SVG, meaning its [Subpath::signed_area] is positive.
+	pub fn is_clockwise(&self) -> bool {
+		self.signed_area() > 0.
+	}
+
+	/// Returns the `Subpath`, reversed if necessary so that it winds in the requested direction as described by [Subpath::is_clockwise].
+	/// A `Subpath` without any enclosed area is returned unchanged.
+	pub fn reverse_to_orientation(&self, clockwise: bool) -> Subpath<ManipulatorGroupId> {
+		let signed_area = self.signed_area();
+		if signed_area != 0. && (signed_area > 0.) != clockwise {
+			self.reverse()
+		} else {
+			self.clone()
+		}
+	}
+}
+
+/// Returns the integral of `(x dy - y dx) / 2` along the segment, which is the segment's contribution to the signed area of a closed path by Green's theorem.
+fn segment_signed_area(bezier: &Bezier) -> f64 {
+	let (p0, p3) = (bezier.start, bezier.end);
+	match bezier.handles {
+		BezierHandles::Linear => p0.perp_dot(p3) / 2.,
+		BezierHandles::Quadratic { handle: p1 } => (2. * p0.perp_dot(p1) + p0.perp_dot(p3) + 2. * p1.perp_dot(p3)) / 6.,
+		BezierHandles::Cubic { handle_start: p1, handle_end: p2 } => {
+			(6. * p0.perp_dot(p1) + 3. * p0.perp_dot(p2) + p0.perp_dot(p3) + 3. * p1.perp_dot(p2) + 3. * p1.perp_dot(p3) + 6. * p2.perp_dot(p3)) / 20.
+		}
+	}
 }
 
 #[cfg(test)]
@@ -264,4 +367,133 @@ mod tests {
 		assert_eq!(subpath.evaluate(SubpathTValue::GlobalEuclidean(0.0)), start);
 		assert_eq!(subpath.evaluate(SubpathTValue::GlobalEuclidean(1.0)), end);
 	}
+
+	#[test]
+	fn arc_length_table_lines() {
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(0., 0.), DVec2::new(30., 0.), DVec2::new(30., 10.)], false);
+		let table = subpath.arc_length_table(None);
+
+		assert!(f64_compare(table.total_length(), 40., MAX_ABSOLUTE_DIFFERENCE));
+		assert_eq!(table.len_segments(), 2);
+
+		let (segment_index, t) = table.distance_to_parametric(15.);
+		assert_eq!(segment_index, 0);
+		assert!(f64_compare(t, 0.5, MAX_ABSOLUTE_DIFFERENCE));
+
+		let (segment_index, t) = table.distance_to_parametric(35.);
+		assert_eq!(segment_index, 1);
+		assert!(f64_compare(t, 0.5, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(table.parametric_to_distance(1, 0.5), 35., MAX_ABSOLUTE_DIFFERENCE));
+
+		// Distances outside of the subpath are clamped to its ends
+		assert_eq!(table.distance_to_parametric(-5.), (0, 0.));
+		assert_eq!(table.distance_to_parametric(100.), (1, 1.));
+	}
+
+	#[test]
+	fn global_euclidean_distance() {
+		let subpath: Subpath<EmptyId> = Subpath::new_rect(DVec2::new(0., 0.), DVec2::new(20., 10.));
+
+		assert!(subpath.evaluate(SubpathTValue::GlobalEuclideanDistance(25.)).abs_diff_eq(DVec2::new(20., 5.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(subpath.evaluate(SubpathTValue::GlobalEuclideanDistance(55.)).abs_diff_eq(DVec2::new(0., 5.), MAX_ABSOLUTE_DIFFERENCE));
+
+		let trimmed = subpath.trim(SubpathTValue::GlobalEuclideanDistance(10.), SubpathTValue::GlobalEuclideanDistance(35.));
+		assert!(f64_compare(trimmed.length(None), 25., MAX_ABSOLUTE_DIFFERENCE));
+
+		// Looking up values through a precomputed table gives the same results
+		let table = subpath.arc_length_table(None);
+		assert_eq!(subpath.evaluate(table.t_value(25.)), subpath.evaluate(SubpathTValue::GlobalEuclideanDistance(25.)));
+	}
+
+	#[test]
+	fn global_euclidean_distance_curve() {
+		let bezier = Bezier::from_cubic_coordinates(10., 10., 30., 100., 70., -40., 90., 50.);
+		let subpath: Subpath<EmptyId> = Subpath::from_bezier(&bezier);
+		let length = bezier.length(None);
+
+		let (first, _) = subpath.split(SubpathTValue::GlobalEuclideanDistance(length / 3.));
+		assert!(f64_compare(first.length(None), length / 3., 0.01));
+	}
+
+	#[test]
+	fn arc_length_samples() {
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(0., 0.), DVec2::new(30., 0.), DVec2::new(30., 10.)], false);
+		let samples: Vec<_> = subpath.arc_length_samples(10.).collect();
+
+		assert_eq!(samples.len(), 5);
+		for (index, sample) in samples.iter().enumerate() {
+			assert!(f64_compare(sample.distance, index as f64 * 10., MAX_ABSOLUTE_DIFFERENCE));
+		}
+		assert!(samples[1].point.abs_diff_eq(DVec2::new(10., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(samples[1].tangent.abs_diff_eq(DVec2::new(1., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(samples[4].point.abs_diff_eq(DVec2::new(30., 10.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(samples[4].tangent.abs_diff_eq(DVec2::new(0., 1.), MAX_ABSOLUTE_DIFFERENCE));
+
+		let table = subpath.arc_length_table(None);
+		assert_eq!(subpath.arc_length_samples_from_table(table, 10.).collect::<Vec<_>>(), samples);
+
+		// The end is not included when the length is not a multiple of the spacing
+		assert_eq!(subpath.arc_length_samples(15.).count(), 3);
+		assert_eq!(subpath.arc_length_samples(40. / 3.).count(), 4);
+
+		// Only the start is produced for invalid spacings or subpaths without segments
+		assert_eq!(subpath.arc_length_samples(0.).count(), 1);
+		let point: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(5., 5.)], false);
+		assert_eq!(point.arc_length_samples(1.).map(|sample| sample.point).collect::<Vec<_>>(), vec![DVec2::new(5., 5.)]);
+	}
+
+	#[test]
+	fn area_and_orientation_of_a_rectangle() {
+		let clockwise: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(10., 10.), DVec2::new(40., 10.), DVec2::new(40., 30.), DVec2::new(10., 30.)], true);
+		let counterclockwise = clockwise.reverse();
+
+		assert!(f64_compare(clockwise.signed_area(), 600., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(counterclockwise.signed_area(), -600., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(f64_compare(counterclockwise.area(), 600., MAX_ABSOLUTE_DIFFERENCE));
+		assert!(clockwise.is_clockwise());
+		assert!(!counterclockwise.is_clockwise());
+
+		assert_eq!(clockwise.reverse_to_orientation(true), clockwise);
+		assert_eq!(counterclockwise.reverse_to_orientation(true), clockwise);
+		assert_eq!(clockwise.reverse_to_orientation(false), counterclockwise);
+
+		assert!(clockwise.centroid().unwrap().abs_diff_eq(DVec2::new(25., 20.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(counterclockwise.centroid().unwrap().abs_diff_eq(DVec2::new(25., 20.), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn area_of_curved_subpaths() {
+		// The cubic approximation of a circle encloses slightly more area than the circle itself
+		let circle: Subpath<EmptyId> = Subpath::new_ellipse(DVec2::new(0., 0.), DVec2::new(100., 100.));
+		assert!(f64_compare(circle.area(), std::f64::consts::PI * 50. * 50., 2.));
+		assert!(circle.centroid().unwrap().abs_diff_eq(DVec2::new(50., 50.), MAX_ABSOLUTE_DIFFERENCE));
+
+		// The region under a parabola from (0, 0) to (2, 0) with its handle at (1, 2) has an area of 4/3 and its centroid at (1, 2/5)
+		let quadratic = Bezier::from_quadratic_dvec2(DVec2::new(0., 0.), DVec2::new(1., 2.), DVec2::new(2., 0.));
+		let parabola: Subpath<EmptyId> = Subpath::from_bezier(&quadratic);
+		assert!(f64_compare(parabola.area(), 4. / 3., 1e-9));
+		assert!(parabola.centroid().unwrap().abs_diff_eq(DVec2::new(1., 0.4), 1e-9));
+
+		// The same curve as an elevated cubic encloses the same area
+		let cubic: Subpath<EmptyId> = Subpath::from_bezier(&Bezier::from_cubic_dvec2(
+			DVec2::new(0., 0.),
+			DVec2::new(2. / 3., 4. / 3.),
+			DVec2::new(4. / 3., 4. / 3.),
+			DVec2::new(2., 0.),
+		));
+		assert!(f64_compare(cubic.signed_area(), parabola.signed_area(), 1e-9));
+		assert!(cubic.centroid().unwrap().abs_diff_eq(DVec2::new(1., 0.4), 1e-9));
+	}
+
+	#[test]
+	fn area_without_enclosed_region() {
+		let line: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(0., 0.), DVec2::new(10., 10.)], false);
+		assert_eq!(line.area(), 0.);
+		assert_eq!(line.centroid(), None);
+		assert_eq!(line.reverse_to_orientation(false), line);
+
+		// Lobes of a figure eight wind in opposite directions and cancel each other out
+		let figure_eight: Subpath<EmptyId> = Subpath::from_anchors([DVec2::new(0., 0.), DVec2::new(10., 10.), DVec2::new(10., 0.), DVec2::new(0., 10.)], true);
+		assert!(f64_compare(figure_eight.signed_area(), 0., MAX_ABSOLUTE_DIFFERENCE));
+	}
 }
@@ -1,13 +1,19 @@
+mod boolean;
 mod core;
+mod fitting;
 mod lookup;
 mod manipulators;
+mod parser;
 mod solvers;
 mod structs;
 mod transform;
 pub use structs::*;
 
+use crate::utils::TValue;
 use crate::Bezier;
 
+use glam::DVec2;
+
 use std::fmt::{Debug, Formatter, Result};
 use std::ops::{Index, IndexMut};
 
@@ -70,6 +76,63 @@ impl<ManipulatorGroupId: crate::Identifier> Iterator for SubpathIter<'_, Manipul
 	}
 }
 
+/// Iteration structure for sampling points and tangents at evenly spaced distances along a `Subpath`, created by [Subpath::arc_length_samples].
+pub struct ArcLengthSamples<'a, ManipulatorGroupId: crate::Identifier> {
+	index: usize,
+	spacing: f64,
+	table: ArcLengthTable,
+	subpath: &'a Subpath<ManipulatorGroupId>,
+}
+
+/// A point sampled along a `Subpath` together with the tangent and the distance along the `Subpath` at that point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArcLengthSample {
+	pub point: DVec2,
+	/// Normalized tangent vector, which is zero if the `Subpath` consists of only a single point.
+	pub tangent: DVec2,
+	pub distance: f64,
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Iterator for ArcLengthSamples<'_, ManipulatorGroupId> {
+	type Item = ArcLengthSample;
+
+	// Returns the sample at the next multiple of the spacing, as long as it is within the length of the `Subpath`.
+	fn next(&mut self) -> Option<Self::Item> {
+		let length = self.table.total_length();
+		let distance = if self.index == 0 { 0. } else { self.index as f64 * self.spacing };
+		let valid_spacing = self.spacing > 0. && self.spacing.is_finite();
+
+		// Allow for the floating point error of the multiplication, so the end is included when the length is a multiple of the spacing
+		if self.index > 0 && (!valid_spacing || self.table.len_segments() == 0 || distance > length * (1. + 16. * f64::EPSILON)) {
+			return None;
+		}
+		self.index += 1;
+
+		if self.table.len_segments() == 0 {
+			let anchor = self.subpath.manipulator_groups.first()?.anchor;
+			return Some(ArcLengthSample {
+				point: anchor,
+				tangent: DVec2::ZERO,
+				distance: 0.,
+			});
+		}
+
+		let (segment_index, t) = self.table.distance_to_parametric(distance);
+		let segment = self.subpath.get_segment(segment_index).unwrap();
+		let mut tangent = segment.tangent(TValue::Parametric(t));
+		if tangent == DVec2::ZERO {
+			// A handle that coincides with its anchor gives no direction exactly at the anchor, so look just inside the segment instead
+			tangent = segment.tangent(TValue::Parametric(if t < 0.5 { t + 1e-6 } else { t - 1e-6 }));
+		}
+
+		Some(ArcLengthSample {
+			point: segment.evaluate(TValue::Parametric(t)),
+			tangent,
+			distance: distance.min(length),
+		})
+	}
+}
+
 impl<ManipulatorGroupId: crate::Identifier> Debug for Subpath<ManipulatorGroupId> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
 		f.debug_struct("Subpath").field("closed", &self.closed).field("manipulator_groups", &self.manipulator_groups).finish()
@@ -0,0 +1,459 @@
+use super::*;
+use crate::consts::STRICT_MAX_ABSOLUTE_DIFFERENCE;
+
+/// Functionality for reading `Subpath`s from SVG path data.
+impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
+	/// Parses the contents of an SVG path's `d` attribute into the `Subpath`s it describes, following the grammar in <https://www.w3.org/TR/SVG2/paths.html#PathDataBNF>.
+	/// All absolute and relative commands are supported, including implicitly repeated arguments. Elliptical arcs are approximated with cubic segments.
+	/// When a closed subpath ends at its start point, the segment back to the start is merged into the closing segment.
+	/// Malformed path data produces an [SvgPathError] which contains the byte offset of the problem.
+	pub fn from_svg_path_data(path_data: &str) -> std::result::Result<Vec<Self>, SvgPathError> {
+		let mut parser = PathDataParser { path_data, position: 0 };
+		let mut builder = PathBuilder::default();
+
+		loop {
+			parser.skip_whitespace();
+			let command_position = parser.position;
+			let Some(command) = parser.peek() else { break };
+			if !b"MmLlHhVvCcSsQqTtAaZz".contains(&command) {
+				return Err(parser.error(SvgPathErrorKind::ExpectedCommand(path_data[command_position..].chars().next().unwrap_or_default())));
+			}
+			if builder.subpaths.is_empty() && builder.groups.is_empty() && !matches!(command, b'M' | b'm') {
+				return Err(parser.error(SvgPathErrorKind::ExpectedMoveTo));
+			}
+			parser.position += 1;
+
+			if command.eq_ignore_ascii_case(&b'Z') {
+				builder.close();
+				continue;
+			}
+
+			let mut first_arguments = true;
+			loop {
+				let origin = if command.is_ascii_lowercase() { builder.current } else { DVec2::ZERO };
+				match command.to_ascii_uppercase() {
+					b'M' => {
+						let point = origin + parser.point()?;
+						// Coordinates following the first pair of a move command are implicit line commands
+						if first_arguments {
+							builder.move_to(point);
+						} else {
+							builder.line_to(point);
+						}
+					}
+					b'L' => builder.line_to(origin + parser.point()?),
+					b'H' => {
+						let x = parser.number()?;
+						let x = if command.is_ascii_lowercase() { builder.current.x + x } else { x };
+						builder.line_to(DVec2::new(x, builder.current.y));
+					}
+					b'V' => {
+						let y = parser.number()?;
+						let y = if command.is_ascii_lowercase() { builder.current.y + y } else { y };
+						builder.line_to(DVec2::new(builder.current.x, y));
+					}
+					b'C' => {
+						let (handle_start, handle_end, end) = (origin + parser.point()?, origin + parser.point()?, origin + parser.point()?);
+						builder.cubic_to(handle_start, handle_end, end);
+					}
+					b'S' => {
+						// The first handle is the reflection of the previous cubic segment's second handle, or the current point otherwise
+						let handle_start = builder.previous_cubic_handle.map_or(builder.current, |handle| 2. * builder.current - handle);
+						let (handle_end, end) = (origin + parser.point()?, origin + parser.point()?);
+						builder.cubic_to(handle_start, handle_end, end);
+					}
+					b'Q' => {
+						let (handle, end) = (origin + parser.point()?, origin + parser.point()?);
+						builder.quadratic_to(handle, end);
+					}
+					b'T' => {
+						let handle = builder.previous_quadratic_handle.map_or(builder.current, |handle| 2. * builder.current - handle);
+						builder.quadratic_to(handle, origin + parser.point()?);
+					}
+					b'A' => {
+						let radii = DVec2::new(parser.number()?, parser.number()?);
+						let x_axis_rotation = parser.number()?.to_radians();
+						let (large_arc, sweep) = (parser.flag()?, parser.flag()?);
+						let end = origin + parser.point()?;
+						builder.arc_to(radii, x_axis_rotation, large_arc, sweep, end);
+					}
+					_ => unreachable!("Path data commands were checked above"),
+				}
+				first_arguments = false;
+
+				parser.skip_comma_whitespace();
+				if !parser.at_number() {
+					break;
+				}
+			}
+		}
+
+		builder.finish();
+		Ok(builder.subpaths)
+	}
+}
+
+/// Reads the numbers and flags of SVG path data while keeping track of the position in it.
+struct PathDataParser<'a> {
+	path_data: &'a str,
+	position: usize,
+}
+
+impl PathDataParser<'_> {
+	fn peek(&self) -> Option<u8> {
+		self.path_data.as_bytes().get(self.position).copied()
+	}
+
+	fn error(&self, kind: SvgPathErrorKind) -> SvgPathError {
+		SvgPathError { kind, position: self.position }
+	}
+
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C')) {
+			self.position += 1;
+		}
+	}
+
+	/// Skips whitespace along with at most one comma separating two arguments.
+	fn skip_comma_whitespace(&mut self) {
+		self.skip_whitespace();
+		if self.peek() == Some(b',') {
+			self.position += 1;
+			self.skip_whitespace();
+		}
+	}
+
+	fn at_number(&self) -> bool {
+		matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'+' | b'-'))
+	}
+
+	fn skip_digits(&mut self) -> usize {
+		let start = self.position;
+		while matches!(self.peek(), Some(b'0'..=b'9')) {
+			self.position += 1;
+		}
+		self.position - start
+	}
+
+	/// Reads a number preceded by optional separators. Numbers may directly follow each other, as in `1-2` or `0.5.5`.
+	fn number(&mut self) -> std::result::Result<f64, SvgPathError> {
+		self.skip_comma_whitespace();
+		let start = self.position;
+
+		if matches!(self.peek(), Some(b'+' | b'-')) {
+			self.position += 1;
+		}
+		let mut digits = self.skip_digits();
+		if self.peek() == Some(b'.') {
+			self.position += 1;
+			digits += self.skip_digits();
+		}
+		if digits == 0 {
+			self.position = start;
+			return Err(self.error(SvgPathErrorKind::ExpectedNumber));
+		}
+
+		// The exponent is only part of the number if it contains digits
+		if matches!(self.peek(), Some(b'e' | b'E')) {
+			let mantissa_end = self.position;
+			self.position += 1;
+			if matches!(self.peek(), Some(b'+' | b'-')) {
+				self.position += 1;
+			}
+			if self.skip_digits() == 0 {
+				self.position = mantissa_end;
+			}
+		}
+
+		match self.path_data[start..self.position].parse::<f64>() {
+			Ok(number) if number.is_finite() => Ok(number),
+			_ => {
+				self.position = start;
+				Err(self.error(SvgPathErrorKind::ExpectedNumber))
+			}
+		}
+	}
+
+	fn point(&mut self) -> std::result::Result<DVec2, SvgPathError> {
+		Ok(DVec2::new(self.number()?, self.number()?))
+	}
+
+	/// Reads an arc flag, which is a single `0` or `1` that needs no separator from what follows it.
+	fn flag(&mut self) -> std::result::Result<bool, SvgPathError> {
+		self.skip_comma_whitespace();
+		let flag = match self.peek() {
+			Some(b'0') => false,
+			Some(b'1') => true,
+			_ => return Err(self.error(SvgPathErrorKind::ExpectedFlag)),
+		};
+		self.position += 1;
+		Ok(flag)
+	}
+}
+
+/// Accumulates the manipulator groups of the subpath currently being parsed, along with the state needed by the commands which depend on the previous one.
+struct PathBuilder<ManipulatorGroupId: crate::Identifier> {
+	subpaths: Vec<Subpath<ManipulatorGroupId>>,
+	groups: Vec<ManipulatorGroup<ManipulatorGroupId>>,
+	current: DVec2,
+	subpath_start: DVec2,
+	previous_cubic_handle: Option<DVec2>,
+	previous_quadratic_handle: Option<DVec2>,
+}
+
+impl<ManipulatorGroupId: crate::Identifier> Default for PathBuilder<ManipulatorGroupId> {
+	fn default() -> Self {
+		Self {
+			subpaths: Vec::new(),
+			groups: Vec::new(),
+			current: DVec2::ZERO,
+			subpath_start: DVec2::ZERO,
+			previous_cubic_handle: None,
+			previous_quadratic_handle: None,
+		}
+	}
+}
+
+impl<ManipulatorGroupId: crate::Identifier> PathBuilder<ManipulatorGroupId> {
+	/// Adds a segment ending at `end`, whose handles are set on the last group and the new one by the caller.
+	fn push_segment(&mut self, out_handle: Option<DVec2>, in_handle: Option<DVec2>, end: DVec2) {
+		// A drawing command following a close command starts a new subpath at the start of the closed one
+		if self.groups.is_empty() {
+			self.groups.push(ManipulatorGroup::new(self.current, None, None));
+		}
+		self.groups.last_mut().unwrap().out_handle = out_handle;
+		self.groups.push(ManipulatorGroup::new(end, in_handle, None));
+		self.current = end;
+		self.previous_cubic_handle = None;
+		self.previous_quadratic_handle = None;
+	}
+
+	fn move_to(&mut self, point: DVec2) {
+		self.finish();
+		self.groups.push(ManipulatorGroup::new(point, None, None));
+		self.current = point;
+		self.subpath_start = point;
+		self.previous_cubic_handle = None;
+		self.previous_quadratic_handle = None;
+	}
+
+	fn line_to(&mut self, end: DVec2) {
+		self.push_segment(None, None, end);
+	}
+
+	fn cubic_to(&mut self, handle_start: DVec2, handle_end: DVec2, end: DVec2) {
+		self.push_segment(Some(handle_start), Some(handle_end), end);
+		self.previous_cubic_handle = Some(handle_end);
+	}
+
+	fn quadratic_to(&mut self, handle: DVec2, end: DVec2) {
+		self.push_segment(Some(handle), None, end);
+		self.previous_quadratic_handle = Some(handle);
+	}
+
+	fn arc_to(&mut self, radii: DVec2, x_axis_rotation: f64, large_arc: bool, sweep: bool, end: DVec2) {
+		let arc: Subpath<ManipulatorGroupId> = Subpath::from_svg_arc(self.current, end, radii, x_axis_rotation, large_arc, sweep, None);
+		for window in arc.manipulator_groups.windows(2) {
+			self.push_segment(window[0].out_handle, window[1].in_handle, window[1].anchor);
+		}
+	}
+
+	fn close(&mut self) {
+		if self.groups.is_empty() {
+			return;
+		}
+
+		// Merge the last group into the first if it returns to the start point, since the closing segment replaces the segment back to it
+		let mut groups = std::mem::take(&mut self.groups);
+		if groups.len() > 2 && groups[0].anchor.abs_diff_eq(groups[groups.len() - 1].anchor, STRICT_MAX_ABSOLUTE_DIFFERENCE) {
+			let last = groups.pop().unwrap();
+			groups[0].in_handle = last.in_handle;
+		}
+		let closed = groups.len() > 1;
+		self.subpaths.push(Subpath::new(groups, closed));
+
+		self.current = self.subpath_start;
+		self.previous_cubic_handle = None;
+		self.previous_quadratic_handle = None;
+	}
+
+	/// Adds the subpath being built, if any, to the list of finished subpaths without closing it.
+	fn finish(&mut self) {
+		if !self.groups.is_empty() {
+			self.subpaths.push(Subpath::new(std::mem::take(&mut self.groups), false));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
+	use crate::utils::SubpathTValue;
+	use crate::EmptyId;
+
+	fn parse(path_data: &str) -> Vec<Subpath<EmptyId>> {
+		Subpath::from_svg_path_data(path_data).unwrap()
+	}
+
+	fn parse_error(path_data: &str) -> SvgPathError {
+		Subpath::<EmptyId>::from_svg_path_data(path_data).unwrap_err()
+	}
+
+	#[test]
+	fn parse_lines_with_implicit_repeats() {
+		let absolute = parse("M10,10 L20 10 30,20 H40 V 50 Z");
+		assert_eq!(absolute.len(), 1);
+		assert!(absolute[0].closed());
+		assert_eq!(
+			absolute[0].anchors(),
+			vec![DVec2::new(10., 10.), DVec2::new(20., 10.), DVec2::new(30., 20.), DVec2::new(40., 20.), DVec2::new(40., 50.)]
+		);
+
+		// Pairs following a move command are lines, and relative commands are relative to the end of the previous segment
+		let relative = parse("m10 10 10 0 l10 10 h10 v30z");
+		assert_eq!(relative, absolute);
+	}
+
+	#[test]
+	fn parse_compact_numbers() {
+		let subpaths = parse("M.5.5-1e1-2E+1l1.5e-1,0");
+		assert_eq!(subpaths[0].anchors(), vec![DVec2::new(0.5, 0.5), DVec2::new(-10., -20.), DVec2::new(-9.85, -20.)]);
+	}
+
+	#[test]
+	fn parse_curves_with_reflected_handles() {
+		let subpaths = parse("M0 0 C10 0 20 10 20 20 S30 40 40 40 Q50 40 50 50 T50 70");
+		let segments: Vec<Bezier> = subpaths[0].iter().collect();
+		assert_eq!(segments.len(), 4);
+		assert_eq!(segments[0], Bezier::from_cubic_dvec2(DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(20., 10.), DVec2::new(20., 20.)));
+		assert_eq!(
+			segments[1],
+			Bezier::from_cubic_dvec2(DVec2::new(20., 20.), DVec2::new(20., 30.), DVec2::new(30., 40.), DVec2::new(40., 40.))
+		);
+		assert_eq!(segments[2], Bezier::from_quadratic_dvec2(DVec2::new(40., 40.), DVec2::new(50., 40.), DVec2::new(50., 50.)));
+		assert_eq!(segments[3], Bezier::from_quadratic_dvec2(DVec2::new(50., 50.), DVec2::new(50., 60.), DVec2::new(50., 70.)));
+
+		// Without a preceding curve of the same kind, the reflected handle is the current point
+		let subpaths = parse("M0 0 L10 0 S20 10 20 20");
+		assert_eq!(
+			subpaths[0].iter().last().unwrap(),
+			Bezier::from_cubic_dvec2(DVec2::new(10., 0.), DVec2::new(10., 0.), DVec2::new(20., 10.), DVec2::new(20., 20.))
+		);
+	}
+
+	#[test]
+	fn parse_arcs() {
+		// Flags don't need to be separated from the following numbers
+		let subpaths = parse("M100 0 A100 100 0 0110 100");
+		let arc = &subpaths[0];
+		assert_eq!(arc.manipulator_groups().last().unwrap().anchor, DVec2::new(10., 100.));
+
+		let subpaths = parse("M100 0 a100,100 0 0,1 -100,100");
+		let arc = &subpaths[0];
+		assert_eq!(arc.manipulator_groups().last().unwrap().anchor, DVec2::new(0., 100.));
+		assert!(arc
+			.evaluate(SubpathTValue::GlobalParametric(0.5))
+			.abs_diff_eq(DVec2::splat(100. / 2_f64.sqrt()), MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn parse_multiple_subpaths() {
+		let subpaths = parse("M0 0 L10 0 L10 10 Z l0 10 M20 20 L30 30 m5 5");
+		assert_eq!(subpaths.len(), 4);
+		assert!(subpaths[0].closed());
+
+		// A drawing command after closing a subpath starts a new one at the start of the closed one
+		assert_eq!(subpaths[1].anchors(), vec![DVec2::ZERO, DVec2::new(0., 10.)]);
+		assert!(!subpaths[1].closed());
+		assert_eq!(subpaths[2].anchors(), vec![DVec2::new(20., 20.), DVec2::new(30., 30.)]);
+		assert_eq!(subpaths[3].anchors(), vec![DVec2::new(35., 35.)]);
+
+		assert!(parse("").is_empty());
+		assert!(parse(" \n\t").is_empty());
+	}
+
+	#[test]
+	fn parse_closing_segment_back_to_start() {
+		let subpaths = parse("M0 0 C10 0 10 10 0 10 C-10 10 -10 0 0 0 Z");
+		let circle = &subpaths[0];
+		assert_eq!(circle.len(), 2);
+		assert!(circle.closed());
+		assert_eq!(circle.manipulator_groups()[0].in_handle, Some(DVec2::new(-10., 0.)));
+	}
+
+	#[test]
+	fn round_trip_svg_writer() {
+		let subpaths: Vec<Subpath<EmptyId>> = vec![
+			Subpath::new_ellipse(DVec2::new(-20., 5.), DVec2::new(40., 45.)),
+			Subpath::new_rect(DVec2::new(1.5, 2.5), DVec2::new(10., 20.)),
+			Subpath::from_bezier(&Bezier::from_quadratic_dvec2(DVec2::ZERO, DVec2::new(5., 10.), DVec2::new(10., 0.))),
+			Subpath::new_cubic_spline(vec![DVec2::ZERO, DVec2::new(10., 20.), DVec2::new(30., -5.), DVec2::new(40., 40.)]),
+		];
+
+		for subpath in subpaths {
+			let mut path_data = String::new();
+			subpath.subpath_to_svg(&mut path_data, glam::DAffine2::IDENTITY).unwrap();
+			assert_eq!(parse(&path_data), vec![subpath.clone()], "{path_data}");
+
+			let mut path_element = String::new();
+			subpath.curve_to_svg(&mut path_element, String::new());
+			let path_data = path_element.split('"').nth(1).unwrap();
+			assert_eq!(parse(path_data), vec![subpath], "{path_data}");
+		}
+	}
+
+	#[test]
+	fn parse_errors() {
+		assert_eq!(
+			parse_error("L10 10"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedMoveTo,
+				position: 0
+			}
+		);
+		assert_eq!(
+			parse_error("M10 10 L20"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedNumber,
+				position: 10
+			}
+		);
+		assert_eq!(
+			parse_error("M10 10 X20 20"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedCommand('X'),
+				position: 7
+			}
+		);
+		assert_eq!(
+			parse_error("M10 10 L20,,20"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedNumber,
+				position: 11
+			}
+		);
+		assert_eq!(
+			parse_error("M10 10 A5 5 0 2 0 20 20"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedFlag,
+				position: 14
+			}
+		);
+		assert_eq!(
+			parse_error("M10 10 L1e999 0"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedNumber,
+				position: 8
+			}
+		);
+		assert_eq!(
+			parse_error("M0 0 Z 5"),
+			SvgPathError {
+				kind: SvgPathErrorKind::ExpectedCommand('5'),
+				position: 7
+			}
+		);
+		assert_eq!(parse_error("M0 0 L1 1 é").to_string(), "Invalid SVG path data: expected a command but found 'é' at position 10");
+	}
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
-use crate::utils::{compute_circular_subpath_details, line_intersection, SubpathTValue};
+use crate::utils::{compute_circular_subpath_details, line_intersection, FillRule, SubpathTValue};
 use crate::TValue;
 
 use glam::{DMat2, DVec2};
@@ -132,9 +132,19 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		inflection_t_values
 	}
 
+	/// Returns the number of times the path winds around a point, where the sign indicates the direction in which it winds.
+	pub fn winding_number(&self, target_point: DVec2) -> i32 {
+		self.iter().map(|bezier| bezier.winding(target_point)).sum()
+	}
+
 	/// Does a path contain a point? Based on the non zero winding
 	pub fn contains_point(&self, target_point: DVec2) -> bool {
-		self.iter().map(|bezier| bezier.winding(target_point)).sum::<i32>() != 0
+		self.contains_point_with_fill_rule(target_point, FillRule::NonZero)
+	}
+
+	/// Does a path contain a point according to the given [FillRule]?
+	pub fn contains_point_with_fill_rule(&self, target_point: DVec2, fill_rule: FillRule) -> bool {
+		fill_rule.is_inside(self.winding_number(target_point))
 	}
 
 	/// Returns the manipulator point that is needed for a miter join if it is possible.
@@ -706,4 +716,22 @@ mod tests {
 		assert!((round_point - middle).angle_between(round_start - middle) < 0.);
 		assert!((round_end - middle).angle_between(round_point - middle) < 0.);
 	}
+
+	#[test]
+	fn contains_point_with_fill_rule() {
+		// The pentagon in the middle of a pentagram is wound around twice
+		let points = (0..5).map(|index| DVec2::from_angle(index as f64 * 2. * std::f64::consts::TAU / 5.) * 10.);
+		let pentagram: Subpath<EmptyId> = Subpath::from_anchors(points, true);
+
+		assert_eq!(pentagram.winding_number(DVec2::ZERO).abs(), 2);
+		assert!(pentagram.contains_point(DVec2::ZERO));
+		assert!(!pentagram.contains_point_with_fill_rule(DVec2::ZERO, FillRule::EvenOdd));
+
+		// The points of the star are wound around once, so both rules include them
+		let point = DVec2::new(8., 0.);
+		assert_eq!(pentagram.winding_number(point).abs(), 1);
+		assert!(pentagram.contains_point_with_fill_rule(point, FillRule::NonZero));
+		assert!(pentagram.contains_point_with_fill_rule(point, FillRule::EvenOdd));
+		assert!(!pentagram.contains_point_with_fill_rule(DVec2::new(20., 0.), FillRule::NonZero));
+	}
 }
@@ -1,4 +1,6 @@
 use super::Bezier;
+use crate::consts::GAUSS_LEGENDRE_5;
+use crate::utils::SubpathTValue;
 
 use glam::{DAffine2, DVec2};
 use std::{
@@ -118,3 +120,152 @@ pub enum AppendType {
 	IgnoreStart,
 	SmoothJoin(f64),
 }
+
+/// Structure used to map between distances along the length of a `Subpath` and parametric positions on its segments.
+/// Computing it once allows many positions to be looked up along the same `Subpath` without measuring its segments again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable {
+	segments: Vec<Bezier>,
+	/// Number of samples taken at evenly spaced parametric `t` values along each segment.
+	samples_per_segment: usize,
+	/// Cumulative length of the `Subpath` at each sample, where neighboring segments share the sample at their common anchor.
+	cumulative_lengths: Vec<f64>,
+}
+
+/// Number of Newton iterations used to refine the parametric `t` value found by interpolating between samples of the table.
+const ARC_LENGTH_NEWTON_ITERATIONS: usize = 3;
+
+impl ArcLengthTable {
+	/// Builds the table from the segments of a `Subpath`, taking `samples_per_segment` samples along each segment.
+	pub(crate) fn new(segments: impl Iterator<Item = Bezier>, samples_per_segment: usize) -> Self {
+		let segments: Vec<Bezier> = segments.collect();
+		let samples_per_segment = samples_per_segment.max(1);
+
+		let mut cumulative_lengths = vec![0.];
+		let mut total_length = 0.;
+		for bezier in &segments {
+			for sample in 0..samples_per_segment {
+				let t_start = sample as f64 / samples_per_segment as f64;
+				let t_end = (sample + 1) as f64 / samples_per_segment as f64;
+				total_length += Self::length_between(bezier, t_start, t_end);
+				cumulative_lengths.push(total_length);
+			}
+		}
+
+		Self {
+			segments,
+			samples_per_segment,
+			cumulative_lengths,
+		}
+	}
+
+	/// Integrates the speed of the bezier between two parametric `t` values to find the length of the curve between them.
+	fn length_between(bezier: &Bezier, t_start: f64, t_end: f64) -> f64 {
+		let half_width = (t_end - t_start) / 2.;
+		let center = (t_start + t_end) / 2.;
+		GAUSS_LEGENDRE_5
+			.iter()
+			.map(|(node, weight)| weight * bezier.non_normalized_tangent(center + half_width * node).length())
+			.sum::<f64>()
+			* half_width
+	}
+
+	/// Returns the approximate length of the whole `Subpath`.
+	pub fn total_length(&self) -> f64 {
+		*self.cumulative_lengths.last().unwrap()
+	}
+
+	/// Returns the number of segments of the `Subpath` that the table was built from.
+	pub fn len_segments(&self) -> usize {
+		self.segments.len()
+	}
+
+	/// Converts a distance along the `Subpath` to a parametric `(segment_index, t)` tuple. The distance is clamped to the length of the `Subpath`.
+	pub fn distance_to_parametric(&self, distance: f64) -> (usize, f64) {
+		if self.segments.is_empty() {
+			return (0, 0.);
+		}
+		let distance = distance.clamp(0., self.total_length());
+
+		// Find the pair of samples that the distance falls between, then interpolate between them
+		let next_sample = self.cumulative_lengths.partition_point(|&length| length < distance).clamp(1, self.cumulative_lengths.len() - 1);
+		let sample = next_sample - 1;
+		let (start_length, end_length) = (self.cumulative_lengths[sample], self.cumulative_lengths[next_sample]);
+		let ratio = if end_length > start_length { (distance - start_length) / (end_length - start_length) } else { 0. };
+
+		let segment_index = sample / self.samples_per_segment;
+		let t_start = (sample % self.samples_per_segment) as f64 / self.samples_per_segment as f64;
+		let t_end = t_start + 1. / self.samples_per_segment as f64;
+		let mut t = t_start + ratio * (t_end - t_start);
+
+		// Refine the interpolated value, since the speed along the curve is not constant between the samples
+		let bezier = &self.segments[segment_index];
+		for _ in 0..ARC_LENGTH_NEWTON_ITERATIONS {
+			let speed = bezier.non_normalized_tangent(t).length();
+			if speed < f64::EPSILON {
+				break;
+			}
+			let error = start_length + Self::length_between(bezier, t_start, t) - distance;
+			t = (t - error / speed).clamp(t_start, t_end);
+		}
+
+		(segment_index, t)
+	}
+
+	/// Returns the distance along the `Subpath` of the point at the parametric `t` value on the segment with the given index.
+	pub fn parametric_to_distance(&self, segment_index: usize, t: f64) -> f64 {
+		let Some(bezier) = self.segments.get(segment_index) else {
+			return self.total_length();
+		};
+		let scaled_t = t.clamp(0., 1.) * self.samples_per_segment as f64;
+		let sample = (scaled_t.floor() as usize).min(self.samples_per_segment - 1);
+		let t_start = sample as f64 / self.samples_per_segment as f64;
+		self.cumulative_lengths[segment_index * self.samples_per_segment + sample] + Self::length_between(bezier, t_start, t.clamp(0., 1.))
+	}
+
+	/// Returns the [SubpathTValue] corresponding to a distance along the `Subpath`, which avoids measuring the `Subpath` again when used with it.
+	pub fn t_value(&self, distance: f64) -> SubpathTValue {
+		let (segment_index, t) = self.distance_to_parametric(distance);
+		SubpathTValue::Parametric { segment_index, t }
+	}
+}
+
+/// Describes what was wrong with malformed SVG path data passed to [super::Subpath::from_svg_path_data].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SvgPathErrorKind {
+	/// The path data does not begin with a `M` or `m` command.
+	ExpectedMoveTo,
+	/// A character was found where a command letter is required.
+	ExpectedCommand(char),
+	/// A command is missing an argument or an argument is not a finite number.
+	ExpectedNumber,
+	/// An arc command's large arc or sweep flag is not `0` or `1`.
+	ExpectedFlag,
+}
+
+impl std::fmt::Display for SvgPathErrorKind {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		match self {
+			SvgPathErrorKind::ExpectedMoveTo => write!(f, "path data must begin with a move command"),
+			SvgPathErrorKind::ExpectedCommand(found) => write!(f, "expected a command but found '{found}'"),
+			SvgPathErrorKind::ExpectedNumber => write!(f, "expected a number"),
+			SvgPathErrorKind::ExpectedFlag => write!(f, "expected a flag of 0 or 1"),
+		}
+	}
+}
+
+/// An error produced when parsing malformed SVG path data, located at a byte offset into the path data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SvgPathError {
+	pub kind: SvgPathErrorKind,
+	/// The offset in bytes from the start of the path data at which the problem was found.
+	pub position: usize,
+}
+
+impl std::fmt::Display for SvgPathError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		write!(f, "Invalid SVG path data: {} at position {}", self.kind, self.position)
+	}
+}
+
+impl std::error::Error for SvgPathError {}
@@ -1,11 +1,34 @@
 use std::vec;
 
 use super::*;
-use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
-use crate::utils::{Cap, Join, SubpathTValue, TValue};
+use crate::consts::{MAX_ABSOLUTE_DIFFERENCE, MAX_DASHES};
+use crate::utils::{Cap, FilletType, Join, SubpathTValue, TValue};
+use crate::BezierHandles;
 
 use glam::{DAffine2, DVec2};
 
+/// The direction a segment leaves its start point in, taken from the first of its points which differs from the start.
+fn start_direction(bezier: &Bezier) -> Option<DVec2> {
+	let start = bezier.start();
+	bezier
+		.get_points()
+		.skip(1)
+		.find(|point| point.distance(start) > MAX_ABSOLUTE_DIFFERENCE)
+		.map(|point| (point - start).normalize())
+}
+
+/// The direction a segment arrives at its end point from, taken from the last of its points which differs from the end.
+fn end_direction(bezier: &Bezier) -> Option<DVec2> {
+	let end = bezier.end();
+	let points: Vec<DVec2> = bezier.get_points().collect();
+	points
+		.into_iter()
+		.rev()
+		.skip(1)
+		.find(|point| point.distance(end) > MAX_ABSOLUTE_DIFFERENCE)
+		.map(|point| (end - point).normalize())
+}
+
 /// Helper function to ensure the index and t value pair is mapped within a maximum index value.
 /// Allows for the point to be fetched without needing to handle an additional edge case.
 /// - Ex. Via `subpath.iter().nth(index).evaluate(t);`
@@ -17,6 +40,37 @@ fn map_index_within_range(index: usize, t: f64, max_size: usize) -> (usize, f64)
 	}
 }
 
+/// Converts a segment into a line segment if its handles lie on top of its anchors, as it is then a straight line anyway.
+fn straighten_degenerate_handles(bezier: &Bezier) -> Bezier {
+	let on_start = |handle: DVec2| handle.abs_diff_eq(bezier.start(), MAX_ABSOLUTE_DIFFERENCE);
+	let on_end = |handle: DVec2| handle.abs_diff_eq(bezier.end(), MAX_ABSOLUTE_DIFFERENCE);
+	let is_straight = match bezier.handles {
+		BezierHandles::Linear => false,
+		BezierHandles::Quadratic { handle } => on_start(handle) || on_end(handle),
+		BezierHandles::Cubic { handle_start, handle_end } => (on_start(handle_start) || on_end(handle_start)) && (on_start(handle_end) || on_end(handle_end)),
+	};
+	if is_straight {
+		Bezier::from_linear_dvec2(bezier.start(), bezier.end())
+	} else {
+		*bezier
+	}
+}
+
+/// Returns the direction in which the segment leaves its start point.
+/// Unlike the tangent, this is also defined when a handle lies on top of the start point, in which case it points towards the next distinct control point.
+fn start_tangent(bezier: &Bezier) -> DVec2 {
+	let start = bezier.start();
+	bezier
+		.get_points()
+		.find(|point| !point.abs_diff_eq(start, MAX_ABSOLUTE_DIFFERENCE))
+		.map_or(DVec2::ZERO, |point| (point - start).normalize())
+}
+
+/// Returns the direction in which the segment arrives at its end point, which is also defined when a handle lies on top of the end point.
+fn end_tangent(bezier: &Bezier) -> DVec2 {
+	-start_tangent(&bezier.reverse())
+}
+
 /// Functionality that transforms Subpaths, such as split, reduce, offset, etc.
 impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 	/// Returns either one or two Subpaths that result from splitting the original Subpath at the point corresponding to `t`.
@@ -273,6 +327,56 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 		}
 	}
 
+	/// Returns the open [Subpath]s that result from breaking the original Subpath into dashes, following the same rules as the SVG `stroke-dasharray` and `stroke-dashoffset` attributes.
+	/// - `dash_lengths` - The alternating lengths of the dashes and the gaps between them, measured along the Subpath. A list with an odd number of lengths is repeated to yield an even number.
+	/// - `dash_offset` - The distance into the dash pattern at which the start of the Subpath lies.
+	///
+	/// If the pattern is empty, contains negative lengths, or sums to zero, the Subpath is returned undashed.
+	/// At most [MAX_DASHES] dashes are returned, and the rest of the Subpath past the last of them is left out.
+	/// For a closed Subpath, a dash that crosses its start point is returned as a single Subpath instead of being broken at the start point.
+	pub fn dash(&self, dash_lengths: &[f64], dash_offset: f64) -> Vec<Subpath<ManipulatorGroupId>> {
+		let pattern: Vec<f64> = if dash_lengths.len() % 2 == 1 { dash_lengths.repeat(2) } else { dash_lengths.to_vec() };
+		let pattern_length: f64 = pattern.iter().sum();
+		if self.len_segments() == 0 || pattern.iter().any(|&length| length < 0. || !length.is_finite()) || pattern_length <= 0. {
+			return vec![self.clone()];
+		}
+
+		// Find the entry of the pattern, and the distance into it, at which the subpath starts
+		let mut offset = dash_offset.rem_euclid(pattern_length);
+		let mut index = 0;
+		while offset > 0. && offset >= pattern[index] {
+			offset -= pattern[index];
+			index = (index + 1) % pattern.len();
+		}
+
+		let table = self.arc_length_table(None);
+		let total_length = table.total_length();
+		let mut dash_ranges = Vec::new();
+		let mut distance = -offset;
+		while distance < total_length && dash_ranges.len() < MAX_DASHES {
+			let end = distance + pattern[index];
+			// Even entries of the pattern are dashes, and odd entries are the gaps between them
+			if index % 2 == 0 {
+				dash_ranges.push((distance.max(0.), end.min(total_length)));
+			}
+			distance = end;
+			index = (index + 1) % pattern.len();
+		}
+
+		// Join the dashes on either side of the start point of a closed subpath
+		if self.closed && dash_ranges.len() > 1 {
+			let (first_start, first_end) = dash_ranges[0];
+			let (last_start, last_end) = dash_ranges[dash_ranges.len() - 1];
+			if first_start == 0. && last_end == total_length {
+				dash_ranges.remove(0);
+				let last_index = dash_ranges.len() - 1;
+				dash_ranges[last_index] = (last_start, first_end);
+			}
+		}
+
+		dash_ranges.into_iter().map(|(start, end)| self.trim(table.t_value(start), table.t_value(end))).collect()
+	}
+
 	/// Apply a transformation to all of the [ManipulatorGroup]s in the [Subpath].
 	pub fn apply_transform(&mut self, affine_transform: DAffine2) {
 		for manipulator_group in &mut self.manipulator_groups {
@@ -360,19 +464,19 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 	/// The intersections of segments of the subpath are joined using the method specified by the `join` argument.
 	/// <iframe frameBorder="0" width="100%" height="400px" src="https://graphite.rs/libraries/bezier-rs#subpath/offset/solo" title="Offset Demo"></iframe>
 	pub fn offset(&self, distance: f64, join: Join) -> Subpath<ManipulatorGroupId> {
-		assert!(self.len_segments() > 1, "Cannot offset an empty Subpath.");
+		assert!(!self.is_empty(), "Cannot offset an empty Subpath.");
+
+		// Segments with handles on top of their anchors (like those made by `from_anchors`) are straight lines, but their tangents vanish at the anchors
+		// They are offset as lines instead, which also gives the joins between them the correct angles
+		let segments: Vec<Bezier> = self.iter().filter(|bezier| !bezier.is_point()).map(|bezier| straighten_degenerate_handles(&bezier)).collect();
 
 		// An offset at a distance 0 from the curve is simply the same curve
 		// An offset of a single point is not defined
-		if distance == 0. || self.len() == 1 {
+		if distance == 0. || segments.is_empty() {
 			return self.clone();
 		}
 
-		let mut subpaths = self
-			.iter()
-			.filter(|bezier| !bezier.is_point())
-			.map(|bezier| bezier.offset(distance))
-			.collect::<Vec<Subpath<ManipulatorGroupId>>>();
+		let mut subpaths = segments.iter().map(|bezier| bezier.offset(distance)).collect::<Vec<Subpath<ManipulatorGroupId>>>();
 		let mut drop_common_point = vec![true; self.len()];
 
 		// Clip or join consecutive Subpaths
@@ -390,8 +494,8 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 			}
 
 			// Calculate the angle formed between two consecutive Subpaths
-			let out_tangent = self.get_segment(i).unwrap().tangent(TValue::Parametric(1.));
-			let in_tangent = self.get_segment(j).unwrap().tangent(TValue::Parametric(0.));
+			let out_tangent = end_tangent(&segments[i]);
+			let in_tangent = start_tangent(&segments[j]);
 			let angle = out_tangent.angle_between(in_tangent);
 
 			// The angle is concave. The Subpath overlap and must be clipped
@@ -418,7 +522,7 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 						}
 					}
 					Join::Round => {
-						let (out_handle, round_point, in_handle) = subpaths[i].round_line_join(&subpaths[j], self.manipulator_groups[j].anchor);
+						let (out_handle, round_point, in_handle) = subpaths[i].round_line_join(&subpaths[j], segments[j].start());
 						let last_index = subpaths[i].manipulator_groups.len() - 1;
 						subpaths[i].manipulator_groups[last_index].out_handle = Some(out_handle);
 						subpaths[i].manipulator_groups.push(round_point.clone());
@@ -430,8 +534,8 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 
 		// Clip any overlap in the last segment
 		if self.closed {
-			let out_tangent = self.get_segment(self.len_segments() - 1).unwrap().tangent(TValue::Parametric(1.));
-			let in_tangent = self.get_segment(0).unwrap().tangent(TValue::Parametric(0.));
+			let out_tangent = end_tangent(&segments[segments.len() - 1]);
+			let in_tangent = start_tangent(&segments[0]);
 			let angle = out_tangent.angle_between(in_tangent);
 
 			let mut apply_join = true;
@@ -457,7 +561,7 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 					}
 					Join::Round => {
 						let last_subpath_index = subpaths.len() - 1;
-						let (out_handle, round_point, in_handle) = subpaths[last_subpath_index].round_line_join(&subpaths[0], self.manipulator_groups[0].anchor);
+						let (out_handle, round_point, in_handle) = subpaths[last_subpath_index].round_line_join(&subpaths[0], segments[0].start());
 						let last_index = subpaths[last_subpath_index].manipulator_groups.len() - 1;
 						subpaths[last_subpath_index].manipulator_groups[last_index].out_handle = Some(out_handle);
 						subpaths[last_subpath_index].manipulator_groups.push(round_point);
@@ -545,14 +649,136 @@ impl<ManipulatorGroupId: crate::Identifier> Subpath<ManipulatorGroupId> {
 
 		(pos_offset.combine_outline(&neg_offset, cap), None)
 	}
+
+	/// Replaces the corners of the subpath with fillets of the given `radius`, keeping the rest of its shape.
+	/// A corner is an anchor where the direction of the path turns by more than `min_angle` radians, and it is only rounded if `include` returns true for its manipulator group index.
+	/// The radius is reduced where the neighboring segments are too short, with a segment shared equally between rounded corners at both of its ends.
+	/// Segments left without any length by the fillets are removed. The first manipulator group of each fillet keeps the id of the corner it replaced.
+	pub fn round_corners(&self, radius: f64, fillet_type: FilletType, min_angle: f64, include: impl Fn(usize) -> bool) -> Subpath<ManipulatorGroupId> {
+		let len = self.len();
+		if radius <= 0. || self.len_segments() < 2 {
+			return self.clone();
+		}
+
+		let segments: Vec<Bezier> = self.iter().collect();
+		let lengths: Vec<f64> = segments.iter().map(|segment| segment.length(None)).collect();
+		let incoming = |index: usize| match index {
+			0 if self.closed => Some(len - 1),
+			0 => None,
+			_ => Some(index - 1),
+		};
+		let outgoing = |index: usize| (index < segments.len()).then_some(index);
+
+		// The distance of each rounded corner's fillet from the corner, measured along the neighboring segments
+		let mut distances: Vec<Option<f64>> = (0..len)
+			.map(|index| {
+				let (incoming, outgoing) = (incoming(index)?, outgoing(index)?);
+				let (incoming_direction, outgoing_direction) = (end_direction(&segments[incoming])?, start_direction(&segments[outgoing])?);
+				let angle = incoming_direction.angle_between(outgoing_direction).abs();
+				let is_corner = angle > min_angle.max(MAX_ABSOLUTE_DIFFERENCE) && angle < std::f64::consts::PI - MAX_ABSOLUTE_DIFFERENCE;
+				(is_corner && include(index)).then(|| radius * (angle / 2.).tan())
+			})
+			.collect();
+
+		// Clamp the distances to the available length of the segments, which is split in half if both of its ends are rounded
+		let available = |segment: usize, distances: &[Option<f64>]| {
+			let both_rounded = distances[segment].is_some() && distances[(segment + 1) % len].is_some();
+			if both_rounded {
+				lengths[segment] / 2.
+			} else {
+				lengths[segment]
+			}
+		};
+		let clamped: Vec<Option<f64>> = (0..len)
+			.map(|index| {
+				let distance = distances[index]?;
+				let distance = distance.min(available(incoming(index)?, &distances)).min(available(outgoing(index)?, &distances));
+				(distance > MAX_ABSOLUTE_DIFFERENCE).then_some(distance)
+			})
+			.collect();
+		distances = clamped;
+
+		// Shorten every segment by the fillets at its ends
+		let trimmed: Vec<Bezier> = segments
+			.iter()
+			.enumerate()
+			.map(|(index, segment)| {
+				let start = distances[index].map_or(0., |distance| (distance / lengths[index]).min(1.));
+				let end = distances[(index + 1) % len].map_or(1., |distance| (1. - distance / lengths[index]).max(start));
+				if start == 0. && end == 1. {
+					*segment
+				} else {
+					// Find the trim points to within a fraction of a unit, since the default error is relative to the segment's length
+					let error = MAX_ABSOLUTE_DIFFERENCE / lengths[index].max(1.);
+					segment.trim(TValue::EuclideanWithinError { t: start, error }, TValue::EuclideanWithinError { t: end, error })
+				}
+			})
+			.collect();
+
+		let mut manipulator_groups = Vec::with_capacity(len * 2);
+		for (index, group) in self.manipulator_groups.iter().enumerate() {
+			let in_handle = incoming(index).map_or(group.in_handle, |segment| trimmed[segment].handle_end());
+			let out_handle = outgoing(index).map_or(group.out_handle, |segment| trimmed[segment].handle_start());
+
+			let (Some(_), Some(incoming), Some(outgoing)) = (distances[index], incoming(index), outgoing(index)) else {
+				manipulator_groups.push(ManipulatorGroup::new_with_id(group.anchor, in_handle, out_handle, group.id.clone()));
+				continue;
+			};
+
+			let (start, end) = (trimmed[incoming].end(), trimmed[outgoing].start());
+			let incoming_direction = end_direction(&trimmed[incoming]).or_else(|| end_direction(&segments[incoming])).unwrap_or_default().normalize_or_zero();
+			let outgoing_direction = start_direction(&trimmed[outgoing])
+				.or_else(|| start_direction(&segments[outgoing]))
+				.unwrap_or_default()
+				.normalize_or_zero();
+			let angle = incoming_direction.angle_between(outgoing_direction).abs();
+
+			// Place the handles based on the chord between the ends of the fillet, which works for curved segments as well as straight ones
+			let chord = start.distance(end);
+			let handle_length = match fillet_type {
+				FilletType::Circular => 4. / 3. * (angle / 4.).tan() * chord / (2. * (angle / 2.).sin()),
+				FilletType::Smooth => 2. / 3. * chord / (2. * (angle / 2.).cos()),
+			};
+
+			manipulator_groups.push(ManipulatorGroup::new_with_id(start, in_handle, Some(start + incoming_direction * handle_length), group.id.clone()));
+			manipulator_groups.push(ManipulatorGroup::new_with_id(
+				end,
+				Some(end - outgoing_direction * handle_length),
+				out_handle,
+				ManipulatorGroupId::new(),
+			));
+		}
+
+		// A fillet that uses up the whole length of a segment leaves it without any length, so the manipulator groups at its ends are merged into the later one
+		let mut merged: Vec<ManipulatorGroup<ManipulatorGroupId>> = Vec::with_capacity(manipulator_groups.len());
+		for group in manipulator_groups {
+			match merged.last_mut() {
+				Some(previous) if previous.to_bezier(&group).is_point() => {
+					*previous = ManipulatorGroup {
+						in_handle: previous.in_handle,
+						..group
+					}
+				}
+				_ => merged.push(group),
+			}
+		}
+		if self.closed && merged.len() > 2 && merged[merged.len() - 1].to_bezier(&merged[0]).is_point() {
+			let last = merged.pop().unwrap();
+			merged[0].in_handle = last.in_handle;
+		}
+
+		let closed = self.closed && merged.len() > 1;
+		Subpath::new(merged, closed)
+	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::{Cap, Join, ManipulatorGroup, Subpath};
+	use super::{Cap, FilletType, Join, ManipulatorGroup, Subpath};
 	use crate::compare::{compare_points, compare_subpaths, compare_vec_of_points};
-	use crate::consts::MAX_ABSOLUTE_DIFFERENCE;
+	use crate::consts::{MAX_ABSOLUTE_DIFFERENCE, MAX_DASHES};
 	use crate::utils::{SubpathTValue, TValue};
+	use crate::Bezier;
 	use crate::EmptyId;
 	use glam::DVec2;
 
@@ -1086,4 +1312,176 @@ mod tests {
 		assert_eq!(outline_closed.0, square);
 		assert_eq!(outline_closed.1, None);
 	}
+
+	#[test]
+	fn dash_open_subpath() {
+		let subpath: Subpath<EmptyId> = Subpath::from_bezier(&Bezier::from_linear_dvec2(DVec2::ZERO, DVec2::new(100., 0.)));
+		let dashes = subpath.dash(&[10., 5.], 0.);
+
+		assert_eq!(dashes.len(), 7);
+		assert!(compare_points(dashes[0].manipulator_groups()[0].anchor, DVec2::ZERO));
+		assert!(compare_points(dashes[0].manipulator_groups().last().unwrap().anchor, DVec2::new(10., 0.)));
+		assert!(compare_points(dashes[1].manipulator_groups()[0].anchor, DVec2::new(15., 0.)));
+		assert!(compare_points(dashes[6].manipulator_groups()[0].anchor, DVec2::new(90., 0.)));
+		assert!(compare_points(dashes[6].manipulator_groups().last().unwrap().anchor, DVec2::new(100., 0.)));
+		assert!(dashes.iter().all(|dash| !dash.closed()));
+
+		// An odd number of lengths is repeated, so a single length is used for both the dashes and the gaps
+		assert_eq!(subpath.dash(&[10.], 0.).len(), 5);
+	}
+
+	#[test]
+	fn dash_offset() {
+		let subpath: Subpath<EmptyId> = Subpath::from_bezier(&Bezier::from_linear_dvec2(DVec2::ZERO, DVec2::new(100., 0.)));
+
+		let dashes = subpath.dash(&[10., 5.], 5.);
+		assert!(compare_points(dashes[0].manipulator_groups().last().unwrap().anchor, DVec2::new(5., 0.)));
+		assert!(compare_points(dashes[1].manipulator_groups()[0].anchor, DVec2::new(10., 0.)));
+
+		let dashes = subpath.dash(&[10., 5.], -5.);
+		assert!(compare_points(dashes[0].manipulator_groups()[0].anchor, DVec2::new(5., 0.)));
+		assert!(compare_points(dashes[0].manipulator_groups().last().unwrap().anchor, DVec2::new(15., 0.)));
+	}
+
+	#[test]
+	fn dash_closed_subpath_joins_across_start() {
+		// The rectangle's perimeter is 120 long, so the dash which starts at 100 continues past the start point until 10
+		let subpath: Subpath<EmptyId> = Subpath::new_rect(DVec2::ZERO, DVec2::new(40., 20.));
+		let dashes = subpath.dash(&[30., 10.], 20.);
+
+		assert_eq!(dashes.len(), 3);
+		let wrapping_dash = &dashes[2];
+		assert!(compare_points(wrapping_dash.manipulator_groups()[0].anchor, DVec2::new(0., 20.)));
+		assert!(compare_points(wrapping_dash.manipulator_groups().last().unwrap().anchor, DVec2::new(10., 0.)));
+		assert!(wrapping_dash.anchors().contains(&DVec2::ZERO));
+	}
+
+	#[test]
+	fn dash_invalid_pattern() {
+		let subpath: Subpath<EmptyId> = Subpath::new_rect(DVec2::ZERO, DVec2::new(40., 20.));
+
+		assert_eq!(subpath.dash(&[], 0.), vec![subpath.clone()]);
+		assert_eq!(subpath.dash(&[0., 0.], 0.), vec![subpath.clone()]);
+		assert_eq!(subpath.dash(&[10., -5.], 0.), vec![subpath.clone()]);
+	}
+
+	#[test]
+	fn dash_count_is_limited() {
+		let subpath: Subpath<EmptyId> = Subpath::from_bezier(&Bezier::from_linear_dvec2(DVec2::ZERO, DVec2::new(100., 0.)));
+		let dashes = subpath.dash(&[1e-6], 0.);
+
+		assert_eq!(dashes.len(), MAX_DASHES);
+		assert!(compare_points(dashes[0].manipulator_groups()[0].anchor, DVec2::ZERO));
+	}
+
+	#[test]
+	fn offset_miter_join_between_straight_segments() {
+		// Segments made by `from_anchors` have their handles on top of their anchors, but should still be joined like a polyline
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., -100.)], false);
+		let offset = subpath.offset(10., Join::Miter(None));
+
+		// The same geometry an SVG renderer produces for one side of `stroke-width="20" stroke-linejoin="miter"`, with the miter tip inserted between the offset segments
+		let expected = vec![DVec2::new(0., 10.), DVec2::new(100., 10.), DVec2::new(110., 10.), DVec2::new(110., 0.), DVec2::new(110., -100.)];
+		assert!(compare_vec_of_points(offset.anchors(), expected, MAX_ABSOLUTE_DIFFERENCE));
+	}
+
+	#[test]
+	fn offset_miter_limit_falls_back_to_bevel() {
+		let corner = DVec2::new(100., 0.);
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, corner, DVec2::new(0., -20.)], false);
+
+		// Like SVG, the miter length relative to the stroke width is 1 / sin(θ / 2), where θ is the angle between the segments
+		let in_direction = DVec2::X;
+		let out_direction = (DVec2::new(0., -20.) - corner).normalize();
+		let half_angle = in_direction.angle_between(-out_direction).abs() / 2.;
+		let miter_ratio = 1. / half_angle.sin();
+		assert!(miter_ratio > 10. && miter_ratio < 10.2);
+
+		let beveled = subpath.offset(10., Join::Miter(Some(10.)));
+		assert_eq!(beveled.len(), 4);
+		assert_eq!(beveled, subpath.offset(10., Join::Bevel));
+
+		let mitered = subpath.offset(10., Join::Miter(Some(10.2)));
+		let tip = corner + (in_direction - out_direction).normalize() * 10. * miter_ratio;
+		assert_eq!(mitered.len(), 5);
+		assert!(mitered.manipulator_groups()[2].anchor.abs_diff_eq(tip, 1e-6));
+	}
+
+	#[test]
+	fn offset_round_join_between_straight_segments() {
+		let corner = DVec2::new(100., 0.);
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, corner, DVec2::new(100., -100.)], false);
+		let offset = subpath.offset(10., Join::Round);
+
+		// The round join spans the segments between the ends of the offset lines, and each of them stays on the circle around the corner
+		let anchors = offset.anchors();
+		assert!(anchors[1].abs_diff_eq(DVec2::new(100., 10.), MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors[anchors.len() - 2].abs_diff_eq(DVec2::new(110., 0.), MAX_ABSOLUTE_DIFFERENCE));
+		for arc in offset.iter().skip(1).take(anchors.len() - 3) {
+			for t in [0., 0.25, 0.5, 0.75, 1.] {
+				assert!((arc.evaluate(TValue::Parametric(t)).distance(corner) - 10.).abs() < 0.01);
+			}
+		}
+	}
+
+	#[test]
+	fn outline_closed_polyline() {
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 50.), DVec2::new(0., 50.)], true);
+		let (inside, outside) = subpath.outline(5., Join::Miter(None), Cap::Butt);
+		let outside = outside.unwrap();
+
+		// Both sides are rectangles with sharp corners, without any stray points from the degenerate handles
+		for (anchors, min, max) in [
+			(outside.anchors(), DVec2::splat(-5.), DVec2::new(105., 55.)),
+			(inside.anchors(), DVec2::splat(5.), DVec2::new(95., 45.)),
+		] {
+			let on_edge = |value: f64, min: f64, max: f64| (value - min).abs() < MAX_ABSOLUTE_DIFFERENCE || (value - max).abs() < MAX_ABSOLUTE_DIFFERENCE;
+			assert!(anchors.iter().all(|anchor| on_edge(anchor.x, min.x, max.x) || on_edge(anchor.y, min.y, max.y)), "{anchors:?}");
+			for corner in [min, DVec2::new(max.x, min.y), max, DVec2::new(min.x, max.y)] {
+				assert!(anchors.iter().any(|anchor| anchor.abs_diff_eq(corner, MAX_ABSOLUTE_DIFFERENCE)), "{anchors:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn round_corners_square() {
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, DVec2::new(100., 0.), DVec2::new(100., 100.), DVec2::new(0., 100.)], true);
+		let rounded = subpath.round_corners(10., FilletType::Circular, 0., |_| true);
+
+		assert_eq!(rounded.len(), 8);
+		assert!(rounded.closed());
+		let anchors = rounded.anchors();
+		assert!(anchors[0].abs_diff_eq(DVec2::new(0., 10.), MAX_ABSOLUTE_DIFFERENCE), "{anchors:?}");
+		assert!(anchors[1].abs_diff_eq(DVec2::new(10., 0.), MAX_ABSOLUTE_DIFFERENCE), "{anchors:?}");
+		assert!(anchors[2].abs_diff_eq(DVec2::new(90., 0.), MAX_ABSOLUTE_DIFFERENCE), "{anchors:?}");
+
+		// The midpoint of each fillet lies on the circle of the given radius
+		let center = DVec2::splat(10.);
+		let fillet = rounded.iter().next().unwrap();
+		assert!((fillet.evaluate(TValue::Parametric(0.5)).distance(center) - 10.).abs() < 0.01);
+	}
+
+	#[test]
+	fn round_corners_clamped_and_excluded() {
+		let subpath: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 100.)], false);
+		let rounded = subpath.round_corners(50., FilletType::Smooth, 0., |_| true);
+
+		// The radius is reduced so the fillet uses up the whole of the short segment, which is merged away, and the open ends are left alone
+		let anchors = rounded.anchors();
+		assert_eq!(anchors.len(), 3);
+		assert!(anchors[0].abs_diff_eq(DVec2::ZERO, MAX_ABSOLUTE_DIFFERENCE));
+		assert!(anchors[1].abs_diff_eq(DVec2::new(10., 10.), MAX_ABSOLUTE_DIFFERENCE), "{anchors:?}");
+		assert!(anchors[2].abs_diff_eq(DVec2::new(10., 100.), MAX_ABSOLUTE_DIFFERENCE), "{anchors:?}");
+		assert!(rounded.iter().all(|segment| !segment.is_point()));
+
+		// Fillets which meet in the middle of every side of a closed square leave only the fillets themselves
+		let square: Subpath<EmptyId> = Subpath::from_anchors([DVec2::ZERO, DVec2::new(10., 0.), DVec2::new(10., 10.), DVec2::new(0., 10.)], true);
+		let circle = square.round_corners(100., FilletType::Circular, 0., |_| true);
+		assert_eq!(circle.len(), 4);
+		assert!(circle.closed());
+		assert!(circle.iter().all(|segment| !segment.is_point()));
+
+		let unchanged = subpath.round_corners(50., FilletType::Smooth, 0., |index| index != 1);
+		assert_eq!(unchanged.anchors(), subpath.anchors());
+	}
 }
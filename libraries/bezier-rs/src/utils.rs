@@ -27,12 +27,27 @@ pub enum TValueType {
 
 #[derive(Copy, Clone, PartialEq)]
 pub enum SubpathTValue {
-	Parametric { segment_index: usize, t: f64 },
+	Parametric {
+		segment_index: usize,
+		t: f64,
+	},
 	GlobalParametric(f64),
-	Euclidean { segment_index: usize, t: f64 },
+	Euclidean {
+		segment_index: usize,
+		t: f64,
+	},
 	GlobalEuclidean(f64),
-	EuclideanWithinError { segment_index: usize, t: f64, error: f64 },
-	GlobalEuclideanWithinError { t: f64, error: f64 },
+	EuclideanWithinError {
+		segment_index: usize,
+		t: f64,
+		error: f64,
+	},
+	GlobalEuclideanWithinError {
+		t: f64,
+		error: f64,
+	},
+	/// A distance along the length of the whole subpath, which is clamped to the range [0, length of the subpath].
+	GlobalEuclideanDistance(f64),
 }
 
 #[derive(Copy, Clone)]
@@ -61,6 +76,60 @@ pub enum Cap {
 	Square,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Represents the shape of the curve which replaces a corner of a path when it is rounded.
+pub enum FilletType {
+	/// An arc of a circle, tangent to both segments which meet at the corner.
+	Circular,
+	/// A curve which eases into the corner more gradually than a circular arc, as if the corner was the control point of a quadratic curve.
+	Smooth,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Represents the rule used to determine which points lie inside of a shape made from one or more closed subpaths.
+/// As defined in SVG: <https://www.w3.org/TR/SVG2/painting.html#FillRuleProperty>.
+pub enum FillRule {
+	/// A point is inside the shape if the winding number of the shape's subpaths around it is not zero.
+	NonZero,
+	/// A point is inside the shape if the winding number of the shape's subpaths around it is odd.
+	EvenOdd,
+}
+
+impl FillRule {
+	/// Returns true if a point with the given winding number is considered inside the shape under this fill rule.
+	pub fn is_inside(&self, winding_number: i32) -> bool {
+		match self {
+			FillRule::NonZero => winding_number != 0,
+			FillRule::EvenOdd => winding_number % 2 != 0,
+		}
+	}
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Represents the way in which the filled areas of two shapes are combined by a boolean operation.
+pub enum BooleanOperation {
+	/// The area covered by either of the shapes.
+	Union,
+	/// The area covered by the first shape but not by the second shape.
+	Difference,
+	/// The area covered by both of the shapes.
+	Intersection,
+	/// The area covered by exactly one of the shapes.
+	Xor,
+}
+
+impl BooleanOperation {
+	/// Returns true if a point that is inside of the first shape (`inside_a`) and/or the second shape (`inside_b`) belongs to the result.
+	pub fn combine(&self, inside_a: bool, inside_b: bool) -> bool {
+		match self {
+			BooleanOperation::Union => inside_a || inside_b,
+			BooleanOperation::Difference => inside_a && !inside_b,
+			BooleanOperation::Intersection => inside_a && inside_b,
+			BooleanOperation::Xor => inside_a != inside_b,
+		}
+	}
+}
+
 /// Helper to perform the computation of a and c, where b is the provided point on the curve.
 /// Given the correct power of `t` and `(1-t)`, the computation is the same for quadratic and cubic cases.
 /// Relevant derivation and the definitions of a, b, and c can be found in [the projection identity section](https://pomax.github.io/bezierinfo/#abc) of Pomax's bezier curve primer.
@@ -3,3 +3,11 @@ use crate::raster::Color;
 // RENDERING
 pub const LAYER_OUTLINE_STROKE_COLOR: Color = Color::BLACK;
 pub const LAYER_OUTLINE_STROKE_WEIGHT: f64 = 1.;
+
+// VECTOR
+pub const MAX_RESAMPLED_POINTS: usize = 10_000;
+pub const FILL_SAMPLE_RELATIVE_DISTANCE: f64 = 1e-4;
+
+// RASTER
+pub const MAX_BLUR_RADIUS_PIXELS: f64 = 500.;
+pub const MAX_MOTION_BLUR_SAMPLES: usize = 1000;
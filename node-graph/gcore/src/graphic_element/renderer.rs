@@ -1,5 +1,6 @@
 use crate::raster::{BlendMode, Image, ImageFrame};
 use crate::uuid::{generate_uuid, ManipulatorGroupId};
+use crate::vector::style::FillRule;
 use crate::{vector::VectorData, Artboard, Color, GraphicElementData, GraphicGroup};
 use base64::Engine;
 use bezier_rs::Subpath;
@@ -15,6 +16,7 @@ mod quad;
 pub struct ClickTarget {
 	pub subpath: bezier_rs::Subpath<ManipulatorGroupId>,
 	pub stroke_width: f64,
+	pub fill_rule: FillRule,
 }
 
 impl ClickTarget {
@@ -22,6 +24,40 @@ impl ClickTarget {
 	pub fn intersect_rectangle(&self, document_quad: Quad, layer_transform: DAffine2) -> bool {
 		let quad = layer_transform.inverse() * document_quad;
 
+		// Check if the outlines intersect or if either of the shape and the selection is entirely within the other
+		self.intersect_outline(quad) || self.fill_contains(quad.center())
+	}
+
+	/// Does the click target intersect the point (accounting for stroke size and the fill rule)
+	pub fn intersect_point(&self, point: DVec2, layer_transform: DAffine2) -> bool {
+		// Allows for selecting lines
+		// TODO: actual intersection of stroke
+		self.intersect_rectangle(self.inflated_point(point), layer_transform)
+	}
+
+	/// Do any of the click targets of a layer intersect the point (accounting for stroke size and the fill rule)?
+	/// Unlike checking each click target on its own, the winding numbers of all the filled click targets are combined, so holes formed by other subpaths are not clickable.
+	pub fn targets_intersect_point(click_targets: &[ClickTarget], point: DVec2, layer_transform: DAffine2) -> bool {
+		let to_layer = layer_transform.inverse();
+		if click_targets.iter().any(|target| target.intersect_outline(to_layer * target.inflated_point(point))) {
+			return true;
+		}
+
+		let point = to_layer.transform_point2(point);
+		[FillRule::NonZero, FillRule::EvenOdd].into_iter().any(|fill_rule| {
+			let filled_targets = click_targets.iter().filter(|target| target.fill_rule == fill_rule && target.subpath.closed());
+			let winding_number = filled_targets.map(|target| target.subpath.winding_number(point)).sum();
+			bezier_rs::FillRule::from(fill_rule).is_inside(winding_number)
+		})
+	}
+
+	/// A square around the point in document space, the size of the stroke width
+	fn inflated_point(&self, point: DVec2) -> Quad {
+		Quad::from_box([point - DVec2::splat(self.stroke_width / 2.), point + DVec2::splat(self.stroke_width / 2.)])
+	}
+
+	/// Does the outline of the click target intersect the quad in layer space, or is the shape entirely within it
+	fn intersect_outline(&self, quad: Quad) -> bool {
 		// Check if outlines intersect
 		if self
 			.subpath
@@ -30,10 +66,6 @@ impl ClickTarget {
 		{
 			return true;
 		}
-		// Check if selection is entirely within the shape
-		if self.subpath.closed() && self.subpath.contains_point(quad.center()) {
-			return true;
-		}
 
 		// Check if shape is entirely within selection
 		self.subpath
@@ -44,12 +76,9 @@ impl ClickTarget {
 			.unwrap_or_default()
 	}
 
-	/// Does the click target intersect the point (accounting for stroke size)
-	pub fn intersect_point(&self, point: DVec2, layer_transform: DAffine2) -> bool {
-		// Allows for selecting lines
-		// TODO: actual intersection of stroke
-		let inflated_quad = Quad::from_box([point - DVec2::splat(self.stroke_width / 2.), point + DVec2::splat(self.stroke_width / 2.)]);
-		self.intersect_rectangle(inflated_quad, layer_transform)
+	/// Is the point in layer space inside the filled area of the click target according to its fill rule
+	fn fill_contains(&self, point: DVec2) -> bool {
+		self.subpath.closed() && self.subpath.contains_point_with_fill_rule(point, self.fill_rule.into())
 	}
 }
 
@@ -248,7 +277,8 @@ impl GraphicElementRendered for VectorData {
 			subpath.set_closed(self.style.fill().is_some());
 			subpath
 		};
-		click_targets.extend(self.subpaths.iter().cloned().map(update_closed).map(|subpath| ClickTarget { stroke_width, subpath }))
+		let fill_rule = self.style.fill_rule();
+		click_targets.extend(self.subpaths.iter().cloned().map(update_closed).map(|subpath| ClickTarget { stroke_width, subpath, fill_rule }))
 	}
 }
 
@@ -321,7 +351,11 @@ impl GraphicElementRendered for Artboard {
 	}
 	fn add_click_targets(&self, click_targets: &mut Vec<ClickTarget>) {
 		let subpath = Subpath::new_rect(self.location.as_dvec2(), self.location.as_dvec2() + self.dimensions.as_dvec2());
-		click_targets.push(ClickTarget { stroke_width: 0., subpath });
+		click_targets.push(ClickTarget {
+			stroke_width: 0.,
+			subpath,
+			fill_rule: FillRule::default(),
+		});
 	}
 }
 
@@ -372,7 +406,11 @@ impl GraphicElementRendered for ImageFrame<Color> {
 	}
 	fn add_click_targets(&self, click_targets: &mut Vec<ClickTarget>) {
 		let subpath = Subpath::new_rect(DVec2::ZERO, DVec2::ONE);
-		click_targets.push(ClickTarget { subpath, stroke_width: 0. });
+		click_targets.push(ClickTarget {
+			subpath,
+			stroke_width: 0.,
+			fill_rule: FillRule::default(),
+		});
 	}
 }
 
@@ -12,14 +12,25 @@ use spirv_std::num_traits::float::Float;
 
 pub mod adjustments;
 pub mod bbox;
+#[cfg(feature = "alloc")]
+pub mod blur;
 #[cfg(not(target_arch = "spirv"))]
 pub mod brightness_contrast;
 #[cfg(not(target_arch = "spirv"))]
 pub mod brush_cache;
 pub mod color;
+#[cfg(feature = "alloc")]
+pub mod convolution;
 #[cfg(not(target_arch = "spirv"))]
 pub mod curve;
 pub mod discrete_srgb;
+#[cfg(feature = "alloc")]
+pub mod filters;
+#[cfg(feature = "alloc")]
+pub mod interpolation;
+pub mod noise;
+#[cfg(feature = "alloc")]
+pub mod resample;
 pub use adjustments::*;
 
 pub trait Linear {
@@ -603,19 +603,53 @@ impl core::fmt::Display for RedGreenBlue {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
 pub enum NoiseType {
 	WhiteNoise,
+	Perlin,
+	Simplex,
+	Worley,
 }
 
 impl core::fmt::Display for NoiseType {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		match self {
 			NoiseType::WhiteNoise => write!(f, "White Noise"),
+			NoiseType::Perlin => write!(f, "Perlin"),
+			NoiseType::Simplex => write!(f, "Simplex"),
+			NoiseType::Worley => write!(f, "Worley"),
 		}
 	}
 }
 
 impl NoiseType {
-	pub fn list() -> [NoiseType; 1] {
-		[NoiseType::WhiteNoise]
+	pub fn list() -> [NoiseType; 4] {
+		[NoiseType::WhiteNoise, NoiseType::Perlin, NoiseType::Simplex, NoiseType::Worley]
+	}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, DynAny)]
+pub enum FractalType {
+	#[default]
+	None,
+	Fbm,
+	Turbulence,
+	Ridged,
+}
+
+impl core::fmt::Display for FractalType {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			FractalType::None => write!(f, "None"),
+			FractalType::Fbm => write!(f, "fBm"),
+			FractalType::Turbulence => write!(f, "Turbulence"),
+			FractalType::Ridged => write!(f, "Ridged"),
+		}
+	}
+}
+
+impl FractalType {
+	pub fn list() -> [FractalType; 4] {
+		[FractalType::None, FractalType::Fbm, FractalType::Turbulence, FractalType::Ridged]
 	}
 }
 
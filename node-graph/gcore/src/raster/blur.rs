@@ -0,0 +1,164 @@
+use super::convolution::{convolve_separable, SeparableKernel};
+use super::{Color, Image, ImageFrame};
+use crate::consts::MAX_MOTION_BLUR_SAMPLES;
+use crate::Node;
+
+use glam::{DAffine2, DVec2};
+
+/// The number of image pixels spanned by one unit of layer space along each image axis.
+///
+/// Blur radii are given in layer space, so converting them through the frame's transform makes the result independent of the image resolution.
+/// Images sampled through a `Footprint` have their resolution matched to the viewport and their transform adjusted to compensate, so a preview at any zoom level blurs by the same visual amount as the full resolution export.
+fn pixels_per_unit(image_frame: &ImageFrame<Color>) -> DVec2 {
+	let size = DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64);
+	let axis_lengths = DVec2::new(image_frame.transform.matrix2.x_axis.length(), image_frame.transform.matrix2.y_axis.length());
+	if axis_lengths.x <= f64::EPSILON || axis_lengths.y <= f64::EPSILON {
+		return DVec2::ZERO;
+	}
+	size / axis_lengths
+}
+
+/// Blurs the image of the frame with the kernel built for a radius given in layer space, which is converted to pixels separately for each axis.
+pub(super) fn blur_image(image_frame: &ImageFrame<Color>, kernel: impl Fn(f64) -> SeparableKernel, radius: f64) -> Image<Color> {
+	let radius_in_pixels = pixels_per_unit(image_frame) * radius.max(0.);
+	convolve_separable(&image_frame.image, &kernel(radius_in_pixels.x), &kernel(radius_in_pixels.y))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GaussianBlurNode<Radius> {
+	radius: Radius,
+}
+
+/// Blurs the image with a Gaussian falloff whose standard deviation is `radius` units of layer space.
+#[node_macro::node_fn(GaussianBlurNode)]
+fn gaussian_blur(mut image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	image_frame.image = blur_image(&image_frame, SeparableKernel::gaussian, radius);
+	image_frame
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoxBlurNode<Radius> {
+	radius: Radius,
+}
+
+/// Replaces each pixel with the average of the square of pixels within `radius` units of layer space around it.
+#[node_macro::node_fn(BoxBlurNode)]
+fn box_blur(mut image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	image_frame.image = blur_image(&image_frame, SeparableKernel::box_filter, radius);
+	image_frame
+}
+
+/// Samples the image at a position given in pixels, where pixel centers lie at half-integer coordinates, interpolating bilinearly and clamping to the image edge.
+fn sample_bilinear(image: &Image<Color>, position: DVec2) -> [f32; 4] {
+	let max = DVec2::new(image.width as f64 - 1., image.height as f64 - 1.);
+	let position = (position - 0.5).clamp(DVec2::ZERO, max);
+	let (x0, y0) = (position.x.floor() as usize, position.y.floor() as usize);
+	let (x1, y1) = ((x0 + 1).min(image.width as usize - 1), (y0 + 1).min(image.height as usize - 1));
+	let (tx, ty) = ((position.x - x0 as f64) as f32, (position.y - y0 as f64) as f32);
+
+	let pixel = |x: usize, y: usize| {
+		let (red, green, blue, alpha) = image.data[y * image.width as usize + x].components();
+		[red, green, blue, alpha]
+	};
+	let lerp = |a: [f32; 4], b: [f32; 4], t: f32| [0, 1, 2, 3].map(|channel| a[channel] + (b[channel] - a[channel]) * t);
+
+	lerp(lerp(pixel(x0, y0), pixel(x1, y0), tx), lerp(pixel(x0, y1), pixel(x1, y1), tx), ty)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MotionBlurNode<Angle, Distance> {
+	angle: Angle,
+	distance: Distance,
+}
+
+/// Smears the image along a direction, averaging the pixels on a line segment `distance` units of layer space long that is centered on each pixel.
+///
+/// The angle is measured in degrees in layer space, so rotating or skewing the image frame doesn't change the direction of the streaks.
+/// Streaks are limited to [MAX_MOTION_BLUR_SAMPLES] pixels long.
+#[node_macro::node_fn(MotionBlurNode)]
+fn motion_blur(mut image_frame: ImageFrame<Color>, angle: f64, distance: f64) -> ImageFrame<Color> {
+	let image = &image_frame.image;
+	if image.data.is_empty() || image_frame.transform.matrix2.determinant().abs() <= f64::EPSILON {
+		return image_frame;
+	}
+
+	let size = DVec2::new(image.width as f64, image.height as f64);
+	let layer_to_pixels = DAffine2::from_scale(size) * image_frame.transform.inverse();
+	let streak = layer_to_pixels.transform_vector2(DVec2::from_angle(angle.to_radians()) * distance.max(0.));
+	if !streak.is_finite() || streak.length() < 0.5 {
+		return image_frame;
+	}
+
+	// Take at least one sample per pixel crossed so that no pixel along the streak is skipped
+	let streak = streak.clamp_length_max(MAX_MOTION_BLUR_SAMPLES as f64 - 1.);
+	let samples = streak.length().ceil() as usize + 1;
+	let weight = 1. / samples as f32;
+
+	let data = (0..image.data.len())
+		.map(|index| {
+			let center = DVec2::new((index % image.width as usize) as f64, (index / image.width as usize) as f64) + 0.5;
+
+			let mut sum = [0_f32; 4];
+			for sample in 0..samples {
+				let offset = sample as f64 / (samples - 1) as f64 - 0.5;
+				let color = sample_bilinear(image, center + streak * offset);
+				sum.iter_mut().zip(color).for_each(|(sum, channel)| *sum += channel * weight);
+			}
+			Color::from_rgbaf32_unchecked(sum[0], sum[1], sum[2], sum[3])
+		})
+		.collect();
+
+	image_frame.image = Image {
+		width: image.width,
+		height: image.height,
+		data,
+	};
+	image_frame
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn frame_with_dot(width: u32, height: u32, transform: DAffine2) -> ImageFrame<Color> {
+		let mut image = Image::new(width, height, Color::TRANSPARENT);
+		image.data[(height / 2 * width + width / 2) as usize] = Color::WHITE;
+		ImageFrame { image, transform }
+	}
+
+	#[test]
+	fn blur_radius_follows_the_transform() {
+		// Two frames of about the same size in layer space, stored at two and at four pixels per unit
+		let box_blur = BoxBlurNode::new(ClonedNode::new(1.));
+		let low_resolution = box_blur.eval(frame_with_dot(21, 1, DAffine2::from_scale(DVec2::new(10.5, 1.))));
+		let high_resolution = box_blur.eval(frame_with_dot(41, 1, DAffine2::from_scale(DVec2::new(10.25, 1.))));
+
+		let covered = |frame: &ImageFrame<Color>| frame.image.data.iter().filter(|pixel| pixel.a() > 0.).count();
+		assert_eq!(covered(&low_resolution), 5);
+		assert_eq!(covered(&high_resolution), 9);
+	}
+
+	#[test]
+	fn motion_blur_streaks_along_the_angle() {
+		let motion_blur = MotionBlurNode::new(ClonedNode::new(90.), ClonedNode::new(4.));
+		let blurred = motion_blur.eval(frame_with_dot(9, 9, DAffine2::from_scale(DVec2::splat(9.))));
+		let alpha = |x: usize, y: usize| blurred.image.data[y * 9 + x].a();
+
+		assert!(alpha(4, 2) > 0. && alpha(4, 6) > 0.);
+		assert_eq!(alpha(2, 4), 0.);
+		assert_eq!(alpha(6, 4), 0.);
+		let total: f32 = blurred.image.data.iter().map(|pixel| pixel.a()).sum();
+		assert!((total - 1.).abs() < 1e-4);
+	}
+
+	#[test]
+	fn huge_radii_are_limited() {
+		let frame = frame_with_dot(9, 9, DAffine2::from_scale(DVec2::splat(1e-6)));
+		let gaussian = GaussianBlurNode::new(ClonedNode::new(1e9)).eval(frame.clone());
+		let motion = MotionBlurNode::new(ClonedNode::new(30.), ClonedNode::new(1e9)).eval(frame);
+		for blurred in [gaussian, motion] {
+			assert!(blurred.image.data.iter().all(|pixel| pixel.a().is_finite() && pixel.a() < 1.));
+		}
+	}
+}
@@ -0,0 +1,280 @@
+use super::{Color, Image};
+use crate::consts::MAX_BLUR_RADIUS_PIXELS;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A normalized one-dimensional convolution kernel with an odd number of weights, centered on its middle weight.
+///
+/// Kernels that can be factored into a horizontal and a vertical pass (like the Gaussian and box filters) are applied with [`convolve_separable`],
+/// which costs `2 * (2r + 1)` weights per pixel instead of `(2r + 1)²`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeparableKernel {
+	weights: Vec<f32>,
+}
+
+impl SeparableKernel {
+	/// The kernel that leaves the image unchanged.
+	pub fn identity() -> Self {
+		Self { weights: vec![1.] }
+	}
+
+	/// Builds a kernel from the given symmetric weights, which are normalized to sum to one.
+	/// Returns `None` if there isn't an odd number of weights to center the kernel on. Weights that sum to zero give the identity kernel.
+	pub fn from_weights(weights: Vec<f32>) -> Option<Self> {
+		if weights.len() % 2 == 0 {
+			return None;
+		}
+		Some(Self::normalized(weights))
+	}
+
+	/// Normalizes an odd number of weights to sum to one.
+	fn normalized(mut weights: Vec<f32>) -> Self {
+		let total: f32 = weights.iter().sum();
+		if total.abs() < f32::EPSILON {
+			return Self::identity();
+		}
+		weights.iter_mut().for_each(|weight| *weight /= total);
+		Self { weights }
+	}
+
+	/// A Gaussian kernel with the given standard deviation in pixels, truncated at three standard deviations.
+	/// The standard deviation is limited so the kernel reaches at most [MAX_BLUR_RADIUS_PIXELS] pixels.
+	pub fn gaussian(sigma: f64) -> Self {
+		if !sigma.is_finite() || sigma <= 1e-3 {
+			return Self::identity();
+		}
+		let sigma = sigma.min(MAX_BLUR_RADIUS_PIXELS / 3.);
+		let radius = (sigma * 3.).ceil() as i64;
+		let weights = (-radius..=radius).map(|offset| (-(offset * offset) as f64 / (2. * sigma * sigma)).exp() as f32).collect();
+		Self::normalized(weights)
+	}
+
+	/// A box kernel averaging everything within `radius` pixels. A fractional radius partially weights the outermost pixels so the blur changes smoothly.
+	/// The radius is limited to [MAX_BLUR_RADIUS_PIXELS].
+	pub fn box_filter(radius: f64) -> Self {
+		if !radius.is_finite() || radius <= 1e-3 {
+			return Self::identity();
+		}
+		let radius = radius.min(MAX_BLUR_RADIUS_PIXELS);
+		let whole = radius.floor() as i64;
+		let fraction = (radius - radius.floor()) as f32;
+		let extent = if fraction > 0. { whole + 1 } else { whole };
+		let weights = (-extent..=extent).map(|offset| if offset.abs() <= whole { 1. } else { fraction }).collect();
+		Self::normalized(weights)
+	}
+
+	/// The number of pixels the kernel reaches on either side of its center.
+	pub fn radius(&self) -> usize {
+		self.weights.len() / 2
+	}
+
+	pub fn weights(&self) -> &[f32] {
+		&self.weights
+	}
+}
+
+/// Convolves every pixel with its neighbors along the rows (or columns), clamping lookups to the image edge.
+fn convolve_axis(image: &Image<Color>, kernel: &SeparableKernel, horizontal: bool) -> Image<Color> {
+	if kernel.radius() == 0 || image.data.is_empty() {
+		return image.clone();
+	}
+
+	let (width, height) = (image.width as usize, image.height as usize);
+	let (length, stride) = if horizontal { (width, 1) } else { (height, width) };
+	let radius = kernel.radius() as i64;
+
+	let data = (0..width * height)
+		.map(|index| {
+			let position = if horizontal { index % width } else { index / width } as i64;
+			let line_start = index - position as usize * stride;
+
+			let mut sum = [0_f32; 4];
+			for (offset, weight) in (-radius..=radius).zip(kernel.weights()) {
+				let neighbor = (position + offset).clamp(0, length as i64 - 1) as usize;
+				let (red, green, blue, alpha) = image.data[line_start + neighbor * stride].components();
+				sum[0] += red * weight;
+				sum[1] += green * weight;
+				sum[2] += blue * weight;
+				sum[3] += alpha * weight;
+			}
+			Color::from_rgbaf32_unchecked(sum[0], sum[1], sum[2], sum[3])
+		})
+		.collect();
+
+	Image {
+		width: image.width,
+		height: image.height,
+		data,
+	}
+}
+
+/// Applies `horizontal` along the rows and then `vertical` along the columns of the image.
+///
+/// Colors are convolved as they are stored, in linear light with premultiplied alpha, so transparent pixels don't bleed their color into their neighbors.
+pub fn convolve_separable(image: &Image<Color>, horizontal: &SeparableKernel, vertical: &SeparableKernel) -> Image<Color> {
+	let image = convolve_axis(image, horizontal, true);
+	convolve_axis(&image, vertical, false)
+}
+
+/// A two-dimensional convolution kernel with an odd width and height, centered on its middle weight.
+///
+/// Unlike [`SeparableKernel`], the weights aren't normalized, so derivative kernels like [`Kernel::sobel_horizontal`] can sum to zero and produce negative values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kernel {
+	width: usize,
+	height: usize,
+	weights: Vec<f32>,
+}
+
+impl Kernel {
+	/// Builds a kernel from its weights given row by row.
+	/// Returns `None` if the width or height is even, or if there isn't one weight per cell.
+	pub fn new(width: usize, height: usize, weights: Vec<f32>) -> Option<Self> {
+		if width % 2 == 0 || height % 2 == 0 || weights.len() != width * height {
+			return None;
+		}
+		Some(Self { width, height, weights })
+	}
+
+	/// A kernel of three by three weights given row by row.
+	fn square(weights: [f32; 9]) -> Self {
+		Self {
+			width: 3,
+			height: 3,
+			weights: weights.to_vec(),
+		}
+	}
+
+	/// The horizontal Sobel operator, which responds to changes from left to right.
+	pub fn sobel_horizontal() -> Self {
+		Self::square([-1., 0., 1., -2., 0., 2., -1., 0., 1.])
+	}
+
+	/// The vertical Sobel operator, which responds to changes from top to bottom.
+	pub fn sobel_vertical() -> Self {
+		Self::square([-1., -2., -1., 0., 0., 0., 1., 2., 1.])
+	}
+
+	/// The Laplacian operator including diagonal neighbors, which responds to changes in every direction.
+	pub fn laplacian() -> Self {
+		Self::square([1., 1., 1., 1., -8., 1., 1., 1., 1.])
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn weights(&self) -> &[f32] {
+		&self.weights
+	}
+}
+
+/// Convolves every pixel with the neighborhood covered by the kernel, clamping lookups to the image edge.
+///
+/// Like [`convolve_separable`], this works on the stored linear premultiplied colors. The result isn't clamped, so it may hold negative or out of gamut values.
+pub fn convolve(image: &Image<Color>, kernel: &Kernel) -> Image<Color> {
+	let (width, height) = (image.width as i64, image.height as i64);
+	let (radius_x, radius_y) = ((kernel.width / 2) as i64, (kernel.height / 2) as i64);
+
+	let data = (0..image.data.len() as i64)
+		.map(|index| {
+			let (x, y) = (index % width, index / width);
+
+			let mut sum = [0_f32; 4];
+			let offsets = (-radius_y..=radius_y).flat_map(|offset_y| (-radius_x..=radius_x).map(move |offset_x| (offset_x, offset_y)));
+			for ((offset_x, offset_y), weight) in offsets.zip(kernel.weights()) {
+				let neighbor_x = (x + offset_x).clamp(0, width - 1);
+				let neighbor_y = (y + offset_y).clamp(0, height - 1);
+				let (red, green, blue, alpha) = image.data[(neighbor_y * width + neighbor_x) as usize].components();
+				sum[0] += red * weight;
+				sum[1] += green * weight;
+				sum[2] += blue * weight;
+				sum[3] += alpha * weight;
+			}
+			Color::from_rgbaf32_unchecked(sum[0], sum[1], sum[2], sum[3])
+		})
+		.collect();
+
+	Image {
+		width: image.width,
+		height: image.height,
+		data,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn kernels_are_normalized() {
+		for kernel in [SeparableKernel::gaussian(2.5), SeparableKernel::box_filter(3.4), SeparableKernel::identity()] {
+			assert!((kernel.weights().iter().sum::<f32>() - 1.).abs() < 1e-5);
+			assert_eq!(kernel.weights().len(), kernel.radius() * 2 + 1);
+		}
+		assert_eq!(SeparableKernel::gaussian(2.).radius(), 6);
+		assert_eq!(SeparableKernel::box_filter(2.).radius(), 2);
+		assert_eq!(SeparableKernel::box_filter(2.5).radius(), 3);
+		assert_eq!(SeparableKernel::box_filter(0.), SeparableKernel::identity());
+	}
+
+	#[test]
+	fn invalid_kernels_are_rejected() {
+		assert_eq!(SeparableKernel::from_weights(vec![1., 1.]), None);
+		assert_eq!(SeparableKernel::from_weights(Vec::new()), None);
+		assert_eq!(SeparableKernel::from_weights(vec![1., -2., 1.]), Some(SeparableKernel::identity()));
+		assert_eq!(Kernel::new(2, 1, vec![1., 1.]), None);
+		assert_eq!(Kernel::new(3, 1, vec![1., 1.]), None);
+		assert_eq!(Kernel::new(3, 1, vec![1., 0., -1.]).map(|kernel| kernel.width()), Some(3));
+	}
+
+	#[test]
+	fn huge_radii_are_limited() {
+		assert_eq!(SeparableKernel::gaussian(1e12).radius(), MAX_BLUR_RADIUS_PIXELS as usize);
+		assert_eq!(SeparableKernel::box_filter(1e12).radius(), MAX_BLUR_RADIUS_PIXELS as usize);
+	}
+
+	#[test]
+	fn separable_convolution_preserves_flat_images() {
+		let color = Color::from_rgbaf32_unchecked(0.25, 0.5, 0.125, 0.5);
+		let image = Image::new(7, 5, color);
+		let blurred = convolve_separable(&image, &SeparableKernel::gaussian(1.5), &SeparableKernel::box_filter(2.));
+		for pixel in blurred.data {
+			assert!((pixel.r() - 0.25).abs() < 1e-5 && (pixel.a() - 0.5).abs() < 1e-5);
+		}
+	}
+
+	#[test]
+	fn box_filter_spreads_a_single_pixel() {
+		let mut image = Image::new(5, 1, Color::TRANSPARENT);
+		image.data[2] = Color::WHITE;
+		let blurred = convolve_separable(&image, &SeparableKernel::box_filter(1.), &SeparableKernel::identity());
+		let alphas: Vec<f32> = blurred.data.iter().map(|pixel| pixel.a()).collect();
+		for (alpha, expected) in alphas.iter().zip([0., 1. / 3., 1. / 3., 1. / 3., 0.]) {
+			assert!((alpha - expected).abs() < 1e-6);
+		}
+	}
+
+	#[test]
+	fn derivative_kernels_respond_to_edges() {
+		// Transparent on the left half, opaque white on the right half
+		let mut image = Image::new(4, 3, Color::TRANSPARENT);
+		image.data.iter_mut().enumerate().filter(|(index, _)| index % 4 >= 2).for_each(|(_, pixel)| *pixel = Color::WHITE);
+
+		let horizontal = convolve(&image, &Kernel::sobel_horizontal());
+		let vertical = convolve(&image, &Kernel::sobel_vertical());
+		let laplacian = convolve(&image, &Kernel::laplacian());
+		for x in 0..4 {
+			let expected = if x == 1 || x == 2 { 4. } else { 0. };
+			assert_eq!(horizontal.data[4 + x].a(), expected);
+			assert_eq!(vertical.data[4 + x].a(), 0.);
+		}
+		assert_eq!(laplacian.data[4 + 1].a(), 3.);
+		assert_eq!(laplacian.data[4 + 2].a(), -3.);
+		assert_eq!(laplacian.data[4].a(), 0.);
+	}
+}
@@ -0,0 +1,256 @@
+use super::blur::blur_image;
+use super::convolution::{convolve, Kernel, SeparableKernel};
+use super::{Color, Image, ImageFrame};
+use crate::Node;
+use alloc::vec::Vec;
+
+use dyn_any::{DynAny, StaticType};
+use glam::{DAffine2, DVec2};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, DynAny, Hash)]
+pub enum EdgeDetectOperator {
+	#[default]
+	Sobel,
+	Laplacian,
+}
+
+impl EdgeDetectOperator {
+	pub fn list() -> [EdgeDetectOperator; 2] {
+		[EdgeDetectOperator::Sobel, EdgeDetectOperator::Laplacian]
+	}
+}
+
+impl core::fmt::Display for EdgeDetectOperator {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			EdgeDetectOperator::Sobel => write!(f, "Sobel"),
+			EdgeDetectOperator::Laplacian => write!(f, "Laplacian"),
+		}
+	}
+}
+
+/// Converts a stored color (linear and premultiplied) into gamma-encoded, unpremultiplied channels, where differences match how contrast is perceived.
+fn perceptual(color: Color) -> [f32; 3] {
+	let color = color.to_unassociated_alpha().to_gamma_srgb();
+	[color.r(), color.g(), color.b()]
+}
+
+/// Converts gamma-encoded, unpremultiplied channels back into a stored color with the given alpha.
+fn from_perceptual([red, green, blue]: [f32; 3], alpha: f32) -> Color {
+	let color = Color::from_rgbaf32_unchecked(red.clamp(0., 1.), green.clamp(0., 1.), blue.clamp(0., 1.), alpha).to_linear_srgb();
+	Color::from_unassociated_alpha(color.r(), color.g(), color.b(), alpha)
+}
+
+/// Builds an image from the gamma-encoded channels composited onto black, so that shapes on a transparent background keep their silhouette.
+fn perceptual_over_black(image: &Image<Color>) -> Image<Color> {
+	let data = image
+		.data
+		.iter()
+		.map(|pixel| {
+			let [red, green, blue] = perceptual(*pixel);
+			Color::from_rgbaf32_unchecked(red * pixel.a(), green * pixel.a(), blue * pixel.a(), pixel.a())
+		})
+		.collect();
+	Image {
+		width: image.width,
+		height: image.height,
+		data,
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct UnsharpMaskNode<Amount, Radius, Threshold> {
+	amount: Amount,
+	radius: Radius,
+	threshold: Threshold,
+}
+
+/// Sharpens the image by exaggerating its difference from a Gaussian blur of `radius` units of layer space.
+///
+/// The amount is a percentage of that difference to add back. Pixels whose difference stays below the threshold percentage in every channel are left untouched, which keeps noise and smooth gradients from being sharpened.
+#[node_macro::node_fn(UnsharpMaskNode)]
+fn unsharp_mask(mut image_frame: ImageFrame<Color>, amount: f32, radius: f64, threshold: f32) -> ImageFrame<Color> {
+	let blurred = blur_image(&image_frame, SeparableKernel::gaussian, radius);
+	let (amount, threshold) = (amount / 100., threshold / 100.);
+
+	for (pixel, blurred) in image_frame.image.data.iter_mut().zip(blurred.data) {
+		let (original, blurred) = (perceptual(*pixel), perceptual(blurred));
+		let difference = [0, 1, 2].map(|channel| original[channel] - blurred[channel]);
+		if difference.iter().all(|difference| difference.abs() < threshold) {
+			continue;
+		}
+
+		*pixel = from_perceptual([0, 1, 2].map(|channel| original[channel] + difference[channel] * amount), pixel.a());
+	}
+
+	image_frame
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HighPassNode<Radius> {
+	radius: Radius,
+}
+
+/// Keeps only the detail finer than `radius` units of layer space, as the difference from a Gaussian blur centered on middle gray.
+#[node_macro::node_fn(HighPassNode)]
+fn high_pass(mut image_frame: ImageFrame<Color>, radius: f64) -> ImageFrame<Color> {
+	let blurred = blur_image(&image_frame, SeparableKernel::gaussian, radius);
+
+	for (pixel, blurred) in image_frame.image.data.iter_mut().zip(blurred.data) {
+		let (original, blurred) = (perceptual(*pixel), perceptual(blurred));
+		*pixel = from_perceptual([0, 1, 2].map(|channel| 0.5 + original[channel] - blurred[channel]), pixel.a());
+	}
+
+	image_frame
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeDetectNode<Operator> {
+	operator: Operator,
+}
+
+/// Produces an opaque image that is bright where the color changes sharply and black elsewhere.
+///
+/// The Sobel operator measures the strength of the gradient in each channel, while the Laplacian operator responds to the change in gradient, giving thinner and noisier lines.
+#[node_macro::node_fn(EdgeDetectNode)]
+fn edge_detect(mut image_frame: ImageFrame<Color>, operator: EdgeDetectOperator) -> ImageFrame<Color> {
+	let image = perceptual_over_black(&image_frame.image);
+
+	let magnitudes: Vec<[f32; 3]> = match operator {
+		EdgeDetectOperator::Sobel => {
+			let horizontal = convolve(&image, &Kernel::sobel_horizontal());
+			let vertical = convolve(&image, &Kernel::sobel_vertical());
+			horizontal
+				.data
+				.iter()
+				.zip(&vertical.data)
+				.map(|(horizontal, vertical)| {
+					let magnitude = |x: f32, y: f32| (x * x + y * y).sqrt() / 4.;
+					[
+						magnitude(horizontal.r(), vertical.r()),
+						magnitude(horizontal.g(), vertical.g()),
+						magnitude(horizontal.b(), vertical.b()),
+					]
+				})
+				.collect()
+		}
+		EdgeDetectOperator::Laplacian => convolve(&image, &Kernel::laplacian())
+			.data
+			.iter()
+			.map(|pixel| [pixel.r(), pixel.g(), pixel.b()].map(|channel| channel.abs() / 3.))
+			.collect(),
+	};
+
+	for (pixel, magnitude) in image_frame.image.data.iter_mut().zip(magnitudes) {
+		*pixel = from_perceptual(magnitude, 1.);
+	}
+
+	image_frame
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EmbossNode<Angle, Amount> {
+	angle: Angle,
+	amount: Amount,
+}
+
+/// Turns the image into a gray relief, as if it were lit from the direction given by the angle in degrees in layer space.
+///
+/// The amount is a percentage that scales the height of the relief.
+#[node_macro::node_fn(EmbossNode)]
+fn emboss(mut image_frame: ImageFrame<Color>, angle: f64, amount: f32) -> ImageFrame<Color> {
+	if image_frame.image.data.is_empty() || image_frame.transform.matrix2.determinant().abs() <= f64::EPSILON {
+		return image_frame;
+	}
+
+	let size = DVec2::new(image_frame.image.width as f64, image_frame.image.height as f64);
+	let layer_to_pixels = DAffine2::from_scale(size) * image_frame.transform.inverse();
+	let light = layer_to_pixels.transform_vector2(DVec2::from_angle(angle.to_radians())).normalize_or_zero().as_vec2();
+
+	let image = perceptual_over_black(&image_frame.image);
+	let horizontal = convolve(&image, &Kernel::sobel_horizontal());
+	let vertical = convolve(&image, &Kernel::sobel_vertical());
+	let amount = amount / 100.;
+
+	for ((pixel, horizontal), vertical) in image_frame.image.data.iter_mut().zip(horizontal.data).zip(vertical.data) {
+		// Slopes rising away from the light are tilted toward it and brightened
+		let slope = |x: f32, y: f32| -(x * light.x + y * light.y) / 4.;
+		let slope = Color::from_rgbaf32_unchecked(slope(horizontal.r(), vertical.r()), slope(horizontal.g(), vertical.g()), slope(horizontal.b(), vertical.b()), 1.);
+		let gray = 0.5 + slope.luminance_srgb() * amount;
+		*pixel = from_perceptual([gray; 3], pixel.a());
+	}
+
+	image_frame
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	/// A frame of one pixel per unit whose left half is black and right half is white.
+	fn step_frame() -> ImageFrame<Color> {
+		let mut image = Image::new(8, 3, Color::BLACK);
+		image.data.iter_mut().enumerate().filter(|(index, _)| index % 8 >= 4).for_each(|(_, pixel)| *pixel = Color::WHITE);
+		ImageFrame {
+			image,
+			transform: DAffine2::from_scale(DVec2::new(8., 3.)),
+		}
+	}
+
+	#[test]
+	fn unsharp_mask_increases_contrast_at_edges() {
+		let sharpened = UnsharpMaskNode::new(ClonedNode::new(100.), ClonedNode::new(1.), ClonedNode::new(0.)).eval(step_frame());
+		// The step is already black and white, so the overshoot is clamped and nothing changes
+		for (sharpened, original) in sharpened.image.data.iter().zip(step_frame().image.data) {
+			assert!((sharpened.r() - original.r()).abs() < 1e-4);
+		}
+
+		let mut frame = step_frame();
+		frame
+			.image
+			.data
+			.iter_mut()
+			.for_each(|pixel| *pixel = Color::from_rgbaf32_unchecked(pixel.r() * 0.5 + 0.1, pixel.g() * 0.5 + 0.1, pixel.b() * 0.5 + 0.1, 1.));
+		let original = frame.image.clone();
+		let sharpened = UnsharpMaskNode::new(ClonedNode::new(100.), ClonedNode::new(1.), ClonedNode::new(0.)).eval(frame.clone());
+		assert!(sharpened.image.data[11].r() < original.data[11].r());
+		assert!(sharpened.image.data[12].r() > original.data[12].r());
+
+		let thresholded = UnsharpMaskNode::new(ClonedNode::new(100.), ClonedNode::new(1.), ClonedNode::new(100.)).eval(frame);
+		assert_eq!(thresholded.image, original);
+	}
+
+	#[test]
+	fn high_pass_of_flat_image_is_gray() {
+		let mut frame = step_frame();
+		frame.image = Image::new(8, 3, Color::WHITE);
+		let filtered = HighPassNode::new(ClonedNode::new(2.)).eval(frame);
+		for pixel in filtered.image.data {
+			assert!((Color::linear_to_srgb(pixel.r()) - 0.5).abs() < 1e-4);
+		}
+	}
+
+	#[test]
+	fn edges_are_found_along_the_step() {
+		for operator in EdgeDetectOperator::list() {
+			let edges = EdgeDetectNode::new(ClonedNode::new(operator)).eval(step_frame());
+			for (x, pixel) in edges.image.data.iter().take(8).enumerate() {
+				let on_edge = x == 3 || x == 4;
+				assert_eq!(pixel.r() > 1e-3, on_edge);
+				assert_eq!(pixel.a(), 1.);
+			}
+		}
+	}
+
+	#[test]
+	fn emboss_lights_edges_facing_the_light() {
+		// With the light coming from the left, the step rising to the right is tilted toward it
+		let embossed = EmbossNode::new(ClonedNode::new(180.), ClonedNode::new(100.)).eval(step_frame());
+		let gray = |x: usize| Color::linear_to_srgb(embossed.image.data[8 + x].r());
+		assert!((gray(0) - 0.5).abs() < 1e-4);
+		assert!(gray(3) > 0.5);
+	}
+}
@@ -0,0 +1,200 @@
+use super::{Color, Image};
+use crate::transform::RenderQuality;
+
+use dyn_any::{DynAny, StaticType};
+use glam::{DVec2, IVec2};
+
+/// The filter used to reconstruct an image when it is drawn at a different size or position than its pixel grid.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", derive(specta::Type))]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, DynAny, Hash)]
+pub enum InterpolationMode {
+	/// Picks a filter based on the quality that is requested for the render.
+	#[default]
+	Auto,
+	/// Keeps hard pixel edges, which suits pixel art.
+	NearestNeighbor,
+	Bilinear,
+	Bicubic,
+	/// The sharpest filter, at the cost of slight ringing next to hard edges.
+	Lanczos,
+}
+
+impl InterpolationMode {
+	pub fn list() -> [InterpolationMode; 5] {
+		[
+			InterpolationMode::Auto,
+			InterpolationMode::NearestNeighbor,
+			InterpolationMode::Bilinear,
+			InterpolationMode::Bicubic,
+			InterpolationMode::Lanczos,
+		]
+	}
+
+	/// Resolves [`InterpolationMode::Auto`] to a concrete filter for the render quality: bilinear for previews, bicubic while the render is being refined and Lanczos at full quality.
+	/// Any other mode is an explicit choice and is kept as it is.
+	pub fn for_quality(self, quality: RenderQuality) -> InterpolationMode {
+		match (self, quality) {
+			(InterpolationMode::Auto, RenderQuality::Preview) => InterpolationMode::Bilinear,
+			(InterpolationMode::Auto, RenderQuality::Scale(_) | RenderQuality::Probabilty(_)) => InterpolationMode::Bicubic,
+			(InterpolationMode::Auto, RenderQuality::Full) => InterpolationMode::Lanczos,
+			(mode, _) => mode,
+		}
+	}
+
+	/// How many pixels the filter reaches on either side of the sample position when the image isn't shrunk.
+	pub fn support(self) -> f64 {
+		match self {
+			InterpolationMode::NearestNeighbor => 0.5,
+			InterpolationMode::Bilinear => 1.,
+			InterpolationMode::Bicubic => 2.,
+			InterpolationMode::Auto | InterpolationMode::Lanczos => 3.,
+		}
+	}
+
+	/// The weight of the filter at a distance in pixels from the sample position. [`InterpolationMode::Auto`] weighs like Lanczos, the filter it resolves to at full quality.
+	pub fn weight(self, distance: f64) -> f64 {
+		let x = distance.abs();
+		match self {
+			InterpolationMode::NearestNeighbor => {
+				if x < 0.5 {
+					1.
+				} else {
+					0.
+				}
+			}
+			InterpolationMode::Bilinear => (1. - x).max(0.),
+			// The Catmull-Rom spline, which passes through the value of every pixel
+			InterpolationMode::Bicubic => {
+				if x < 1. {
+					(1.5 * x - 2.5) * x * x + 1.
+				} else if x < 2. {
+					((-0.5 * x + 2.5) * x - 4.) * x + 2.
+				} else {
+					0.
+				}
+			}
+			InterpolationMode::Auto | InterpolationMode::Lanczos => {
+				let support = 3.;
+				if x < 1e-8 {
+					1.
+				} else if x < support {
+					let pi_x = core::f64::consts::PI * x;
+					support * pi_x.sin() * (pi_x / support).sin() / (pi_x * pi_x)
+				} else {
+					0.
+				}
+			}
+		}
+	}
+}
+
+impl core::fmt::Display for InterpolationMode {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			InterpolationMode::Auto => write!(f, "Auto"),
+			InterpolationMode::NearestNeighbor => write!(f, "Nearest Neighbor"),
+			InterpolationMode::Bilinear => write!(f, "Bilinear"),
+			InterpolationMode::Bicubic => write!(f, "Bicubic"),
+			InterpolationMode::Lanczos => write!(f, "Lanczos"),
+		}
+	}
+}
+
+/// Samples the image at a position given in pixels, where pixel centers lie at half-integer coordinates, clamping lookups to the image edge.
+///
+/// The footprint is the number of image pixels covered by one pixel of the output along each axis.
+/// Above one, the filter is stretched to match, so that shrinking the image averages every pixel it covers instead of skipping some of them.
+/// Nearest neighbor sampling ignores the footprint to keep hard pixel edges.
+pub fn sample(image: &Image<Color>, position: DVec2, footprint: DVec2, mode: InterpolationMode) -> Color {
+	if image.data.is_empty() {
+		return Color::TRANSPARENT;
+	}
+	let width = image.width as usize;
+	let last = IVec2::new(image.width as i32 - 1, image.height as i32 - 1);
+
+	if mode == InterpolationMode::NearestNeighbor {
+		let pixel = position.floor().as_ivec2().clamp(IVec2::ZERO, last);
+		return image.data[pixel.y as usize * width + pixel.x as usize];
+	}
+
+	let footprint = footprint.max(DVec2::ONE);
+	let center = position - 0.5;
+	let reach = footprint * mode.support();
+	let (start, end) = ((center - reach).ceil().as_ivec2(), (center + reach).floor().as_ivec2());
+	// The weights are computed as they are needed, since this runs for every output pixel and shouldn't allocate
+	let weight_of = |pixel: i32, center: f64, footprint: f64| mode.weight((pixel as f64 - center) / footprint);
+
+	let mut sum = [0_f64; 4];
+	let mut total = 0.;
+	for y in start.y..=end.y {
+		let vertical = weight_of(y, center.y, footprint.y);
+		if vertical == 0. {
+			continue;
+		}
+		let row = y.clamp(0, last.y) as usize * width;
+		for x in start.x..=end.x {
+			let weight = vertical * weight_of(x, center.x, footprint.x);
+			if weight == 0. {
+				continue;
+			}
+			let (red, green, blue, alpha) = image.data[row + x.clamp(0, last.x) as usize].components();
+			sum.iter_mut().zip([red, green, blue, alpha]).for_each(|(sum, channel)| *sum += channel as f64 * weight);
+			total += weight;
+		}
+	}
+	if total.abs() <= f64::EPSILON {
+		return Color::TRANSPARENT;
+	}
+
+	// Bicubic and Lanczos filters overshoot next to hard edges, which must not leave the range of valid premultiplied colors
+	let alpha = (sum[3] / total).clamp(0., 1.) as f32;
+	let channel = |sum: f64| ((sum / total) as f32).clamp(0., alpha);
+	Color::from_rgbaf32_unchecked(channel(sum[0]), channel(sum[1]), channel(sum[2]), alpha)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn auto_follows_the_render_quality() {
+		assert_eq!(InterpolationMode::Auto.for_quality(RenderQuality::Preview), InterpolationMode::Bilinear);
+		assert_eq!(InterpolationMode::Auto.for_quality(RenderQuality::Scale(0.5)), InterpolationMode::Bicubic);
+		assert_eq!(InterpolationMode::Auto.for_quality(RenderQuality::Full), InterpolationMode::Lanczos);
+		for mode in InterpolationMode::list().into_iter().skip(1) {
+			assert_eq!(mode.for_quality(RenderQuality::Preview), mode);
+			assert_eq!(mode.for_quality(RenderQuality::Full), mode);
+		}
+	}
+
+	#[test]
+	fn filters_reproduce_pixels_at_their_centers() {
+		let mut image = Image::new(5, 3, Color::BLACK);
+		image
+			.data
+			.iter_mut()
+			.enumerate()
+			.for_each(|(index, pixel)| *pixel = Color::from_rgbaf32_unchecked(index as f32 / 15., 0., 0., 1.));
+
+		for mode in InterpolationMode::list() {
+			for (index, pixel) in image.data.iter().enumerate() {
+				let center = DVec2::new((index % 5) as f64, (index / 5) as f64) + 0.5;
+				let sampled = sample(&image, center, DVec2::ONE, mode);
+				assert!((sampled.r() - pixel.r()).abs() < 1e-5, "{mode:?} at {center}");
+			}
+		}
+	}
+
+	#[test]
+	fn shrinking_averages_the_covered_pixels() {
+		// Alternating black and white columns, which skipping pixels would turn into a solid color
+		let mut image = Image::new(16, 1, Color::BLACK);
+		image.data.iter_mut().step_by(2).for_each(|pixel| *pixel = Color::WHITE);
+
+		for mode in InterpolationMode::list().into_iter().skip(2) {
+			let sampled = sample(&image, DVec2::new(8., 0.5), DVec2::new(4., 1.), mode);
+			assert!((sampled.r() - 0.5).abs() < 0.05, "{mode:?} gave {}", sampled.r());
+		}
+	}
+}
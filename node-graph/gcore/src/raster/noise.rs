@@ -0,0 +1,284 @@
+//! Seeded procedural noise, evaluated at any position so it can be sampled at whatever resolution it is displayed at.
+//!
+//! Positions are measured in cells, the size of the noise's features. Passing a period (in whole cells) wraps the lattice so that the noise repeats seamlessly.
+
+use super::{FractalType, NoiseType};
+
+use glam::{DVec2, DVec4, IVec2};
+
+#[cfg(target_arch = "spirv")]
+use spirv_std::num_traits::float::Float;
+
+/// Mixes the bits of the seed and each coordinate into a well distributed hash.
+fn hash(seed: u32, coordinates: &[i32]) -> u32 {
+	coordinates.iter().fold(seed, |hash, &coordinate| {
+		// The "lowbias32" integer hash by Chris Wellons
+		let mut hash = hash ^ (coordinate as u32).wrapping_add(0x9e3779b9);
+		hash ^= hash >> 16;
+		hash = hash.wrapping_mul(0x7feb352d);
+		hash ^= hash >> 15;
+		hash = hash.wrapping_mul(0x846ca68b);
+		hash ^ (hash >> 16)
+	})
+}
+
+/// Maps a hash onto the range [0, 1).
+fn unit_float(hash: u32) -> f64 {
+	hash as f64 / (u32::MAX as f64 + 1.)
+}
+
+fn wrap(cell: IVec2, period: Option<IVec2>) -> IVec2 {
+	match period {
+		Some(period) => IVec2::new(cell.x.rem_euclid(period.x.max(1)), cell.y.rem_euclid(period.y.max(1))),
+		None => cell,
+	}
+}
+
+/// One of eight evenly spaced unit vectors.
+fn gradient(hash: u32) -> DVec2 {
+	let angle = (hash & 7) as f64 * core::f64::consts::FRAC_PI_4;
+	DVec2::new(angle.cos(), angle.sin())
+}
+
+/// Quintic smoothstep, which keeps the second derivative of the noise continuous across cell boundaries.
+fn fade(t: DVec2) -> DVec2 {
+	t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+/// Classic Perlin gradient noise, in the range [-1, 1].
+pub fn perlin(position: DVec2, seed: u32, period: Option<IVec2>) -> f64 {
+	let cell = position.floor();
+	let offset = position - cell;
+
+	let corner = |corner: IVec2| {
+		let lattice = wrap(cell.as_ivec2() + corner, period);
+		gradient(hash(seed, &[lattice.x, lattice.y])).dot(offset - corner.as_dvec2())
+	};
+	let [bottom_left, bottom_right, top_left, top_right] = [IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(0, 1), IVec2::new(1, 1)].map(corner);
+
+	let t = fade(offset);
+	let bottom = bottom_left + (bottom_right - bottom_left) * t.x;
+	let top = top_left + (top_right - top_left) * t.x;
+
+	// Unit gradients reach at most √½, so scale the result up to fill the range
+	((bottom + (top - bottom) * t.y) * core::f64::consts::SQRT_2).clamp(-1., 1.)
+}
+
+/// Simplex gradient noise, in the range [-1, 1]. It has fewer directional artifacts than Perlin noise.
+///
+/// A skewed triangular lattice can't repeat on a rectangular period, so tiling noise is instead sampled from four dimensional simplex noise on a torus, where each axis wraps around a circle.
+pub fn simplex(position: DVec2, seed: u32, period: Option<IVec2>) -> f64 {
+	// Simplex lattices are denser than the square lattice of Perlin noise, so positions are scaled down to give features of about one cell
+	match period {
+		None => simplex_2d(position * 0.7, seed),
+		Some(period) => {
+			let period = period.max(IVec2::ONE).as_dvec2();
+			let angle = position / period * core::f64::consts::TAU;
+			// The circumference of each circle is proportional to the period, so features keep the same size as in the untiled noise
+			let radius = period / core::f64::consts::TAU * 0.83;
+			simplex_4d(DVec4::new(angle.x.cos() * radius.x, angle.x.sin() * radius.x, angle.y.cos() * radius.y, angle.y.sin() * radius.y), seed)
+		}
+	}
+}
+
+fn simplex_2d(position: DVec2, seed: u32) -> f64 {
+	let skew = (3_f64.sqrt() - 1.) / 2.;
+	let unskew = (3. - 3_f64.sqrt()) / 6.;
+
+	let cell = (position + (position.x + position.y) * skew).floor();
+	let origin = cell - (cell.x + cell.y) * unskew;
+	let offset = position - origin;
+
+	// Each square cell is split along its diagonal into two triangles
+	let middle = if offset.x > offset.y { DVec2::X } else { DVec2::Y };
+	let corners = [DVec2::ZERO, middle, DVec2::ONE];
+
+	let sum: f64 = corners
+		.iter()
+		.map(|&corner| {
+			let distance = offset - corner + (corner.x + corner.y) * unskew;
+			let falloff = 0.5 - distance.length_squared();
+			if falloff <= 0. {
+				return 0.;
+			}
+			let lattice = (cell + corner).as_ivec2();
+			falloff.powi(4) * gradient(hash(seed, &[lattice.x, lattice.y])).dot(distance)
+		})
+		.sum();
+
+	(sum * 99.2).clamp(-1., 1.)
+}
+
+fn simplex_4d(position: DVec4, seed: u32) -> f64 {
+	let skew = (5_f64.sqrt() - 1.) / 4.;
+	let unskew = (5. - 5_f64.sqrt()) / 20.;
+
+	let cell = (position + position.dot(DVec4::ONE) * skew).floor();
+	let offset = position - (cell - cell.dot(DVec4::ONE) * unskew);
+
+	// Rank the components of the offset to find which of the 24 simplices in the hypercube contains it
+	let components = offset.to_array();
+	let rank = |axis: usize| {
+		(0..4)
+			.filter(|&other| other != axis && (components[axis] > components[other] || (components[axis] == components[other] && axis < other)))
+			.count()
+	};
+	let ranks = [rank(0), rank(1), rank(2), rank(3)];
+	let corner = |threshold: usize| DVec4::from_array(ranks.map(|rank| if rank >= threshold { 1. } else { 0. }));
+	let corners = [DVec4::ZERO, corner(3), corner(2), corner(1), DVec4::ONE];
+
+	let sum: f64 = corners
+		.iter()
+		.map(|&corner| {
+			let distance = offset - corner + corner.dot(DVec4::ONE) * unskew;
+			let falloff = 0.6 - distance.length_squared();
+			if falloff <= 0. {
+				return 0.;
+			}
+			let lattice = (cell + corner).as_ivec4();
+			let corner_hash = hash(seed, &lattice.to_array());
+
+			// One of the 32 vectors pointing from the center of a 4D hypercube to the middle of its edges
+			let zero_axis = (corner_hash >> 3) as usize % 4;
+			let mut signs = corner_hash;
+			let gradient = core::array::from_fn(|axis| {
+				if axis == zero_axis {
+					return 0.;
+				}
+				let sign = if signs & 1 == 0 { 1. } else { -1. };
+				signs >>= 1;
+				sign
+			});
+
+			falloff.powi(4) * DVec4::from_array(gradient).dot(distance)
+		})
+		.sum();
+
+	(sum * 32.).clamp(-1., 1.)
+}
+
+/// Cellular noise: the distance to the nearest of a set of points scattered one per cell, mapped onto the range [-1, 1].
+pub fn worley(position: DVec2, seed: u32, period: Option<IVec2>) -> f64 {
+	let cell = position.floor().as_ivec2();
+
+	let mut nearest = f64::MAX;
+	for y in -1..=1 {
+		for x in -1..=1 {
+			let neighbor = cell + IVec2::new(x, y);
+			let lattice = wrap(neighbor, period);
+			let cell_hash = hash(seed, &[lattice.x, lattice.y]);
+			let feature = neighbor.as_dvec2() + DVec2::new(unit_float(cell_hash), unit_float(hash(cell_hash, &[0])));
+			nearest = nearest.min(position.distance_squared(feature));
+		}
+	}
+
+	(nearest.sqrt().min(1.) * 2. - 1.).clamp(-1., 1.)
+}
+
+/// A random value for each cell, in the range [-1, 1].
+pub fn white(position: DVec2, seed: u32, period: Option<IVec2>) -> f64 {
+	let lattice = wrap(position.floor().as_ivec2(), period);
+	unit_float(hash(seed, &[lattice.x, lattice.y])) * 2. - 1.
+}
+
+/// Samples a single octave of the given type of noise, in the range [-1, 1].
+pub fn sample(noise_type: NoiseType, position: DVec2, seed: u32, period: Option<IVec2>) -> f64 {
+	match noise_type {
+		NoiseType::WhiteNoise => white(position, seed, period),
+		NoiseType::Perlin => perlin(position, seed, period),
+		NoiseType::Simplex => simplex(position, seed, period),
+		NoiseType::Worley => worley(position, seed, period),
+	}
+}
+
+/// Layers octaves of noise, each with twice the frequency and half the amplitude of the previous one, and combines them according to the fractal type.
+///
+/// The result is normalized to the range [0, 1]. Periods are doubled along with the frequency, so tiling noise still tiles at every octave.
+pub fn fractal(noise_type: NoiseType, fractal_type: FractalType, octaves: u32, position: DVec2, seed: u32, period: Option<IVec2>) -> f64 {
+	let octaves = if fractal_type == FractalType::None { 1 } else { octaves.clamp(1, 16) };
+
+	let mut total = 0.;
+	let mut total_amplitude = 0.;
+	for octave in 0..octaves {
+		let frequency = (1 << octave) as f64;
+		let amplitude = 1. / frequency;
+		// Saturating keeps large periods from overflowing at the higher octaves
+		let period = period.map(|period| IVec2::new(period.x.saturating_mul(1 << octave), period.y.saturating_mul(1 << octave)));
+		let value = sample(noise_type, position * frequency, hash(seed, &[octave as i32]), period);
+
+		total += amplitude
+			* match fractal_type {
+				FractalType::None | FractalType::Fbm => value,
+				FractalType::Turbulence => value.abs(),
+				FractalType::Ridged => (1. - value.abs()).powi(2),
+			};
+		total_amplitude += amplitude;
+	}
+
+	let normalized = total / total_amplitude;
+	match fractal_type {
+		FractalType::None | FractalType::Fbm => (normalized + 1.) / 2.,
+		FractalType::Turbulence | FractalType::Ridged => normalized,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const NOISE_TYPES: [NoiseType; 4] = [NoiseType::WhiteNoise, NoiseType::Perlin, NoiseType::Simplex, NoiseType::Worley];
+	const FRACTAL_TYPES: [FractalType; 4] = [FractalType::None, FractalType::Fbm, FractalType::Turbulence, FractalType::Ridged];
+
+	fn positions() -> impl Iterator<Item = DVec2> {
+		(0..200).map(|index| DVec2::new((index % 20) as f64 * 0.37 - 3., (index / 20) as f64 * 0.53 - 2.))
+	}
+
+	#[test]
+	fn noise_stays_in_range() {
+		for noise_type in NOISE_TYPES {
+			for fractal_type in FRACTAL_TYPES {
+				for position in positions() {
+					let value = fractal(noise_type, fractal_type, 4, position, 7, None);
+					assert!((0. ..=1.).contains(&value), "{noise_type:?} {fractal_type:?} at {position} gave {value}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn seed_changes_the_noise() {
+		for noise_type in NOISE_TYPES {
+			let differs = positions().any(|position| sample(noise_type, position, 1, None) != sample(noise_type, position, 2, None));
+			assert!(differs, "{noise_type:?}");
+			let repeatable = positions().all(|position| sample(noise_type, position, 1, None) == sample(noise_type, position, 1, None));
+			assert!(repeatable, "{noise_type:?}");
+		}
+	}
+
+	#[test]
+	fn periodic_noise_tiles() {
+		let period = IVec2::new(4, 3);
+		for noise_type in NOISE_TYPES {
+			for position in positions() {
+				let value = fractal(noise_type, FractalType::Fbm, 3, position, 11, Some(period));
+				let shifted = fractal(noise_type, FractalType::Fbm, 3, position + period.as_dvec2() * DVec2::new(2., -1.), 11, Some(period));
+				assert!((value - shifted).abs() < 1e-9, "{noise_type:?} at {position}: {value} != {shifted}");
+			}
+		}
+	}
+
+	#[test]
+	fn large_periods_do_not_overflow() {
+		for noise_type in NOISE_TYPES {
+			let value = fractal(noise_type, FractalType::Fbm, 16, DVec2::new(0.3, 0.7), 11, Some(IVec2::splat(i32::MAX / 2)));
+			assert!((0. ..=1.).contains(&value), "{noise_type:?}: {value}");
+		}
+	}
+
+	#[test]
+	fn gradient_noise_is_zero_on_the_lattice() {
+		for position in [DVec2::ZERO, DVec2::new(3., -2.), DVec2::new(-5., 8.)] {
+			assert_eq!(perlin(position, 3, None), 0.);
+		}
+	}
+}
@@ -0,0 +1,203 @@
+use super::bbox::Bbox;
+use super::interpolation::{sample, InterpolationMode};
+use super::{Color, Image, ImageFrame};
+use crate::transform::RenderQuality;
+use crate::Node;
+use alloc::vec::Vec;
+
+use glam::{DAffine2, DVec2, UVec2};
+
+/// The most pixels that an image is resampled into, which stays far below the memory available to WebAssembly.
+const MAX_RESOLUTION: u32 = 8192 * 4096;
+
+/// Picks the size of the baked image. A requested axis below one pixel is derived from the other axis to keep the aspect ratio,
+/// and if neither axis is given, the image keeps the density of its source pixels.
+/// Sizes beyond [MAX_RESOLUTION] pixels are scaled down to it, keeping their aspect ratio, so the image can always be allocated.
+fn output_resolution(requested: DVec2, bounds_size: DVec2, pixels_per_unit: f64) -> UVec2 {
+	let resolution = match (requested.x >= 1., requested.y >= 1.) {
+		(true, true) => requested,
+		(true, false) => DVec2::new(requested.x, requested.x * bounds_size.y / bounds_size.x),
+		(false, true) => DVec2::new(requested.y * bounds_size.x / bounds_size.y, requested.y),
+		(false, false) => bounds_size * pixels_per_unit,
+	};
+	let resolution = resolution.round().max(DVec2::ONE);
+
+	let pixels = resolution.x * resolution.y;
+	let resolution = if pixels > MAX_RESOLUTION as f64 {
+		(resolution * (MAX_RESOLUTION as f64 / pixels).sqrt()).floor().max(DVec2::ONE)
+	} else {
+		resolution
+	};
+	// An extremely wide or tall image is left one pixel thick by the scaling above, so its length is limited on its own
+	resolution.min(DVec2::splat(MAX_RESOLUTION as f64)).as_uvec2()
+}
+
+/// The area of an output pixel, mapped into the pixel coordinates of the source image, that overlaps the source image.
+///
+/// The mapped pixel is a parallelogram, which is clipped against each edge of the image before measuring what is left of it.
+fn covered_area(output_to_source: DAffine2, output_pixel: DVec2, source_size: DVec2) -> f64 {
+	let corners = [DVec2::ZERO, DVec2::X, DVec2::ONE, DVec2::Y].map(|corner| output_to_source.transform_point2(output_pixel + corner));
+	let (min, max) = corners.iter().fold((DVec2::MAX, DVec2::MIN), |(min, max), &corner| (min.min(corner), max.max(corner)));
+	if min.cmpge(DVec2::ZERO).all() && max.cmple(source_size).all() {
+		return output_to_source.matrix2.determinant().abs();
+	}
+	if max.cmple(DVec2::ZERO).any() || min.cmpge(source_size).any() {
+		return 0.;
+	}
+
+	let mut polygon = corners.to_vec();
+	let edges = [(DVec2::X, 0.), (-DVec2::X, -source_size.x), (DVec2::Y, 0.), (-DVec2::Y, -source_size.y)];
+	for (normal, offset) in edges {
+		// Positive on the side of the edge that lies inside the image
+		let distance = |point: DVec2| point.dot(normal) - offset;
+		let mut clipped = Vec::with_capacity(polygon.len() + 1);
+		for (index, &current) in polygon.iter().enumerate() {
+			let previous = polygon[(index + polygon.len() - 1) % polygon.len()];
+			let (current_distance, previous_distance) = (distance(current), distance(previous));
+			if (current_distance >= 0.) != (previous_distance >= 0.) {
+				clipped.push(previous + (current - previous) * (previous_distance / (previous_distance - current_distance)));
+			}
+			if current_distance >= 0. {
+				clipped.push(current);
+			}
+		}
+		polygon = clipped;
+		if polygon.is_empty() {
+			return 0.;
+		}
+	}
+
+	let doubled_area: f64 = polygon.iter().zip(polygon.iter().cycle().skip(1)).map(|(a, b)| a.perp_dot(*b)).sum();
+	doubled_area.abs() / 2.
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ResampleImageNode<Resolution, Interpolation, Antialiasing> {
+	resolution: Resolution,
+	interpolation: Interpolation,
+	antialiasing: Antialiasing,
+}
+
+/// Bakes the transform of the image frame into new pixels, producing an axis-aligned image that covers the bounding box of the transformed image.
+///
+/// The resolution is the size of the new image in pixels. Leaving an axis at zero derives it from the other one, or from the density of the source pixels if both are zero.
+/// With antialiasing, pixels along the edges of a rotated or skewed image are made as opaque as the fraction of them that the image covers, otherwise each pixel is either fully inside or outside.
+/// [`InterpolationMode::Auto`] uses the filter for full quality renders.
+#[node_macro::node_fn(ResampleImageNode)]
+fn resample_image(image_frame: ImageFrame<Color>, resolution: DVec2, interpolation: InterpolationMode, antialiasing: bool) -> ImageFrame<Color> {
+	let image = &image_frame.image;
+	let source_area = image_frame.transform.matrix2.determinant().abs();
+	if image.data.is_empty() || source_area <= f64::EPSILON {
+		return ImageFrame::empty();
+	}
+
+	let source_size = DVec2::new(image.width as f64, image.height as f64);
+	let bounds = Bbox::from_transform(image_frame.transform).to_axis_aligned_bbox();
+	let pixels_per_unit = (source_size.x * source_size.y / source_area).sqrt();
+	let size = output_resolution(resolution, bounds.size(), pixels_per_unit);
+	let transform = bounds.to_transform();
+
+	let output_to_source = DAffine2::from_scale(source_size) * image_frame.transform.inverse() * transform * DAffine2::from_scale(size.as_dvec2().recip());
+	let mode = interpolation.for_quality(RenderQuality::Full);
+	// The source pixels spanned by one output pixel along each axis of the source image
+	let footprint = output_to_source.matrix2.x_axis.abs() + output_to_source.matrix2.y_axis.abs();
+	let pixel_area = output_to_source.matrix2.determinant().abs();
+
+	let data = (0..size.x as usize * size.y as usize)
+		.map(|index| {
+			let output_pixel = DVec2::new((index % size.x as usize) as f64, (index / size.x as usize) as f64);
+			let center = output_to_source.transform_point2(output_pixel + 0.5);
+
+			let coverage = if antialiasing {
+				(covered_area(output_to_source, output_pixel, source_size) / pixel_area).min(1.) as f32
+			} else if center.cmpge(DVec2::ZERO).all() && center.cmplt(source_size).all() {
+				1.
+			} else {
+				0.
+			};
+			if coverage <= 0. {
+				return Color::TRANSPARENT;
+			}
+
+			let color = sample(image, center, footprint, mode);
+			Color::from_rgbaf32_unchecked(color.r() * coverage, color.g() * coverage, color.b() * coverage, color.a() * coverage)
+		})
+		.collect();
+
+	ImageFrame {
+		image: Image { width: size.x, height: size.y, data },
+		transform,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	fn resample(image_frame: ImageFrame<Color>, resolution: DVec2, interpolation: InterpolationMode, antialiasing: bool) -> ImageFrame<Color> {
+		ResampleImageNode::new(ClonedNode::new(resolution), ClonedNode::new(interpolation), ClonedNode::new(antialiasing)).eval(image_frame)
+	}
+
+	#[test]
+	fn axis_aligned_frames_keep_their_pixels() {
+		let mut image = Image::new(4, 3, Color::BLACK);
+		image
+			.data
+			.iter_mut()
+			.enumerate()
+			.for_each(|(index, pixel)| *pixel = Color::from_rgbaf32_unchecked(index as f32 / 12., 0.5, 0., 1.));
+		let transform = DAffine2::from_translation(DVec2::new(5., -2.)) * DAffine2::from_scale(DVec2::new(8., 6.));
+		let image_frame = ImageFrame { image: image.clone(), transform };
+
+		for interpolation in InterpolationMode::list() {
+			let resampled = resample(image_frame.clone(), DVec2::ZERO, interpolation, true);
+			assert_eq!((resampled.image.width, resampled.image.height), (4, 3));
+			assert!(resampled.transform.abs_diff_eq(transform, 1e-9));
+			for (resampled, original) in resampled.image.data.iter().zip(&image.data) {
+				assert!((resampled.r() - original.r()).abs() < 1e-5 && (resampled.a() - 1.).abs() < 1e-5, "{interpolation:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn missing_resolution_keeps_the_aspect_ratio() {
+		let image_frame = ImageFrame {
+			image: Image::new(2, 2, Color::WHITE),
+			transform: DAffine2::from_scale(DVec2::new(20., 10.)),
+		};
+		let resampled = resample(image_frame, DVec2::new(30., 0.), InterpolationMode::Bilinear, true);
+		assert_eq!((resampled.image.width, resampled.image.height), (30, 15));
+	}
+
+	#[test]
+	fn huge_resolutions_are_limited() {
+		let size = output_resolution(DVec2::new(200_000., 100_000.), DVec2::ONE, 1.);
+		assert!(size.x as u64 * size.y as u64 <= MAX_RESOLUTION as u64);
+		assert!((size.x as f64 / size.y as f64 - 2.).abs() < 1e-3);
+
+		let size = output_resolution(DVec2::new(1e12, 1.), DVec2::ONE, 1.);
+		assert_eq!((size.x, size.y), (MAX_RESOLUTION, 1));
+	}
+
+	#[test]
+	fn rotated_edges_are_antialiased() {
+		let image_frame = ImageFrame {
+			image: Image::new(8, 8, Color::WHITE),
+			transform: DAffine2::from_angle(core::f64::consts::FRAC_PI_4) * DAffine2::from_scale(DVec2::splat(8.)),
+		};
+
+		let aliased = resample(image_frame.clone(), DVec2::splat(24.), InterpolationMode::NearestNeighbor, false);
+		assert!(aliased.image.data.iter().all(|pixel| pixel.a() == 0. || pixel.a() == 1.));
+
+		let antialiased = resample(image_frame, DVec2::splat(24.), InterpolationMode::NearestNeighbor, true);
+		assert_eq!(antialiased.image.data[0].a(), 0.);
+		assert_eq!(antialiased.image.data[12 * 24 + 12].a(), 1.);
+		assert!(antialiased.image.data.iter().any(|pixel| pixel.a() > 0.1 && pixel.a() < 0.9));
+
+		// The coverage is exact, so the opacity adds up to the area of the rotated square
+		let pixel_area = antialiased.transform.matrix2.determinant() / (24. * 24.);
+		let total: f64 = antialiased.image.data.iter().map(|pixel| pixel.a() as f64 * pixel_area).sum();
+		assert!((total - 64.).abs() < 1e-3, "{total}");
+	}
+}
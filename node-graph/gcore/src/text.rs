@@ -1,3 +1,5 @@
+#![allow(clippy::too_many_arguments)]
+
 mod font_cache;
 mod to_path;
 
@@ -8,14 +10,76 @@ pub use to_path::*;
 
 use crate::Node;
 
-pub struct TextGenerator<Text, FontName, Size> {
+pub struct TextGenerator<Text, FontName, Size, LineHeightRatio, LetterSpacing, ParagraphSpacing, Alignment, Kerning, Ligatures, Features> {
 	text: Text,
 	font_name: FontName,
 	font_size: Size,
+	line_height_ratio: LineHeightRatio,
+	letter_spacing: LetterSpacing,
+	paragraph_spacing: ParagraphSpacing,
+	alignment: Alignment,
+	kerning: Kerning,
+	ligatures: Ligatures,
+	features: Features,
 }
 
 #[node_fn(TextGenerator)]
-fn generate_text<'a: 'input, T>(editor: EditorApi<'a, T>, text: String, font_name: Font, font_size: f64) -> crate::vector::VectorData {
+fn generate_text<'a: 'input, T>(
+	editor: EditorApi<'a, T>,
+	text: String,
+	font_name: Font,
+	font_size: f64,
+	line_height_ratio: f64,
+	letter_spacing: f64,
+	paragraph_spacing: f64,
+	alignment: TextAlignment,
+	kerning: bool,
+	ligatures: bool,
+	features: String,
+) -> crate::vector::VectorData {
 	let buzz_face = editor.font_cache.get(&font_name).map(|data| load_face(data));
-	crate::vector::VectorData::from_subpaths(to_path(&text, buzz_face, font_size, None))
+	let config = TypesettingConfig {
+		font_size,
+		line_height_ratio,
+		letter_spacing,
+		paragraph_spacing,
+		max_width: None,
+		alignment,
+		kerning,
+		ligatures,
+		features,
+	};
+	crate::vector::VectorData::from_subpaths(to_path(&text, buzz_face, &config))
+}
+
+pub struct TextOnPathGenerator<Text, FontName, Size, Path, StartOffset, Alignment, Overflow> {
+	text: Text,
+	font_name: FontName,
+	font_size: Size,
+	path: Path,
+	start_offset: StartOffset,
+	alignment: Alignment,
+	overflow: Overflow,
+}
+
+#[node_fn(TextOnPathGenerator)]
+fn generate_text_on_path<'a: 'input, T>(
+	editor: EditorApi<'a, T>,
+	text: String,
+	font_name: Font,
+	font_size: f64,
+	path: crate::vector::VectorData,
+	start_offset: f64,
+	alignment: TextPathAlignment,
+	overflow: TextPathOverflow,
+) -> crate::vector::VectorData {
+	let buzz_face = editor.font_cache.get(&font_name).map(|data| load_face(data));
+
+	// The text follows the first subpath with any segments, in the space that contains the path
+	let Some(mut guide) = path.subpaths.iter().find(|subpath| subpath.len_segments() > 0).cloned() else {
+		return crate::vector::VectorData::empty();
+	};
+	guide.apply_transform(path.transform);
+
+	crate::vector::VectorData::from_subpaths(to_path_on_path(&text, buzz_face, font_size, &guide, start_offset, alignment, overflow))
 }
@@ -1,10 +1,13 @@
 use crate::uuid::ManipulatorGroupId;
 
 use bezier_rs::{ManipulatorGroup, Subpath};
+use dyn_any::{DynAny, StaticType};
+use serde::{Deserialize, Serialize};
 
-use glam::DVec2;
-use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder};
-use rustybuzz::{GlyphBuffer, UnicodeBuffer};
+use glam::{DAffine2, DVec2};
+use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder, Tag};
+use rustybuzz::{Feature, GlyphBuffer, UnicodeBuffer};
+use std::fmt::{self, Display};
 
 struct Builder {
 	current_subpath: Subpath<ManipulatorGroupId>,
@@ -68,108 +71,371 @@ fn push_str(buffer: &mut UnicodeBuffer, word: &str, trailing_space: bool) {
 	}
 }
 
-fn wrap_word(line_width: Option<f64>, glyph_buffer: &GlyphBuffer, scale: f64, x_pos: f64) -> bool {
+fn wrap_word(line_width: Option<f64>, glyph_buffer: &GlyphBuffer, scale: f64, letter_spacing: f64, x_pos: f64) -> bool {
 	if let Some(line_width) = line_width {
-		let word_length: i32 = glyph_buffer.glyph_positions().iter().map(|pos| pos.x_advance).sum();
-		let scaled_word_length = word_length as f64 * scale;
+		let word_length: f64 = glyph_buffer.glyph_positions().iter().map(|pos| pos.x_advance as f64 * scale + letter_spacing).sum();
 
-		if scaled_word_length + x_pos > line_width {
+		if word_length + x_pos > line_width {
 			return true;
 		}
 	}
 	false
 }
 
-pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> Vec<Subpath<ManipulatorGroupId>> {
-	let buzz_face = match buzz_face {
-		Some(face) => face,
-		// Show blank layer if font has not loaded
-		None => return vec![],
-	};
+/// How the lines of a text are aligned to each other, or to the line width if there is one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum TextAlignment {
+	#[default]
+	Left,
+	Center,
+	Right,
+	/// Lines are stretched to the full width by widening their spaces, except for the last line of each paragraph which is aligned left.
+	Justify,
+}
 
-	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
+impl Display for TextAlignment {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TextAlignment::Left => write!(f, "Left"),
+			TextAlignment::Center => write!(f, "Center"),
+			TextAlignment::Right => write!(f, "Right"),
+			TextAlignment::Justify => write!(f, "Justify"),
+		}
+	}
+}
 
-	let mut builder = Builder {
-		current_subpath: Subpath::new(Vec::new(), false),
-		other_subpaths: Vec::new(),
-		pos: DVec2::ZERO,
-		offset: DVec2::ZERO,
-		ascender: (buzz_face.ascender() as f64 / buzz_face.height() as f64) * font_size / scale,
-		scale,
-		id: ManipulatorGroupId::ZERO,
-	};
+/// The settings used to lay out a text, apart from its font.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct TypesettingConfig {
+	pub font_size: f64,
+	/// The distance between the baselines of consecutive lines, as a multiple of the font size.
+	pub line_height_ratio: f64,
+	/// Extra space added after every glyph, in pixels.
+	pub letter_spacing: f64,
+	/// Extra space added between paragraphs on top of the line height, in pixels.
+	pub paragraph_spacing: f64,
+	/// Lines longer than this wrap onto the next line.
+	pub max_width: Option<f64>,
+	pub alignment: TextAlignment,
+	/// Enables the `kern` OpenType feature.
+	pub kerning: bool,
+	/// Enables the `liga` and `clig` OpenType features.
+	pub ligatures: bool,
+	/// Further OpenType features in the HarfBuzz syntax, separated by commas or spaces, such as `smcp, -calt, onum`.
+	pub features: String,
+}
+
+impl Default for TypesettingConfig {
+	fn default() -> Self {
+		Self {
+			font_size: 24.,
+			line_height_ratio: 1.,
+			letter_spacing: 0.,
+			paragraph_spacing: 0.,
+			max_width: None,
+			alignment: TextAlignment::default(),
+			kerning: true,
+			ligatures: true,
+			features: String::new(),
+		}
+	}
+}
+
+impl TypesettingConfig {
+	/// The OpenType features passed to the shaper. Features which fail to parse are ignored.
+	fn shaping_features(&self) -> Vec<Feature> {
+		let toggle = |tag: &[u8; 4], enabled: bool| Feature::new(Tag::from_bytes(tag), enabled as u32, ..);
+		let mut features = vec![toggle(b"kern", self.kerning), toggle(b"liga", self.ligatures), toggle(b"clig", self.ligatures)];
+		features.extend(self.features.split(|c: char| c == ',' || c.is_whitespace()).filter_map(|feature| feature.parse::<Feature>().ok()));
+		features
+	}
+}
+
+struct PositionedGlyph {
+	glyph_id: GlyphId,
+	x: f64,
+	offset: DVec2,
+	is_space: bool,
+}
+
+#[derive(Default)]
+struct Line {
+	glyphs: Vec<PositionedGlyph>,
+	/// The width up to the end of the last glyph which isn't a space.
+	width: f64,
+	y: f64,
+	ends_paragraph: bool,
+}
+
+struct TextLayout {
+	/// The glyphs and the top left corners of their boxes.
+	glyphs: Vec<(GlyphId, DVec2)>,
+	size: DVec2,
+}
 
-	for line in str.split('\n') {
-		let length = line.split(' ').count();
-		for (index, word) in line.split(' ').enumerate() {
+fn layout_text(str: &str, buzz_face: &rustybuzz::Face, config: &TypesettingConfig) -> TextLayout {
+	let (scale, _, mut buffer) = font_properties(buzz_face, config.font_size);
+	let line_height = config.font_size * config.line_height_ratio;
+	let features = config.shaping_features();
+
+	let mut lines = Vec::new();
+	let mut line = Line::default();
+	let mut x = 0.;
+
+	for (paragraph_index, paragraph) in str.split('\n').enumerate() {
+		if paragraph_index > 0 {
+			let y = line.y + line_height + config.paragraph_spacing;
+			line.ends_paragraph = true;
+			lines.push(core::mem::replace(&mut line, Line { y, ..Default::default() }));
+			x = 0.;
+		}
+
+		let length = paragraph.split(' ').count();
+		for (index, word) in paragraph.split(' ').enumerate() {
 			push_str(&mut buffer, word, index != length - 1);
-			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+			let glyph_buffer = rustybuzz::shape(buzz_face, &features, buffer);
 
-			if wrap_word(line_width, &glyph_buffer, scale, builder.pos.x) {
-				builder.pos = DVec2::new(0., builder.pos.y + line_height);
+			if x > 0. && wrap_word(config.max_width, &glyph_buffer, scale, config.letter_spacing, x) {
+				let y = line.y + line_height;
+				lines.push(core::mem::replace(&mut line, Line { y, ..Default::default() }));
+				x = 0.;
 			}
 
 			for (glyph_position, glyph_info) in glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()) {
-				if let Some(line_width) = line_width {
-					if builder.pos.x + (glyph_position.x_advance as f64 * builder.scale) >= line_width {
-						builder.pos = DVec2::new(0., builder.pos.y + line_height);
+				let glyph_width = glyph_position.x_advance as f64 * scale;
+				if let Some(line_width) = config.max_width {
+					if x > 0. && x + glyph_width + config.letter_spacing >= line_width {
+						let y = line.y + line_height;
+						lines.push(core::mem::replace(&mut line, Line { y, ..Default::default() }));
+						x = 0.;
 					}
 				}
-				builder.offset = DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * builder.scale;
-				buzz_face.outline_glyph(GlyphId(glyph_info.glyph_id as u16), &mut builder);
-				if !builder.current_subpath.is_empty() {
-					builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
-				}
 
-				builder.pos += DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * builder.scale;
+				// The only character after the word in the buffer is its trailing space
+				let is_space = glyph_info.cluster as usize >= word.len();
+				if !is_space {
+					line.width = x + glyph_width;
+				}
+				line.glyphs.push(PositionedGlyph {
+					glyph_id: GlyphId(glyph_info.glyph_id as u16),
+					x,
+					offset: DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * scale,
+					is_space,
+				});
+
+				x += glyph_width + config.letter_spacing;
 			}
 
 			buffer = glyph_buffer.clear();
 		}
-		builder.pos = DVec2::new(0., builder.pos.y + line_height);
 	}
-	builder.other_subpaths
+	line.ends_paragraph = true;
+	lines.push(line);
+
+	let box_width = config.max_width.unwrap_or_else(|| lines.iter().map(|line| line.width).fold(0., f64::max));
+
+	let mut glyphs = Vec::new();
+	let mut size = DVec2::new(0., lines.last().map_or(0., |line| line.y) + line_height);
+	for line in &lines {
+		let free_space = box_width - line.width;
+		let start = match config.alignment {
+			TextAlignment::Left | TextAlignment::Justify => 0.,
+			TextAlignment::Center => free_space / 2.,
+			TextAlignment::Right => free_space,
+		};
+
+		// Only spaces between words are widened, not the ones at the end of the line
+		let inner_spaces = line.glyphs.iter().filter(|glyph| glyph.is_space && glyph.x < line.width).count();
+		let space_extension = if config.alignment == TextAlignment::Justify && !line.ends_paragraph && inner_spaces > 0 && free_space > 0. {
+			free_space / inner_spaces as f64
+		} else {
+			0.
+		};
+
+		let mut extension = start;
+		for glyph in &line.glyphs {
+			glyphs.push((glyph.glyph_id, DVec2::new(glyph.x + extension, line.y) + glyph.offset));
+			if glyph.is_space && glyph.x < line.width {
+				extension += space_extension;
+			}
+		}
+		size.x = size.x.max(start + line.width + space_extension * inner_spaces as f64);
+	}
+
+	TextLayout { glyphs, size }
 }
 
-pub fn bounding_box(str: &str, buzz_face: Option<rustybuzz::Face>, font_size: f64, line_width: Option<f64>) -> DVec2 {
+pub fn to_path(str: &str, buzz_face: Option<rustybuzz::Face>, config: &TypesettingConfig) -> Vec<Subpath<ManipulatorGroupId>> {
 	let buzz_face = match buzz_face {
 		Some(face) => face,
 		// Show blank layer if font has not loaded
-		None => return DVec2::ZERO,
+		None => return vec![],
+	};
+
+	let (scale, _, _) = font_properties(&buzz_face, config.font_size);
+
+	let mut builder = Builder {
+		current_subpath: Subpath::new(Vec::new(), false),
+		other_subpaths: Vec::new(),
+		pos: DVec2::ZERO,
+		offset: DVec2::ZERO,
+		ascender: (buzz_face.ascender() as f64 / buzz_face.height() as f64) * config.font_size / scale,
+		scale,
+		id: ManipulatorGroupId::ZERO,
 	};
 
-	let (scale, line_height, mut buffer) = font_properties(&buzz_face, font_size);
+	for (glyph_id, position) in layout_text(str, &buzz_face, config).glyphs {
+		builder.pos = position;
+		buzz_face.outline_glyph(glyph_id, &mut builder);
+		if !builder.current_subpath.is_empty() {
+			builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
+		}
+	}
+	builder.other_subpaths
+}
 
-	let mut pos = DVec2::ZERO;
-	let mut bounds = DVec2::ZERO;
+/// Where text is placed along a path, relative to the start offset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum TextPathAlignment {
+	/// The text starts at the start offset.
+	#[default]
+	Start,
+	/// The text is centered on the middle of the path, moved along it by the start offset.
+	Center,
+	/// The text ends at the end of the path, moved along it by the start offset.
+	End,
+}
 
-	for line in str.split('\n') {
-		let length = line.split(' ').count();
-		for (index, word) in line.split(' ').enumerate() {
-			push_str(&mut buffer, word, index != length - 1);
+impl Display for TextPathAlignment {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TextPathAlignment::Start => write!(f, "Start"),
+			TextPathAlignment::Center => write!(f, "Center"),
+			TextPathAlignment::End => write!(f, "End"),
+		}
+	}
+}
 
-			let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+/// How glyphs that run past an end of an open path are handled. On closed paths the text wraps around instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum TextPathOverflow {
+	/// Glyphs which don't fit on the path are left out.
+	#[default]
+	Hide,
+	/// Glyphs which don't fit on the path continue in a straight line in the direction of its end.
+	Extend,
+	/// The text is scaled down so it fits on the path.
+	Shrink,
+}
 
-			if wrap_word(line_width, &glyph_buffer, scale, pos.x) {
-				pos = DVec2::new(0., pos.y + line_height);
-			}
+impl Display for TextPathOverflow {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TextPathOverflow::Hide => write!(f, "Hide"),
+			TextPathOverflow::Extend => write!(f, "Extend"),
+			TextPathOverflow::Shrink => write!(f, "Shrink"),
+		}
+	}
+}
 
-			for glyph_position in glyph_buffer.glyph_positions() {
-				if let Some(line_width) = line_width {
-					if pos.x + (glyph_position.x_advance as f64 * scale) >= line_width {
-						pos = DVec2::new(0., pos.y + line_height);
-					}
-				}
-				pos += DVec2::new(glyph_position.x_advance as f64, glyph_position.y_advance as f64) * scale;
+/// Lays out the text on a single line along the guide path, with the baseline of each glyph on the path and rotated to the direction of the path at the middle of the glyph.
+/// Line breaks are treated as spaces.
+pub fn to_path_on_path(
+	str: &str,
+	buzz_face: Option<rustybuzz::Face>,
+	font_size: f64,
+	guide: &Subpath<ManipulatorGroupId>,
+	start_offset: f64,
+	alignment: TextPathAlignment,
+	overflow: TextPathOverflow,
+) -> Vec<Subpath<ManipulatorGroupId>> {
+	let Some(buzz_face) = buzz_face else { return vec![] };
+	if guide.len_segments() == 0 {
+		return vec![];
+	}
+
+	let (scale, _, mut buffer) = font_properties(&buzz_face, font_size);
+	buffer.push_str(&str.replace('\n', " "));
+	let glyph_buffer = rustybuzz::shape(&buzz_face, &[], buffer);
+
+	let arc_length_table = guide.arc_length_table(None);
+	let path_length = arc_length_table.total_length();
+	let text_length = glyph_buffer.glyph_positions().iter().map(|position| position.x_advance as f64 * scale).sum::<f64>();
+
+	let fit_scale = if overflow == TextPathOverflow::Shrink && text_length > path_length {
+		path_length / text_length
+	} else {
+		1.
+	};
+	let start = start_offset
+		+ match alignment {
+			TextPathAlignment::Start => 0.,
+			TextPathAlignment::Center => (path_length - text_length * fit_scale) / 2.,
+			TextPathAlignment::End => path_length - text_length * fit_scale,
+		};
+
+	// Returns the position and direction at a distance along the guide, or `None` if that is past an end and the glyph there is hidden
+	let placement = |distance: f64| -> Option<(DVec2, DVec2)> {
+		let direction_at = |distance: f64| {
+			let t = arc_length_table.t_value(distance);
+			(guide.evaluate(t), guide.tangent(t).try_normalize().unwrap_or(DVec2::X))
+		};
+		if guide.closed() {
+			return Some(direction_at(distance.rem_euclid(path_length)));
+		}
+		if (0.0..=path_length).contains(&distance) {
+			return Some(direction_at(distance));
+		}
+		match overflow {
+			TextPathOverflow::Extend => {
+				let end_distance = distance.clamp(0., path_length);
+				let (end, direction) = direction_at(end_distance);
+				Some((end + direction * (distance - end_distance), direction))
 			}
-			bounds = bounds.max(pos + DVec2::new(0., line_height));
+			// Shrunk text fits on the path unless it was moved off by the start offset
+			TextPathOverflow::Hide | TextPathOverflow::Shrink => None,
+		}
+	};
 
-			buffer = glyph_buffer.clear();
+	let mut builder = Builder {
+		current_subpath: Subpath::new(Vec::new(), false),
+		other_subpaths: Vec::new(),
+		pos: DVec2::ZERO,
+		offset: DVec2::ZERO,
+		ascender: 0.,
+		scale,
+		id: ManipulatorGroupId::ZERO,
+	};
+
+	let mut advance = 0.;
+	for (glyph_position, glyph_info) in glyph_buffer.glyph_positions().iter().zip(glyph_buffer.glyph_infos()) {
+		let glyph_width = glyph_position.x_advance as f64 * scale;
+		let center = start + (advance + glyph_width / 2.) * fit_scale;
+		advance += glyph_width;
+
+		let Some((position, direction)) = placement(center) else { continue };
+
+		// Outline the glyph around the middle of its baseline, then move that onto the path
+		let first_new_subpath = builder.other_subpaths.len();
+		builder.pos = DVec2::new(-glyph_width / 2., 0.);
+		builder.offset = DVec2::new(glyph_position.x_offset as f64, glyph_position.y_offset as f64) * scale;
+		buzz_face.outline_glyph(GlyphId(glyph_info.glyph_id as u16), &mut builder);
+		if !builder.current_subpath.is_empty() {
+			builder.other_subpaths.push(core::mem::replace(&mut builder.current_subpath, Subpath::new(Vec::new(), false)));
 		}
-		pos = DVec2::new(0., pos.y + line_height);
+
+		let transform = DAffine2::from_scale_angle_translation(DVec2::splat(fit_scale), direction.y.atan2(direction.x), position);
+		builder.other_subpaths[first_new_subpath..].iter_mut().for_each(|subpath| subpath.apply_transform(transform));
 	}
+	builder.other_subpaths
+}
 
-	bounds
+pub fn bounding_box(str: &str, buzz_face: Option<rustybuzz::Face>, config: &TypesettingConfig) -> DVec2 {
+	match buzz_face {
+		Some(face) => layout_text(str, &face, config).size,
+		// Show blank layer if font has not loaded
+		None => DVec2::ZERO,
+	}
 }
 
 pub fn load_face(data: &[u8]) -> rustybuzz::Face {
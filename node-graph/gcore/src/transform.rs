@@ -1,7 +1,7 @@
 use core::future::Future;
 
 use dyn_any::StaticType;
-use glam::DAffine2;
+use glam::{DAffine2, DMat2};
 
 use glam::DVec2;
 
@@ -135,6 +135,26 @@ impl TransformMut for DAffine2 {
 	}
 }
 
+/// Interpolates between two transforms by their rotation, scale, shear and translation, so a rotation doesn't shrink the shape halfway through like it would when interpolating the matrices directly.
+pub fn lerp_affine(a: DAffine2, b: DAffine2, time: f64) -> DAffine2 {
+	if a == b {
+		return a;
+	}
+
+	// Splits the linear part into a rotation followed by an upper triangular matrix holding the scale and shear
+	let decompose = |transform: DAffine2| {
+		let angle = transform.matrix2.x_axis.y.atan2(transform.matrix2.x_axis.x);
+		let triangular = DMat2::from_angle(-angle) * transform.matrix2;
+		(angle, triangular)
+	};
+	let (a_angle, a_triangular) = decompose(a);
+	let (b_angle, b_triangular) = decompose(b);
+
+	let angle_difference = (b_angle - a_angle + core::f64::consts::PI).rem_euclid(core::f64::consts::TAU) - core::f64::consts::PI;
+	let triangular = a_triangular + (b_triangular - a_triangular) * time;
+	DAffine2::from_mat2_translation(DMat2::from_angle(a_angle + angle_difference * time) * triangular, a.translation.lerp(b.translation, time))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct TransformNode<TransformTarget, Translation, Rotation, Scale, Shear, Pivot> {
 	pub(crate) transform_target: TransformTarget,
@@ -0,0 +1,307 @@
+use crate::uuid::ManipulatorGroupId;
+use crate::Color;
+
+use dyn_any::{DynAny, StaticType};
+use glam::DVec2;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::hash::{Hash, Hasher};
+
+/// Whether the values of an [AttributeChannel] belong to the anchors of a path or to the segments between them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum AttributeDomain {
+	/// One value for each manipulator group.
+	#[default]
+	Point,
+	/// One value for each segment, stored on the manipulator group the segment starts from.
+	/// The closing segment of a closed subpath belongs to its last manipulator group.
+	Segment,
+}
+
+impl Display for AttributeDomain {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			AttributeDomain::Point => write!(f, "Point"),
+			AttributeDomain::Segment => write!(f, "Segment"),
+		}
+	}
+}
+
+/// The type of the values stored in an [AttributeChannel].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum AttributeType {
+	Number,
+	Vector,
+	Color,
+}
+
+/// A single value of an [AttributeChannel].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, DynAny)]
+pub enum AttributeValue {
+	Number(f64),
+	Vector(DVec2),
+	Color(Color),
+}
+
+impl Hash for AttributeValue {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		core::mem::discriminant(self).hash(state);
+		match self {
+			AttributeValue::Number(number) => number.to_bits().hash(state),
+			AttributeValue::Vector(vector) => vector.to_array().iter().for_each(|component| component.to_bits().hash(state)),
+			AttributeValue::Color(color) => color.hash(state),
+		}
+	}
+}
+
+impl From<f64> for AttributeValue {
+	fn from(number: f64) -> Self {
+		AttributeValue::Number(number)
+	}
+}
+
+impl From<DVec2> for AttributeValue {
+	fn from(vector: DVec2) -> Self {
+		AttributeValue::Vector(vector)
+	}
+}
+
+impl From<Color> for AttributeValue {
+	fn from(color: Color) -> Self {
+		AttributeValue::Color(color)
+	}
+}
+
+impl AttributeValue {
+	pub fn attribute_type(&self) -> AttributeType {
+		match self {
+			AttributeValue::Number(_) => AttributeType::Number,
+			AttributeValue::Vector(_) => AttributeType::Vector,
+			AttributeValue::Color(_) => AttributeType::Color,
+		}
+	}
+
+	/// Converts the value to another type.
+	/// Numbers are broadcast to every component, vectors are reduced to their length and colors to their luminance.
+	pub fn convert(self, attribute_type: AttributeType) -> Self {
+		match (self, attribute_type) {
+			(AttributeValue::Number(_), AttributeType::Number) | (AttributeValue::Vector(_), AttributeType::Vector) | (AttributeValue::Color(_), AttributeType::Color) => self,
+			(AttributeValue::Number(number), AttributeType::Vector) => AttributeValue::Vector(DVec2::splat(number)),
+			(AttributeValue::Number(number), AttributeType::Color) => AttributeValue::Color(Color::from_rgbf32_unchecked(number as f32, number as f32, number as f32)),
+			(AttributeValue::Vector(vector), AttributeType::Number) => AttributeValue::Number(vector.length()),
+			(AttributeValue::Vector(vector), AttributeType::Color) => AttributeValue::Color(Color::from_rgbf32_unchecked(vector.x as f32, vector.y as f32, 0.)),
+			(AttributeValue::Color(color), AttributeType::Number) => AttributeValue::Number(color.luminance_srgb() as f64),
+			(AttributeValue::Color(color), AttributeType::Vector) => AttributeValue::Vector(DVec2::new(color.r() as f64, color.g() as f64)),
+		}
+	}
+
+	/// The value as a number, see [AttributeValue::convert].
+	pub fn as_number(self) -> f64 {
+		match self.convert(AttributeType::Number) {
+			AttributeValue::Number(number) => number,
+			_ => unreachable!(),
+		}
+	}
+
+	/// Linearly interpolates towards `other`, which is first converted to the type of `self`.
+	pub fn lerp(self, other: Self, t: f64) -> Self {
+		match (self, other.convert(self.attribute_type())) {
+			(AttributeValue::Number(a), AttributeValue::Number(b)) => AttributeValue::Number(a + (b - a) * t),
+			(AttributeValue::Vector(a), AttributeValue::Vector(b)) => AttributeValue::Vector(a.lerp(b, t)),
+			(AttributeValue::Color(a), AttributeValue::Color(b)) => AttributeValue::Color(a.lerp(b, t as f32)),
+			_ => self,
+		}
+	}
+
+	/// Applies `map` to each component of the value. The alpha of colors is left unchanged.
+	pub fn map_components(self, map: impl Fn(f64) -> f64) -> Self {
+		match self {
+			AttributeValue::Number(number) => AttributeValue::Number(map(number)),
+			AttributeValue::Vector(vector) => AttributeValue::Vector(DVec2::new(map(vector.x), map(vector.y))),
+			AttributeValue::Color(color) => AttributeValue::Color(color.map_rgb(|channel| map(channel as f64) as f32)),
+		}
+	}
+}
+
+/// Values of a single type attached to the manipulator groups of a [VectorData](super::VectorData).
+///
+/// Manipulator groups without a value of their own read the default value of the channel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, DynAny)]
+pub struct AttributeChannel {
+	domain: AttributeDomain,
+	default: AttributeValue,
+	values: BTreeMap<ManipulatorGroupId, AttributeValue>,
+}
+
+impl Hash for AttributeChannel {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.domain.hash(state);
+		self.default.hash(state);
+		self.values.hash(state);
+	}
+}
+
+impl AttributeChannel {
+	pub fn new(domain: AttributeDomain, default: AttributeValue) -> Self {
+		Self {
+			domain,
+			default,
+			values: BTreeMap::new(),
+		}
+	}
+
+	pub fn domain(&self) -> AttributeDomain {
+		self.domain
+	}
+
+	pub fn attribute_type(&self) -> AttributeType {
+		self.default.attribute_type()
+	}
+
+	pub fn default_value(&self) -> AttributeValue {
+		self.default
+	}
+
+	/// The value of a manipulator group, or the default value of the channel if it has none.
+	pub fn get(&self, id: ManipulatorGroupId) -> AttributeValue {
+		self.values.get(&id).copied().unwrap_or(self.default)
+	}
+
+	/// Sets the value of a manipulator group, converting it to the type of the channel.
+	pub fn set(&mut self, id: ManipulatorGroupId, value: impl Into<AttributeValue>) {
+		self.values.insert(id, value.into().convert(self.attribute_type()));
+	}
+
+	/// Removes the value of a manipulator group so it reads the default value again.
+	pub fn remove(&mut self, id: ManipulatorGroupId) -> Option<AttributeValue> {
+		self.values.remove(&id)
+	}
+
+	/// Only keeps the values of the manipulator groups for which `keep` returns true.
+	pub fn retain(&mut self, mut keep: impl FnMut(ManipulatorGroupId) -> bool) {
+		self.values.retain(|&id, _| keep(id));
+	}
+
+	/// Iterator over the manipulator groups which have a value of their own.
+	pub fn iter(&self) -> impl Iterator<Item = (ManipulatorGroupId, AttributeValue)> + '_ {
+		self.values.iter().map(|(&id, &value)| (id, value))
+	}
+}
+
+/// The named [AttributeChannel]s of a [VectorData](super::VectorData).
+#[derive(Debug, Clone, Default, PartialEq, Hash, Serialize, Deserialize, DynAny)]
+pub struct VectorAttributes(BTreeMap<String, AttributeChannel>);
+
+impl VectorAttributes {
+	pub const fn new() -> Self {
+		Self(BTreeMap::new())
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub fn get(&self, name: &str) -> Option<&AttributeChannel> {
+		self.0.get(name)
+	}
+
+	pub fn get_mut(&mut self, name: &str) -> Option<&mut AttributeChannel> {
+		self.0.get_mut(name)
+	}
+
+	/// Returns the channel with this name, creating it first if it doesn't exist yet.
+	/// An existing channel with a different domain or type is replaced by an empty one.
+	pub fn channel_mut(&mut self, name: &str, domain: AttributeDomain, default: AttributeValue) -> &mut AttributeChannel {
+		let channel = self.0.entry(name.to_string()).or_insert_with(|| AttributeChannel::new(domain, default));
+		if channel.domain != domain || channel.attribute_type() != default.attribute_type() {
+			*channel = AttributeChannel::new(domain, default);
+		}
+		channel
+	}
+
+	pub fn remove(&mut self, name: &str) -> Option<AttributeChannel> {
+		self.0.remove(name)
+	}
+
+	/// Iterator over the names and channels, ordered by name.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &AttributeChannel)> {
+		self.0.iter().map(|(name, channel)| (name.as_str(), channel))
+	}
+
+	/// Removes the values of a manipulator group from every channel.
+	pub fn remove_id(&mut self, id: ManipulatorGroupId) {
+		self.0.values_mut().for_each(|channel| {
+			channel.remove(id);
+		});
+	}
+
+	/// Only keeps the values of the manipulator groups for which `keep` returns true.
+	pub fn retain_ids(&mut self, mut keep: impl FnMut(ManipulatorGroupId) -> bool) {
+		self.0.values_mut().for_each(|channel| channel.retain(&mut keep));
+	}
+
+	/// Gives the values of one manipulator group to another, such as a copy of it.
+	pub fn copy_id(&mut self, from: ManipulatorGroupId, to: ManipulatorGroupId) {
+		for channel in self.0.values_mut() {
+			match channel.values.get(&from).copied() {
+				Some(value) => channel.set(to, value),
+				None => {
+					channel.remove(to);
+				}
+			}
+		}
+	}
+
+	/// Fills in the values of a manipulator group inserted at `t` along the segment from `start` to `end`.
+	/// Point values are interpolated between the two ends, and the new segment continues the value of the segment it was split from.
+	pub fn insert_between(&mut self, start: ManipulatorGroupId, end: ManipulatorGroupId, inserted: ManipulatorGroupId, t: f64) {
+		for channel in self.0.values_mut() {
+			match channel.domain {
+				AttributeDomain::Point => {
+					if channel.values.contains_key(&start) || channel.values.contains_key(&end) {
+						let value = channel.get(start).lerp(channel.get(end), t);
+						channel.set(inserted, value);
+					}
+				}
+				AttributeDomain::Segment => match channel.values.get(&start).copied() {
+					Some(value) => channel.set(inserted, value),
+					None => {
+						channel.remove(inserted);
+					}
+				},
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn convert_and_lerp() {
+		assert_eq!(AttributeValue::Number(2.).convert(AttributeType::Vector), AttributeValue::Vector(DVec2::splat(2.)));
+		assert_eq!(AttributeValue::Vector(DVec2::new(3., 4.)).as_number(), 5.);
+		assert_eq!(AttributeValue::Number(1.).lerp(AttributeValue::Vector(DVec2::new(3., 4.)), 0.5), AttributeValue::Number(3.));
+	}
+
+	#[test]
+	fn insert_between_interpolates_points_and_continues_segments() {
+		let mut ids = ManipulatorGroupId::ZERO;
+		let (start, end, inserted) = (ids.next_id(), ids.next_id(), ids.next_id());
+
+		let mut attributes = VectorAttributes::default();
+		let width = attributes.channel_mut("width", AttributeDomain::Point, AttributeValue::Number(1.));
+		width.set(start, 2.);
+		width.set(end, 4.);
+		attributes.channel_mut("weight", AttributeDomain::Segment, AttributeValue::Number(0.)).set(start, 7.);
+
+		attributes.insert_between(start, end, inserted, 0.25);
+		assert_eq!(attributes.get("width").unwrap().get(inserted), AttributeValue::Number(2.5));
+		assert_eq!(attributes.get("weight").unwrap().get(inserted), AttributeValue::Number(7.));
+
+		attributes.remove_id(inserted);
+		assert_eq!(attributes.get("width").unwrap().get(inserted), AttributeValue::Number(1.));
+	}
+}
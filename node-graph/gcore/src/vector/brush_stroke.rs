@@ -1,7 +1,9 @@
 use crate::raster::bbox::AxisAlignedBbox;
 use crate::raster::BlendMode;
+use crate::uuid::ManipulatorGroupId;
 use crate::Color;
 
+use bezier_rs::{ManipulatorGroup, Subpath};
 use dyn_any::{DynAny, StaticType};
 use glam::DVec2;
 use std::hash::{Hash, Hasher};
@@ -97,34 +99,12 @@ impl BrushStroke {
 		// placing a blit point every time we travelled our spacing distance.
 		let spacing_dist = self.style.spacing / 100. * self.style.diameter;
 
-		let Some(first_sample) = self.trace.first() else {
-			return Vec::new();
-		};
-
-		let mut cur_pos = first_sample.position;
-		let mut result = vec![cur_pos];
-		let mut dist_until_next_blit = spacing_dist;
-		for sample in &self.trace[1..] {
-			// Travel to the next sample.
-			let delta = sample.position - cur_pos;
-			let mut dist_left = delta.length();
-			let unit_step = delta / dist_left;
-
-			while dist_left >= dist_until_next_blit {
-				// Take a step to the next blit point.
-				cur_pos += dist_until_next_blit * unit_step;
-				dist_left -= dist_until_next_blit;
-
-				// Blit.
-				result.push(cur_pos);
-				dist_until_next_blit = spacing_dist;
-			}
-
-			// Take the partial step to land at the sample.
-			dist_until_next_blit -= dist_left;
-			cur_pos = sample.position;
-		}
-
-		result
+		let manipulator_groups = self
+			.trace
+			.iter()
+			.map(|sample| ManipulatorGroup::new_with_id(sample.position, None, None, ManipulatorGroupId::ZERO))
+			.collect();
+		let trace_path = Subpath::new(manipulator_groups, false);
+		trace_path.arc_length_samples(spacing_dist).map(|sample| sample.point).collect()
 	}
 }
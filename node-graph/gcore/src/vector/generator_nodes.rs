@@ -2,7 +2,7 @@ use crate::uuid::ManipulatorGroupId;
 use crate::vector::VectorData;
 use crate::Node;
 
-use bezier_rs::Subpath;
+use bezier_rs::{FilletType, Subpath};
 
 use glam::DVec2;
 
@@ -32,47 +32,59 @@ fn ellipse_generator(_input: (), radius_x: f32, radius_y: f32) -> VectorData {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct RectangleGenerator<SizeX, SizeY> {
+pub struct RectangleGenerator<SizeX, SizeY, CornerRadius> {
 	size_x: SizeX,
 	size_y: SizeY,
+	corner_radius: CornerRadius,
 }
 
 #[node_macro::node_fn(RectangleGenerator)]
-fn square_generator(_input: (), size_x: f32, size_y: f32) -> VectorData {
+fn square_generator(_input: (), size_x: f32, size_y: f32, corner_radius: f32) -> VectorData {
 	let size = DVec2::new(size_x as f64, size_y as f64);
 	let corner1 = -size / 2.;
 	let corner2 = size / 2.;
 
-	super::VectorData::from_subpaths(vec![Subpath::new_rect(corner1, corner2)])
+	super::VectorData::from_subpaths(vec![with_corner_radius(Subpath::new_rect(corner1, corner2), corner_radius)])
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct RegularPolygonGenerator<Points, Radius> {
+pub struct RegularPolygonGenerator<Points, Radius, CornerRadius> {
 	points: Points,
 	radius: Radius,
+	corner_radius: CornerRadius,
 }
 
 #[node_macro::node_fn(RegularPolygonGenerator)]
-fn regular_polygon_generator(_input: (), points: u32, radius: f32) -> VectorData {
+fn regular_polygon_generator(_input: (), points: u32, radius: f32, corner_radius: f32) -> VectorData {
 	let points = points.into();
 	let radius: f64 = (radius * 2.).into();
-	super::VectorData::from_subpath(Subpath::new_regular_polygon(DVec2::splat(-radius), points, radius))
+	super::VectorData::from_subpath(with_corner_radius(Subpath::new_regular_polygon(DVec2::splat(-radius), points, radius), corner_radius))
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct StarGenerator<Points, Radius, InnerRadius> {
+pub struct StarGenerator<Points, Radius, InnerRadius, CornerRadius> {
 	points: Points,
 	radius: Radius,
 	inner_radius: InnerRadius,
+	corner_radius: CornerRadius,
 }
 
 #[node_macro::node_fn(StarGenerator)]
-fn star_generator(_input: (), points: u32, radius: f32, inner_radius: f32) -> VectorData {
+fn star_generator(_input: (), points: u32, radius: f32, inner_radius: f32, corner_radius: f32) -> VectorData {
 	let points = points.into();
 	let diameter: f64 = (radius * 2.).into();
 	let inner_diameter = (inner_radius * 2.).into();
 
-	super::VectorData::from_subpath(Subpath::new_star_polygon(DVec2::splat(-diameter), points, diameter, inner_diameter))
+	let star = Subpath::new_star_polygon(DVec2::splat(-diameter), points, diameter, inner_diameter);
+	super::VectorData::from_subpath(with_corner_radius(star, corner_radius))
+}
+
+/// Rounds every corner of a generated shape, leaving it unchanged if the radius is zero.
+fn with_corner_radius(subpath: Subpath<ManipulatorGroupId>, corner_radius: f32) -> Subpath<ManipulatorGroupId> {
+	if corner_radius <= 0. {
+		return subpath;
+	}
+	subpath.round_corners(corner_radius.into(), FilletType::Circular, 0., |_| true)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -98,14 +110,16 @@ fn spline_generator(_input: (), positions: Vec<DVec2>) -> VectorData {
 
 // TODO(TrueDoctor): I removed the Arc requirement we should think about when it makes sense to use it vs making a generic value node
 #[derive(Debug, Clone)]
-pub struct PathGenerator<Mirror> {
+pub struct PathGenerator<Mirror, Attributes> {
 	mirror: Mirror,
+	attributes: Attributes,
 }
 
 #[node_macro::node_fn(PathGenerator)]
-fn generate_path(path_data: Vec<Subpath<ManipulatorGroupId>>, mirror: Vec<ManipulatorGroupId>) -> super::VectorData {
+fn generate_path(path_data: Vec<Subpath<ManipulatorGroupId>>, mirror: Vec<ManipulatorGroupId>, attributes: super::VectorAttributes) -> super::VectorData {
 	let mut vector_data = super::VectorData::from_subpaths(path_data);
 	vector_data.mirror_angle = mirror;
+	vector_data.attributes = attributes;
 	vector_data
 }
 
@@ -1,3 +1,6 @@
+mod attributes;
+pub use attributes::*;
+
 pub mod brush_stroke;
 pub mod consts;
 pub mod generator_nodes;
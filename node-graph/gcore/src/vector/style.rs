@@ -1,6 +1,7 @@
 //! Contains stylistic options for SVG elements.
 
 use crate::consts::{LAYER_OUTLINE_STROKE_COLOR, LAYER_OUTLINE_STROKE_WEIGHT};
+use crate::transform::lerp_affine;
 use crate::Color;
 
 use dyn_any::{DynAny, StaticType};
@@ -148,6 +149,34 @@ impl Gradient {
 
 		Some(index)
 	}
+
+	/// Linearly interpolates the stops and the placement of the gradient towards another with the same number of stops.
+	/// Gradients with a different number of stops or a different type switch over halfway instead.
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		if self.positions.len() != other.positions.len() || self.gradient_type != other.gradient_type {
+			return if time < 0.5 { self.clone() } else { other.clone() };
+		}
+
+		let positions = self
+			.positions
+			.iter()
+			.zip(&other.positions)
+			.map(|(&(a_position, a_color), &(b_position, b_color))| {
+				let color = match (a_color, b_color) {
+					(Some(a), Some(b)) => Some(a.lerp(b, time as f32)),
+					(color, None) | (None, color) => color,
+				};
+				(a_position + (b_position - a_position) * time, color)
+			})
+			.collect();
+		Self {
+			start: self.start.lerp(other.start, time),
+			end: self.end.lerp(other.end, time),
+			transform: lerp_affine(self.transform, other.transform, time),
+			positions,
+			gradient_type: self.gradient_type,
+		}
+	}
 }
 
 /// Describes the fill of a layer.
@@ -203,6 +232,26 @@ impl Fill {
 			None
 		}
 	}
+
+	/// Linearly interpolates towards another fill, where a time of 0 gives this fill and 1 gives the other.
+	/// A missing fill fades in or out as a transparent version of the other one, and a solid color blends with a gradient by becoming a gradient of that color.
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let solid_gradient = |gradient: &Gradient, color: Color| Gradient {
+			positions: gradient.positions.iter().map(|&(position, _)| (position, Some(color))).collect(),
+			..gradient.clone()
+		};
+		match (self, other) {
+			(Self::None, Self::None) => Self::None,
+			(Self::Solid(a), Self::Solid(b)) => Self::Solid(a.lerp(*b, time as f32)),
+			(Self::Solid(a), Self::None) => Self::Solid(a.lerp(a.with_alpha(0.), time as f32)),
+			(Self::None, Self::Solid(b)) => Self::Solid(b.with_alpha(0.).lerp(*b, time as f32)),
+			(Self::Gradient(a), Self::Gradient(b)) => Self::Gradient(a.lerp(b, time)),
+			(Self::Gradient(a), Self::Solid(b)) => Self::Gradient(a.lerp(&solid_gradient(a, *b), time)),
+			(Self::Solid(a), Self::Gradient(b)) => Self::Gradient(solid_gradient(b, *a).lerp(b, time)),
+			(Self::Gradient(a), Self::None) => Self::Gradient(a.lerp(&solid_gradient(a, Color::TRANSPARENT), time)),
+			(Self::None, Self::Gradient(b)) => Self::Gradient(solid_gradient(b, Color::TRANSPARENT).lerp(b, time)),
+		}
+	}
 }
 
 /// Enum describing the type of [Fill]
@@ -214,6 +263,36 @@ pub enum FillType {
 	Gradient,
 }
 
+/// The rule used to determine which regions enclosed by the subpaths of a shape are inside of it and get filled.
+/// As defined in SVG: <https://www.w3.org/TR/SVG2/painting.html#FillRuleProperty>.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum FillRule {
+	/// Regions which the subpaths wind around a non-zero number of times in total are filled, so holes must wind in the opposite direction.
+	#[default]
+	NonZero,
+	/// Regions enclosed by an odd number of subpaths are filled, regardless of their direction.
+	EvenOdd,
+}
+
+impl Display for FillRule {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FillRule::NonZero => write!(f, "nonzero"),
+			FillRule::EvenOdd => write!(f, "evenodd"),
+		}
+	}
+}
+
+impl From<FillRule> for bezier_rs::FillRule {
+	fn from(fill_rule: FillRule) -> Self {
+		match fill_rule {
+			FillRule::NonZero => bezier_rs::FillRule::NonZero,
+			FillRule::EvenOdd => bezier_rs::FillRule::EvenOdd,
+		}
+	}
+}
+
 /// The stroke (outline) style of an SVG element.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
@@ -387,6 +466,33 @@ impl Stroke {
 		self.line_join_miter_limit = limit;
 		self
 	}
+
+	/// Linearly interpolates the color, weight and dashes towards another stroke. The line cap and join switch over halfway.
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let color = match (self.color, other.color) {
+			(Some(a), Some(b)) => Some(a.lerp(b, time as f32)),
+			(Some(a), None) => Some(a.lerp(a.with_alpha(0.), time as f32)),
+			(None, Some(b)) => Some(b.with_alpha(0.).lerp(b, time as f32)),
+			(None, None) => None,
+		};
+		let dash_lengths = if self.dash_lengths.len() == other.dash_lengths.len() {
+			self.dash_lengths.iter().zip(&other.dash_lengths).map(|(a, b)| a + (b - a) * time as f32).collect()
+		} else if time < 0.5 {
+			self.dash_lengths.clone()
+		} else {
+			other.dash_lengths.clone()
+		};
+		let nearest = if time < 0.5 { self } else { other };
+		Self {
+			color,
+			weight: self.weight + (other.weight - self.weight) * time,
+			dash_lengths,
+			dash_offset: self.dash_offset + (other.dash_offset - self.dash_offset) * time,
+			line_cap: nearest.line_cap,
+			line_join: nearest.line_join,
+			line_join_miter_limit: self.line_join_miter_limit + (other.line_join_miter_limit - self.line_join_miter_limit) * time,
+		}
+	}
 }
 
 // Having an alpha of 1 to start with leads to a better experience with the properties panel
@@ -409,6 +515,8 @@ impl Default for Stroke {
 pub struct PathStyle {
 	stroke: Option<Stroke>,
 	fill: Fill,
+	#[serde(default)]
+	fill_rule: FillRule,
 	pub opacity: f32,
 }
 
@@ -416,13 +524,19 @@ impl core::hash::Hash for PathStyle {
 	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
 		self.stroke.hash(state);
 		self.fill.hash(state);
+		self.fill_rule.hash(state);
 		self.opacity.to_bits().hash(state);
 	}
 }
 
 impl PathStyle {
 	pub const fn new(stroke: Option<Stroke>, fill: Fill) -> Self {
-		Self { stroke, fill, opacity: 1. }
+		Self {
+			stroke,
+			fill,
+			fill_rule: FillRule::NonZero,
+			opacity: 1.,
+		}
 	}
 
 	/// Get the current path's [Fill].
@@ -455,6 +569,34 @@ impl PathStyle {
 		self.stroke.clone()
 	}
 
+	/// Get the [FillRule] which determines the regions of the path that are filled.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{Fill, FillRule, PathStyle};
+	/// let style = PathStyle::new(None, Fill::None);
+	///
+	/// assert_eq!(style.fill_rule(), FillRule::NonZero);
+	/// ```
+	pub fn fill_rule(&self) -> FillRule {
+		self.fill_rule
+	}
+
+	/// Replace the path's [FillRule] with a provided one.
+	///
+	/// # Example
+	/// ```
+	/// # use graphene_core::vector::style::{FillRule, PathStyle};
+	/// let mut style = PathStyle::default();
+	///
+	/// style.set_fill_rule(FillRule::EvenOdd);
+	///
+	/// assert_eq!(style.fill_rule(), FillRule::EvenOdd);
+	/// ```
+	pub fn set_fill_rule(&mut self, fill_rule: FillRule) {
+		self.fill_rule = fill_rule;
+	}
+
 	/// Replace the path's [Fill] with a provided one.
 	///
 	/// # Example
@@ -529,18 +671,40 @@ impl PathStyle {
 		self.stroke = None;
 	}
 
+	/// Linearly interpolates the stroke, fill and opacity towards another style, where a time of 0 gives this style and 1 gives the other.
+	/// A missing stroke grows from or shrinks to a weight of zero.
+	pub fn lerp(&self, other: &Self, time: f64) -> Self {
+		let stroke = match (&self.stroke, &other.stroke) {
+			(Some(a), Some(b)) => Some(a.lerp(b, time)),
+			(Some(a), None) => Some(a.lerp(&a.clone().with_weight(0.), time)),
+			(None, Some(b)) => Some(b.clone().with_weight(0.).lerp(b, time)),
+			(None, None) => None,
+		};
+		Self {
+			stroke,
+			fill: self.fill.lerp(&other.fill, time),
+			fill_rule: if time < 0.5 { self.fill_rule } else { other.fill_rule },
+			opacity: self.opacity + (other.opacity - self.opacity) * time as f32,
+		}
+	}
+
 	pub fn render(&self, view_mode: ViewMode, svg_defs: &mut String, multiplied_transform: DAffine2, bounds: [DVec2; 2], transformed_bounds: [DVec2; 2]) -> String {
 		let fill_attribute = match (view_mode, &self.fill) {
 			(ViewMode::Outline, _) => Fill::None.render(svg_defs, multiplied_transform, bounds, transformed_bounds, self.opacity),
 			(_, fill) => fill.render(svg_defs, multiplied_transform, bounds, transformed_bounds, self.opacity),
 		};
+		// The non-zero fill rule is the default in SVG, so it doesn't need to be written
+		let fill_rule_attribute = match (view_mode, self.fill_rule) {
+			(ViewMode::Outline, _) | (_, FillRule::NonZero) => String::new(),
+			(_, fill_rule) => format!(r#" fill-rule="{fill_rule}""#),
+		};
 		let stroke_attribute = match (view_mode, &self.stroke) {
 			(ViewMode::Outline, _) => Stroke::new(Some(LAYER_OUTLINE_STROKE_COLOR), LAYER_OUTLINE_STROKE_WEIGHT).render(self.opacity),
 			(_, Some(stroke)) => stroke.render(self.opacity),
 			(_, None) => String::new(),
 		};
 
-		format!("{fill_attribute}{stroke_attribute}")
+		format!("{fill_attribute}{fill_rule_attribute}{stroke_attribute}")
 	}
 }
 
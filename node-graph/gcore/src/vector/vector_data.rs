@@ -1,14 +1,16 @@
+use super::attributes::{AttributeDomain, VectorAttributes};
 use super::style::{PathStyle, Stroke};
 use crate::uuid::ManipulatorGroupId;
 use crate::Color;
 
-use bezier_rs::ManipulatorGroup;
+use bezier_rs::{ManipulatorGroup, SubpathTValue};
 use dyn_any::{DynAny, StaticType};
 
 use glam::{DAffine2, DVec2};
+use std::collections::HashSet;
 
 /// [VectorData] is passed between nodes.
-/// It contains a list of subpaths (that may be open or closed), a transform and some style information.
+/// It contains a list of subpaths (that may be open or closed), a transform, some style information and the attributes of its points and segments.
 #[derive(Clone, Debug, PartialEq, DynAny)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VectorData {
@@ -16,6 +18,8 @@ pub struct VectorData {
 	pub transform: DAffine2,
 	pub style: PathStyle,
 	pub mirror_angle: Vec<ManipulatorGroupId>,
+	#[cfg_attr(feature = "serde", serde(default))]
+	pub attributes: VectorAttributes,
 }
 
 impl core::hash::Hash for VectorData {
@@ -24,6 +28,7 @@ impl core::hash::Hash for VectorData {
 		self.transform.to_cols_array().iter().for_each(|x| x.to_bits().hash(state));
 		self.style.hash(state);
 		self.mirror_angle.hash(state);
+		self.attributes.hash(state);
 	}
 }
 
@@ -35,6 +40,7 @@ impl VectorData {
 			transform: DAffine2::IDENTITY,
 			style: PathStyle::new(Some(Stroke::new(Some(Color::BLACK), 0.)), super::style::Fill::None),
 			mirror_angle: Vec::new(),
+			attributes: VectorAttributes::new(),
 		}
 	}
 
@@ -47,6 +53,66 @@ impl VectorData {
 		self.subpaths.iter().find_map(|subpath| subpath.manipulator_from_id(id))
 	}
 
+	/// Inserts a manipulator group into a subpath, giving it the attribute values at the parametric `t` between its neighbors.
+	/// At either end of an open subpath, the values are copied from the only neighbor.
+	pub fn insert_manipulator_group(&mut self, subpath_index: usize, index: usize, manipulator_group: ManipulatorGroup<ManipulatorGroupId>, t: f64) {
+		let subpath = &mut self.subpaths[subpath_index];
+		let groups = subpath.manipulator_groups();
+		let previous = match index {
+			0 if subpath.closed() => groups.last(),
+			0 => None,
+			_ => groups.get(index - 1),
+		};
+		let next = groups.get(index).or_else(|| subpath.closed().then(|| groups.first()).flatten());
+		if let Some((start, end)) = previous.or(next).zip(next.or(previous)) {
+			self.attributes.insert_between(start.id, end.id, manipulator_group.id, t);
+		}
+		subpath.insert_manipulator_group(index, manipulator_group);
+	}
+
+	/// Splits a segment of a subpath at the parametric `t` by inserting a manipulator group, whose attribute values are interpolated from the ends of the segment.
+	/// Returns the id of the new manipulator group, or `None` if `t` falls on an existing anchor.
+	pub fn split_segment(&mut self, subpath_index: usize, segment_index: usize, t: f64) -> Option<ManipulatorGroupId> {
+		let subpath = &mut self.subpaths[subpath_index];
+		let previous_len = subpath.len();
+		subpath.insert(SubpathTValue::Parametric { segment_index, t });
+		if subpath.len() == previous_len {
+			return None;
+		}
+
+		let groups = subpath.manipulator_groups();
+		let [start, inserted, end] = [segment_index, segment_index + 1, (segment_index + 2) % groups.len()].map(|index| groups[index].id);
+		self.attributes.insert_between(start, end, inserted, t);
+		Some(inserted)
+	}
+
+	/// Removes a manipulator group along with its attribute values and handle mirroring.
+	pub fn remove_manipulator_group(&mut self, id: ManipulatorGroupId) -> Option<ManipulatorGroup<ManipulatorGroupId>> {
+		let (subpath, index) = self.subpaths.iter_mut().find_map(|subpath| subpath.manipulator_index_from_id(id).map(|index| (subpath, index)))?;
+		self.attributes.remove_id(id);
+		self.mirror_angle.retain(|&mirrored_id| mirrored_id != id);
+		Some(subpath.remove_manipulator_group(index))
+	}
+
+	/// Drops the attribute values and handle mirroring of manipulator groups that are no longer part of any subpath,
+	/// such as after the subpaths were replaced by new geometry.
+	pub fn retain_existing_ids(&mut self) {
+		let ids: HashSet<_> = self.manipulator_groups().map(|group| group.id).collect();
+		self.mirror_angle.retain(|id| ids.contains(id));
+		self.attributes.retain_ids(|id| ids.contains(&id));
+	}
+
+	/// Iterator over the ids of the manipulator groups which hold the values of an attribute domain, in path order.
+	pub fn attribute_ids(&self, domain: AttributeDomain) -> impl Iterator<Item = ManipulatorGroupId> + '_ {
+		self.subpaths.iter().flat_map(move |subpath| {
+			let count = match domain {
+				AttributeDomain::Point => subpath.len(),
+				AttributeDomain::Segment => subpath.len_segments(),
+			};
+			subpath.manipulator_groups()[..count].iter().map(|group| group.id)
+		})
+	}
+
 	/// Construct some new vector data from a single subpath with an identy transform and black fill.
 	pub fn from_subpath(subpath: bezier_rs::Subpath<ManipulatorGroupId>) -> Self {
 		Self::from_subpaths(vec![subpath])
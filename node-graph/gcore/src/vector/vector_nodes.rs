@@ -1,13 +1,21 @@
-use super::style::{Fill, FillType, Gradient, GradientType, Stroke};
-use super::VectorData;
-use crate::{Color, Node};
+#![allow(clippy::too_many_arguments)]
 
-use bezier_rs::{Subpath, SubpathTValue};
+use super::style::{Fill, FillRule, FillType, Gradient, GradientType, LineCap, LineJoin, Stroke};
+use super::{AttributeDomain, AttributeValue, VectorData};
+use crate::consts::{FILL_SAMPLE_RELATIVE_DISTANCE, MAX_RESAMPLED_POINTS};
+use crate::renderer::GraphicElementRendered;
+use crate::uuid::ManipulatorGroupId;
+use crate::{Color, GraphicElement, GraphicElementData, GraphicGroup, Node};
+
+use bezier_rs::{ArcLengthSample, Bezier, Cap, Identifier, Join, ManipulatorGroup, Subpath, TValue};
+use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2};
 use num_traits::Zero;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
 
 #[derive(Debug, Clone, Copy)]
-pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions> {
+pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform, Positions, FillRule> {
 	fill_type: FillType,
 	solid_color: SolidColor,
 	gradient_type: GradientType,
@@ -15,6 +23,7 @@ pub struct SetFillNode<FillType, SolidColor, GradientType, Start, End, Transform
 	end: End,
 	transform: Transform,
 	positions: Positions,
+	fill_rule: FillRule,
 }
 
 #[node_macro::node_fn(SetFillNode)]
@@ -27,7 +36,9 @@ fn set_vector_data_fill(
 	end: DVec2,
 	transform: DAffine2,
 	positions: Vec<(f64, Option<Color>)>,
+	fill_rule: FillRule,
 ) -> VectorData {
+	vector_data.style.set_fill_rule(fill_rule);
 	vector_data.style.set_fill(match fill_type {
 		FillType::None | FillType::Solid => solid_color.map_or(Fill::None, Fill::Solid),
 		FillType::Gradient => Fill::Gradient(Gradient {
@@ -136,6 +147,159 @@ fn circular_repeat_vector_data(mut vector_data: VectorData, angle_offset: f32, r
 	vector_data
 }
 
+/// Data which can be placed by the [CopyToPointsNode].
+pub trait CopyToPointsInstance {
+	/// Returns the bounding box in the space that contains the instance.
+	fn instance_bounds(&self) -> Option<[DVec2; 2]>;
+	/// Returns the combination of copies of the instance, each of which is transformed (within the space that contains the instance) by one of the transforms.
+	fn copies(&self, transforms: impl Iterator<Item = DAffine2>) -> Self;
+}
+
+impl CopyToPointsInstance for VectorData {
+	fn instance_bounds(&self) -> Option<[DVec2; 2]> {
+		self.bounding_box_with_transform(self.transform)
+	}
+	fn copies(&self, transforms: impl Iterator<Item = DAffine2>) -> Self {
+		let mut copies = VectorData {
+			subpaths: Vec::new(),
+			transform: DAffine2::IDENTITY,
+			style: self.style.clone(),
+			mirror_angle: Vec::new(),
+			attributes: self.attributes.clone(),
+		};
+
+		for transform in transforms {
+			let transform = transform * self.transform;
+			for subpath in &self.subpaths {
+				// Each copy gets its own manipulator group ids, which start out with the attribute values and mirroring of the originals
+				let groups = subpath
+					.manipulator_groups()
+					.iter()
+					.map(|group| {
+						let id = ManipulatorGroupId::new();
+						copies.attributes.copy_id(group.id, id);
+						if self.mirror_angle.contains(&group.id) {
+							copies.mirror_angle.push(id);
+						}
+						let mut group = group.clone();
+						group.apply_transform(transform);
+						group.id = id;
+						group
+					})
+					.collect();
+				copies.subpaths.push(Subpath::new(groups, subpath.closed()));
+			}
+		}
+
+		copies.retain_existing_ids();
+		copies
+	}
+}
+
+impl CopyToPointsInstance for GraphicGroup {
+	fn instance_bounds(&self) -> Option<[DVec2; 2]> {
+		GraphicElementRendered::bounding_box(self, DAffine2::IDENTITY)
+	}
+	fn copies(&self, transforms: impl Iterator<Item = DAffine2>) -> Self {
+		let mut group = GraphicGroup::EMPTY;
+		group.extend(transforms.map(|transform| {
+			let mut copy = self.clone();
+			copy.transform = transform * self.transform;
+			GraphicElement {
+				graphic_element_data: GraphicElementData::GraphicGroup(copy),
+				..Default::default()
+			}
+		}));
+		group
+	}
+}
+
+/// Returns a pseudorandom number in `0..1` which is the same every time for the given seed, index and stream.
+fn seeded_random(seed: u32, index: usize, stream: u64) -> f64 {
+	// SplitMix64 finalizer, which scrambles all bits of the combined input
+	let mut x = (((seed as u64) << 32) ^ index as u64).wrapping_add(stream.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+	x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+	x ^= x >> 31;
+	(x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Returns the direction of a subpath at one of its anchors, which bisects the directions of the segments before and after it.
+fn anchor_tangent(subpath: &Subpath<ManipulatorGroupId>, index: usize) -> DVec2 {
+	let direction = |bezier: Bezier, t: f64| {
+		let tangent = bezier.tangent(TValue::Parametric(t));
+		let tangent = if tangent == DVec2::ZERO { bezier.end() - bezier.start() } else { tangent };
+		tangent.normalize_or_zero()
+	};
+
+	let incoming = match index {
+		0 if subpath.closed() => subpath.get_segment(subpath.len_segments().wrapping_sub(1)),
+		0 => None,
+		_ => subpath.get_segment(index - 1),
+	};
+	let outgoing = subpath.get_segment(index);
+	let tangent = incoming.map_or(DVec2::ZERO, |bezier| direction(bezier, 1.)) + outgoing.map_or(DVec2::ZERO, |bezier| direction(bezier, 0.));
+	tangent.try_normalize().unwrap_or(DVec2::X)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CopyToPointsNode<Instance, AlignToTangent, ScaleAttribute, RotationAttribute, RandomScaleMin, RandomScaleMax, RandomRotation, RandomSeed> {
+	instance: Instance,
+	align_to_tangent: AlignToTangent,
+	scale_attribute: ScaleAttribute,
+	rotation_attribute: RotationAttribute,
+	random_scale_min: RandomScaleMin,
+	random_scale_max: RandomScaleMax,
+	random_rotation: RandomRotation,
+	random_seed: RandomSeed,
+}
+
+/// Places a copy of the instance, centered on its bounding box, at every anchor of the points.
+/// Each copy is scaled by the number in the scale attribute (if there is one) times a random factor between the minimum and maximum random scale,
+/// and rotated by the path direction (if aligned to the tangent) plus the degrees in the rotation attribute plus a random angle of up to the random rotation either way.
+#[node_macro::node_fn(CopyToPointsNode)]
+fn copy_to_points<Instance: CopyToPointsInstance>(
+	points: VectorData,
+	instance: Instance,
+	align_to_tangent: bool,
+	scale_attribute: String,
+	rotation_attribute: String,
+	random_scale_min: f64,
+	random_scale_max: f64,
+	random_rotation: f64,
+	random_seed: u32,
+) -> Instance {
+	let Some([bounds_min, bounds_max]) = instance.instance_bounds() else {
+		return instance.copies(core::iter::empty());
+	};
+	let center = (bounds_min + bounds_max) / 2.;
+
+	let scale_channel = points.attributes.get(&scale_attribute);
+	let rotation_channel = points.attributes.get(&rotation_attribute);
+	let transforms = points
+		.subpaths
+		.iter()
+		.flat_map(|subpath| subpath.manipulator_groups().iter().enumerate().map(move |(index, group)| (subpath, index, group)))
+		.enumerate()
+		.map(|(point_index, (subpath, index, group))| {
+			let position = points.transform.transform_point2(group.anchor);
+
+			let mut angle = rotation_channel.map_or(0., |channel| channel.get(group.id).as_number()).to_radians();
+			angle += (seeded_random(random_seed, point_index, 0) * 2. - 1.) * random_rotation.to_radians();
+			if align_to_tangent {
+				let tangent = points.transform.transform_vector2(anchor_tangent(subpath, index));
+				angle += tangent.y.atan2(tangent.x);
+			}
+
+			let mut scale = scale_channel.map_or(1., |channel| channel.get(group.id).as_number());
+			scale *= random_scale_min + (random_scale_max - random_scale_min) * seeded_random(random_seed, point_index, 1);
+
+			DAffine2::from_scale_angle_translation(DVec2::splat(scale), angle, position) * DAffine2::from_translation(-center)
+		});
+
+	instance.copies(transforms)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BoundingBoxNode;
 
@@ -161,16 +325,227 @@ fn resample_points(mut vector_data: VectorData, spacing: f64) -> VectorData {
 		}
 
 		subpath.apply_transform(vector_data.transform);
-		let length = subpath.length(None);
+		let table = subpath.arc_length_table(None);
+		let length = table.total_length();
 		let rounded_count = (length / spacing).round();
 
 		if rounded_count >= 1. {
-			let new_anchors = (0..=rounded_count as usize).map(|c| subpath.evaluate(SubpathTValue::GlobalEuclidean(c as f64 / rounded_count)));
+			// Adjust the spacing so the points are spread evenly over the whole length, including both of its ends
+			let new_anchors: Vec<_> = subpath.arc_length_samples_from_table(table, length / rounded_count).map(|sample| sample.point).collect();
 			*subpath = Subpath::from_anchors(new_anchors, subpath.closed() && rounded_count as usize > 1);
 		}
 
 		subpath.apply_transform(vector_data.transform.inverse());
 	}
+	vector_data.retain_existing_ids();
+	vector_data
+}
+
+/// Samples the points which divide the subpath into pieces of equal length, along with the direction of the path at each of them.
+/// The number of pieces is given by `count` for the length of the subpath, rounded so the points are spread evenly over the whole length, including both of its ends.
+/// The count is limited to [MAX_RESAMPLED_POINTS] pieces. Returns `None` if the subpath is empty or would be divided into less than one piece.
+fn evenly_spaced_samples(subpath: &Subpath<ManipulatorGroupId>, count: impl Fn(f64) -> f64) -> Option<Vec<ArcLengthSample>> {
+	if subpath.is_empty() {
+		return None;
+	}
+
+	let table = subpath.arc_length_table(None);
+	let length = table.total_length();
+	let rounded_count = count(length).round();
+	if rounded_count < 1. || rounded_count.is_nan() {
+		return None;
+	}
+	let rounded_count = rounded_count.min(MAX_RESAMPLED_POINTS as f64);
+	Some(subpath.arc_length_samples_from_table(table, length / rounded_count).collect())
+}
+
+/// Resamples each subpath in the space containing the vector data like the [ResamplePoints] node, with `points_per_cycle` points for every cycle of the `frequency`,
+/// which counts the cycles along every 100 units of length. The subpath is then replaced by straight segments between the positions returned by `displace`,
+/// which is given the index of the subpath, the index of the point within it, and the sampled point with the direction of the path there.
+fn displace_resampled_points(vector_data: &mut VectorData, frequency: f64, points_per_cycle: usize, mut displace: impl FnMut(usize, usize, ArcLengthSample) -> DVec2) {
+	if frequency <= 0. || !frequency.is_finite() {
+		return;
+	}
+
+	let transform = vector_data.transform;
+	for (subpath_index, subpath) in vector_data.subpaths.iter_mut().enumerate() {
+		subpath.apply_transform(transform);
+		if let Some(mut samples) = evenly_spaced_samples(subpath, |length| (length * frequency / 100.).round() * points_per_cycle as f64) {
+			// The last sample of a closed subpath coincides with its start, so it is dropped to keep the point from being displaced twice
+			let closed = subpath.closed() && samples.len() > 2;
+			if closed {
+				samples.pop();
+			}
+			let anchors = samples.into_iter().enumerate().map(|(index, sample)| displace(subpath_index, index, sample));
+			*subpath = Subpath::from_anchors(anchors, closed);
+		}
+		subpath.apply_transform(transform.inverse());
+	}
+	vector_data.retain_existing_ids();
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RoughenNode<Amplitude, Frequency, Seed> {
+	amplitude: Amplitude,
+	frequency: Frequency,
+	seed: Seed,
+}
+
+/// Resamples the paths with the given frequency of points along every 100 units of length, then moves each point randomly by up to the amplitude on both axes.
+#[node_macro::node_fn(RoughenNode)]
+fn roughen(mut vector_data: VectorData, amplitude: f64, frequency: f64, seed: u32) -> VectorData {
+	displace_resampled_points(&mut vector_data, frequency, 1, |subpath_index, index, sample| {
+		// Each subpath draws from its own pair of random streams, so they aren't roughened identically
+		let stream = subpath_index as u64 * 2;
+		let offset = DVec2::new(seeded_random(seed, index, stream), seeded_random(seed, index, stream + 1)) * 2. - 1.;
+		sample.point + offset * amplitude
+	});
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ZigZagNode<Amplitude, Frequency> {
+	amplitude: Amplitude,
+	frequency: Frequency,
+}
+
+/// Resamples the paths with the given frequency of ridges along every 100 units of length, then moves the points alternately to either side of the path by the amplitude.
+#[node_macro::node_fn(ZigZagNode)]
+fn zig_zag(mut vector_data: VectorData, amplitude: f64, frequency: f64) -> VectorData {
+	displace_resampled_points(&mut vector_data, frequency, 2, |_, index, sample| {
+		let side = if index % 2 == 0 { 1. } else { -1. };
+		sample.point + sample.tangent.perp() * amplitude * side
+	});
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TwistNode<Angle, Frequency> {
+	angle: Angle,
+	frequency: Frequency,
+}
+
+/// Resamples the paths with the given frequency of points along every 100 units of length, then rotates each point around the center of the bounding box.
+/// Points at the center are rotated by the angle in degrees, decreasing to no rotation at the corners of the bounding box.
+#[node_macro::node_fn(TwistNode)]
+fn twist(mut vector_data: VectorData, angle: f64, frequency: f64) -> VectorData {
+	let Some([min, max]) = vector_data.bounding_box_with_transform(vector_data.transform) else {
+		return vector_data;
+	};
+	let center = (min + max) / 2.;
+	let radius = max.distance(center);
+	if radius <= 0. {
+		return vector_data;
+	}
+
+	displace_resampled_points(&mut vector_data, frequency, 1, |_, _, sample| {
+		let offset = sample.point - center;
+		let falloff = (1. - offset.length() / radius).max(0.);
+		center + DVec2::from_angle(angle.to_radians() * falloff).rotate(offset)
+	});
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PuckerBloatNode<Amount, Frequency> {
+	amount: Amount,
+	frequency: Frequency,
+}
+
+/// Resamples the paths with the given frequency of points along every 100 units of length, then curves the segments between them outward and pulls the points inward toward the center
+/// of the bounding box, by the amount as a percentage of their distance from the center. A negative amount puckers the paths instead, curving the segments inward and pushing the points outward.
+#[node_macro::node_fn(PuckerBloatNode)]
+fn pucker_bloat(mut vector_data: VectorData, amount: f64, frequency: f64) -> VectorData {
+	if frequency <= 0. || !frequency.is_finite() {
+		return vector_data;
+	}
+	let Some([min, max]) = vector_data.bounding_box_with_transform(vector_data.transform) else {
+		return vector_data;
+	};
+	let center = (min + max) / 2.;
+	let factor = amount / 100.;
+
+	// The segments are straightened first, so their handles can be moved away from the center as the points are moved toward it
+	displace_resampled_points(&mut vector_data, frequency, 1, |_, _, sample| sample.point);
+
+	let transform = vector_data.transform;
+	for subpath in &mut vector_data.subpaths {
+		subpath.apply_transform(transform);
+		let anchors = subpath.anchors();
+		let count = anchors.len();
+		let closed = subpath.closed();
+		let manipulator_groups = subpath
+			.manipulator_groups()
+			.iter()
+			.enumerate()
+			.map(|(index, group)| {
+				let previous = (index > 0 || closed).then(|| anchors[(index + count - 1) % count]);
+				let next = (index + 1 < count || closed).then(|| anchors[(index + 1) % count]);
+				let handle = |neighbor: DVec2| {
+					let handle = group.anchor.lerp(neighbor, 1. / 3.);
+					handle + (handle - center) * factor
+				};
+				let anchor = group.anchor - (group.anchor - center) * factor;
+				ManipulatorGroup::new_with_id(anchor, previous.map(handle), next.map(handle), group.id)
+			})
+			.collect();
+		*subpath = Subpath::new(manipulator_groups, closed);
+		subpath.apply_transform(transform.inverse());
+	}
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SimplifyPathNode<Tolerance, CornerAngle> {
+	tolerance: Tolerance,
+	corner_angle: CornerAngle,
+}
+
+#[node_macro::node_fn(SimplifyPathNode)]
+fn simplify_path(mut vector_data: VectorData, tolerance: f64, corner_angle: f64) -> VectorData {
+	for subpath in &mut vector_data.subpaths {
+		if subpath.len_segments() == 0 || tolerance <= 0. || !tolerance.is_finite() {
+			continue;
+		}
+
+		subpath.apply_transform(vector_data.transform);
+
+		// Sample each segment densely enough that the fitted curve can follow its shape within the tolerance
+		let mut points: Vec<DVec2> = subpath
+			.iter()
+			.flat_map(|bezier| {
+				let steps = (bezier.length(None) / tolerance).ceil().clamp(1., 100.) as usize;
+				bezier.compute_lookup_table(Some(steps), None).into_iter().take(steps)
+			})
+			.collect();
+		points.extend(subpath.iter().last().map(|bezier| bezier.end()));
+
+		*subpath = if subpath.closed() {
+			// Closed subpaths are fitted as a loop so that the curve stays smooth where it returns to its first anchor
+			Subpath::new_fitted_closed_curve(&points, tolerance, Some(corner_angle.to_radians()))
+		} else {
+			Subpath::new_fitted_curve(&points, tolerance, Some(corner_angle.to_radians()))
+		};
+
+		subpath.apply_transform(vector_data.transform.inverse());
+	}
+	vector_data.retain_existing_ids();
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReduceSegmentsNode<Tolerance> {
+	tolerance: Tolerance,
+}
+
+#[node_macro::node_fn(ReduceSegmentsNode)]
+fn reduce_segments(mut vector_data: VectorData, tolerance: f64) -> VectorData {
+	for subpath in &mut vector_data.subpaths {
+		subpath.apply_transform(vector_data.transform);
+		*subpath = subpath.simplify(tolerance);
+		subpath.apply_transform(vector_data.transform.inverse());
+	}
+	vector_data.retain_existing_ids();
 	vector_data
 }
 
@@ -182,6 +557,727 @@ fn spline_from_points(mut vector_data: VectorData) -> VectorData {
 	for subpath in &mut vector_data.subpaths {
 		*subpath = Subpath::new_cubic_spline(subpath.anchors());
 	}
+	vector_data.retain_existing_ids();
+
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DashStrokeNode {}
+
+#[node_macro::node_fn(DashStrokeNode)]
+fn dash_stroke(mut vector_data: VectorData) -> VectorData {
+	let Some(mut stroke) = vector_data.style.stroke() else { return vector_data };
+	if stroke.dash_lengths.is_empty() {
+		return vector_data;
+	}
+
+	// The stroke is rendered after the transform is applied, so the dashes are measured in that space
+	let dash_lengths: Vec<f64> = stroke.dash_lengths.iter().map(|&length| length as f64).collect();
+	let transform = vector_data.transform;
+	vector_data.subpaths = vector_data
+		.subpaths
+		.iter()
+		.flat_map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform);
+			subpath.dash(&dash_lengths, stroke.dash_offset)
+		})
+		.map(|mut dash| {
+			dash.apply_transform(transform.inverse());
+			dash
+		})
+		.collect();
+
+	// The dashes are now part of the geometry, so the stroke no longer needs to draw them
+	stroke.dash_lengths.clear();
+	stroke.dash_offset = 0.;
+	vector_data.style.set_stroke(stroke);
+	// Each dash is an open subpath, which would otherwise be filled as a sliver closed off by a chord
+	vector_data.style.clear_fill();
+
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OutlineStrokeNode {}
+
+#[node_macro::node_fn(OutlineStrokeNode)]
+fn outline_stroke(mut vector_data: VectorData) -> VectorData {
+	let Some(stroke) = vector_data.style.stroke() else { return vector_data };
+
+	let join = match stroke.line_join {
+		LineJoin::Miter => Join::Miter(Some(stroke.line_join_miter_limit)),
+		LineJoin::Bevel => Join::Bevel,
+		LineJoin::Round => Join::Round,
+	};
+	let cap = match stroke.line_cap {
+		LineCap::Butt => Cap::Butt,
+		LineCap::Round => Cap::Round,
+		LineCap::Square => Cap::Square,
+	};
+	let dash_lengths: Vec<f64> = stroke.dash_lengths.iter().map(|&length| length as f64).collect();
+
+	// The stroke is rendered after the transform is applied, so its width and dashes are measured in that space
+	let transform = vector_data.transform;
+	let mut outlines = Vec::new();
+	if stroke.weight > 0. {
+		for subpath in &vector_data.subpaths {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform);
+			if subpath.is_empty() {
+				continue;
+			}
+
+			for dash in subpath.dash(&dash_lengths, stroke.dash_offset) {
+				let (outline, inner_outline) = dash.outline(stroke.weight / 2., join, cap);
+				outlines.push(outline);
+				outlines.extend(inner_outline);
+			}
+		}
+	}
+
+	// Merge the overlapping outlines of the dashes, joins and self-intersections into shapes that don't overlap,
+	// then remove the many short segments left over where the outlines of neighboring joins and segments nearly coincide
+	let merged = Subpath::boolean_operation(&outlines, &[], bezier_rs::BooleanOperation::Union, bezier_rs::FillRule::NonZero);
+	vector_data.subpaths = merged
+		.iter()
+		.map(|subpath| {
+			let mut subpath = subpath.simplify(stroke.weight * 1e-3);
+			subpath.apply_transform(transform.inverse());
+			subpath
+		})
+		.collect();
+	vector_data.mirror_angle.clear();
+
+	vector_data.style.set_fill(stroke.color.map_or(Fill::None, Fill::Solid));
+	vector_data.style.clear_stroke();
+
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetPathNode<Distance, LineJoin, MiterLimit> {
+	distance: Distance,
+	line_join: LineJoin,
+	miter_limit: MiterLimit,
+}
+
+/// Returns whether the fill lies to the left of the closed subpath (with +y as up), judged just beside the middle of its first segment,
+/// or `None` if the subpath doesn't separate a filled area from an unfilled one there.
+/// The fill is sampled at a distance from the subpath that is a [FILL_SAMPLE_RELATIVE_DISTANCE] fraction of its size, so it works for shapes of any scale.
+fn fill_lies_left(subpath: &Subpath<ManipulatorGroupId>, is_filled: impl Fn(DVec2) -> bool) -> Option<bool> {
+	let bezier = subpath.iter().find(|bezier| !bezier.is_point())?;
+	let point = bezier.evaluate(TValue::Parametric(0.5));
+	let tangent = bezier
+		.tangent(TValue::Parametric(0.5))
+		.try_normalize()
+		.unwrap_or_else(|| (bezier.end() - bezier.start()).normalize_or_zero());
+	let size = subpath.bounding_box().map_or(0., |[min, max]| (max - min).max_element());
+	let normal = tangent.perp() * size * FILL_SAMPLE_RELATIVE_DISTANCE;
+	match (is_filled(point + normal), is_filled(point - normal)) {
+		(true, false) => Some(true),
+		(false, true) => Some(false),
+		_ => None,
+	}
+}
+
+/// Moves every subpath the given distance away from itself, measured in the space containing the vector data, with the corners that open up joined in the given style.
+/// Closed subpaths are moved relative to the fill of the whole shape: with a positive distance, the outer contours grow and the holes shrink, and a negative distance does the opposite.
+/// The offset contours are combined into the union of the areas they fill, and the loops left behind where a shrinking shape pinches off are removed.
+/// Open subpaths are moved in the direction of their normals with a positive distance.
+#[node_macro::node_fn(OffsetPathNode)]
+fn offset_path(mut vector_data: VectorData, distance: f64, line_join: LineJoin, miter_limit: f64) -> VectorData {
+	if distance == 0. || !distance.is_finite() {
+		return vector_data;
+	}
+
+	let join = match line_join {
+		LineJoin::Miter => Join::Miter(Some(miter_limit)),
+		LineJoin::Bevel => Join::Bevel,
+		LineJoin::Round => Join::Round,
+	};
+
+	let transform = vector_data.transform;
+	let subpaths: Vec<_> = vector_data
+		.subpaths
+		.iter()
+		.filter(|subpath| subpath.len_segments() > 0)
+		.map(|subpath| {
+			let mut subpath = subpath.clone();
+			subpath.apply_transform(transform);
+			subpath
+		})
+		.collect();
+	let fill_rule: bezier_rs::FillRule = vector_data.style.fill_rule().into();
+	let is_filled = |point: DVec2| fill_rule.is_inside(subpaths.iter().filter(|subpath| subpath.closed()).map(|subpath| subpath.winding_number(point)).sum());
+
+	let mut offset_subpaths = Vec::with_capacity(subpaths.len());
+	let mut offset_contours = Vec::new();
+	for subpath in &subpaths {
+		if !subpath.closed() {
+			offset_subpaths.push(subpath.offset(distance, join));
+			continue;
+		}
+
+		// The normals of a subpath point to its left (with +y as up), so a contour with the fill on its left is offset backwards to move away from the fill.
+		// Each offset contour is wound to keep the fill on its left, so the areas they fill all have a positive winding number.
+		let fill_left = fill_lies_left(subpath, is_filled).unwrap_or_else(|| subpath.signed_area() > 0.);
+		let offset = subpath.offset(if fill_left { -distance } else { distance }, join);
+		offset_contours.push(if fill_left { offset } else { offset.reverse() });
+	}
+	offset_subpaths.extend(Subpath::resolve_intersections(&offset_contours, true));
 
+	vector_data.subpaths = offset_subpaths
+		.into_iter()
+		.map(|mut subpath| {
+			subpath.apply_transform(transform.inverse());
+			subpath
+		})
+		.collect();
+	vector_data.retain_existing_ids();
 	vector_data
 }
+
+/// The way in which the filled areas of the shapes are combined by the [BooleanOperationNode].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum BooleanOperation {
+	/// The area covered by any of the shapes.
+	Union,
+	/// The area of the bottom shape with the shapes in front of it removed.
+	SubtractFront,
+	/// The area of the top shape with the shapes behind it removed.
+	SubtractBack,
+	/// The area covered by all of the shapes.
+	Intersection,
+	/// The area covered by an odd number of the shapes.
+	Difference,
+}
+
+impl BooleanOperation {
+	pub fn list() -> [BooleanOperation; 5] {
+		[
+			BooleanOperation::Union,
+			BooleanOperation::SubtractFront,
+			BooleanOperation::SubtractBack,
+			BooleanOperation::Intersection,
+			BooleanOperation::Difference,
+		]
+	}
+}
+
+impl Display for BooleanOperation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			BooleanOperation::Union => write!(f, "Union"),
+			BooleanOperation::SubtractFront => write!(f, "Subtract Front"),
+			BooleanOperation::SubtractBack => write!(f, "Subtract Back"),
+			BooleanOperation::Intersection => write!(f, "Intersection"),
+			BooleanOperation::Difference => write!(f, "Difference"),
+		}
+	}
+}
+
+impl From<BooleanOperation> for bezier_rs::BooleanOperation {
+	fn from(operation: BooleanOperation) -> Self {
+		match operation {
+			BooleanOperation::Union => bezier_rs::BooleanOperation::Union,
+			BooleanOperation::SubtractFront | BooleanOperation::SubtractBack => bezier_rs::BooleanOperation::Difference,
+			BooleanOperation::Intersection => bezier_rs::BooleanOperation::Intersection,
+			BooleanOperation::Difference => bezier_rs::BooleanOperation::Xor,
+		}
+	}
+}
+
+/// Data which can be used as an input to the [BooleanOperationNode], providing the shapes to combine ordered from bottom to top.
+pub trait BooleanOperationInput {
+	/// Returns each of the shapes with its transform set to the one of the space that contains the input.
+	fn shapes(self) -> Vec<VectorData>;
+}
+
+impl BooleanOperationInput for VectorData {
+	fn shapes(self) -> Vec<VectorData> {
+		vec![self]
+	}
+}
+
+impl BooleanOperationInput for GraphicGroup {
+	fn shapes(self) -> Vec<VectorData> {
+		let transform = self.transform;
+		let mut shapes = Vec::new();
+		for element in self.iter().filter(|element| element.visible) {
+			let element_shapes = match &element.graphic_element_data {
+				GraphicElementData::VectorShape(vector_data) => vec![(**vector_data).clone()],
+				GraphicElementData::GraphicGroup(graphic_group) => graphic_group.clone().shapes(),
+				_ => Vec::new(),
+			};
+			shapes.extend(element_shapes.into_iter().map(|mut shape| {
+				shape.transform = transform * shape.transform;
+				shape
+			}));
+		}
+		shapes
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BooleanOperationNode<UpperVectorData, Operation> {
+	upper_vector_data: UpperVectorData,
+	operation: Operation,
+}
+
+/// Combines the shapes from both inputs, where the shapes of the lower input are below those of the upper input.
+/// Every shape after the bottom one is combined in turn with the result so far, and shapes without any subpaths (such as a disconnected input) are ignored.
+/// The result takes the style of the shape that is subtracted from, or the style of the top shape for the other operations.
+#[node_macro::node_fn(BooleanOperationNode)]
+fn boolean_operation_node<LowerVectorData: BooleanOperationInput, UpperVectorData: BooleanOperationInput>(
+	lower_vector_data: LowerVectorData,
+	upper_vector_data: UpperVectorData,
+	operation: BooleanOperation,
+) -> VectorData {
+	let mut shapes: Vec<VectorData> = lower_vector_data
+		.shapes()
+		.into_iter()
+		.chain(upper_vector_data.shapes())
+		.filter(|shape| !shape.subpaths.is_empty())
+		.collect();
+	// Subtracting the shapes behind the top shape is the same as subtracting the shapes in front of it with the order reversed
+	if operation == BooleanOperation::SubtractBack {
+		shapes.reverse();
+	}
+	let (Some(first), Some(last)) = (shapes.first(), shapes.last()) else {
+		return VectorData::empty();
+	};
+	let style = match operation {
+		BooleanOperation::SubtractFront | BooleanOperation::SubtractBack => first.style.clone(),
+		_ => last.style.clone(),
+	};
+
+	// Bring all shapes into the same space so they can be combined
+	let mut shapes = shapes.into_iter().map(|shape| {
+		let mut subpaths = shape.subpaths;
+		subpaths.iter_mut().for_each(|subpath| subpath.apply_transform(shape.transform));
+		(subpaths, shape.style.fill_rule())
+	});
+	let (first, first_fill_rule) = shapes.next().unwrap_or_default();
+
+	// Both shapes passed to a boolean operation are filled with the same rule, but results of boolean operations are filled the same way under either rule.
+	// So only the bottom shape needs to be converted when its fill rule differs from the one of the shape it is combined with.
+	let (subpaths, _) = shapes.fold((first, Some(first_fill_rule)), |(result, result_fill_rule), (subpaths, fill_rule)| {
+		let result = match result_fill_rule {
+			Some(result_fill_rule) if result_fill_rule != fill_rule => Subpath::boolean_operation(&result, &[], bezier_rs::BooleanOperation::Union, result_fill_rule.into()),
+			_ => result,
+		};
+		(Subpath::boolean_operation(&result, &subpaths, operation.into(), fill_rule.into()), None)
+	});
+
+	let mut vector_data = VectorData::from_subpaths(subpaths);
+	vector_data.style = style;
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SetAttributeNode<Name, Domain, Value> {
+	name: Name,
+	domain: Domain,
+	value: Value,
+}
+
+#[node_macro::node_fn(SetAttributeNode)]
+fn set_attribute<Value: Into<AttributeValue>>(mut vector_data: VectorData, name: String, domain: AttributeDomain, value: Value) -> VectorData {
+	if name.is_empty() {
+		return vector_data;
+	}
+
+	let value = value.into();
+	let ids: Vec<_> = vector_data.attribute_ids(domain).collect();
+	let channel = vector_data.attributes.channel_mut(&name, domain, value);
+	ids.into_iter().for_each(|id| channel.set(id, value));
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReadAttributeNode<Name, Index> {
+	name: Name,
+	index: Index,
+}
+
+/// Reads the value of an attribute at the manipulator group with the given index, counted across all subpaths, as a number.
+#[node_macro::node_fn(ReadAttributeNode)]
+fn read_attribute(vector_data: VectorData, name: String, index: u32) -> f64 {
+	let Some(channel) = vector_data.attributes.get(&name) else { return 0. };
+	let value = match vector_data.attribute_ids(channel.domain()).nth(index as usize) {
+		Some(id) => channel.get(id),
+		None => channel.default_value(),
+	};
+	value.as_number()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MapAttributeNode<Name, FromMin, FromMax, ToMin, ToMax, Clamp> {
+	name: Name,
+	from_min: FromMin,
+	from_max: FromMax,
+	to_min: ToMin,
+	to_max: ToMax,
+	clamp: Clamp,
+}
+
+/// Linearly remaps every component of an attribute's values from one range to another.
+#[node_macro::node_fn(MapAttributeNode)]
+fn map_attribute(mut vector_data: VectorData, name: String, from_min: f64, from_max: f64, to_min: f64, to_max: f64, clamp: bool) -> VectorData {
+	let Some(domain) = vector_data.attributes.get(&name).map(|channel| channel.domain()) else {
+		return vector_data;
+	};
+
+	let remap = |value: f64| {
+		let factor = if from_max == from_min { 0. } else { (value - from_min) / (from_max - from_min) };
+		let factor = if clamp { factor.clamp(0., 1.) } else { factor };
+		to_min + (to_max - to_min) * factor
+	};
+	let ids: Vec<_> = vector_data.attribute_ids(domain).collect();
+	let channel = vector_data.attributes.get_mut(&name).unwrap();
+	for id in ids {
+		let value = channel.get(id).map_components(remap);
+		channel.set(id, value);
+	}
+	vector_data
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MorphNode<Target, Time> {
+	target: Target,
+	time: Time,
+}
+
+/// Blends the shape and style of the vector data towards the target, where a time of 0 gives the input and 1 gives the target.
+///
+/// Both shapes are blended in the space containing the vector data, so the result has their transforms applied to its geometry.
+/// Subpaths are paired up in order, and subpaths without a partner grow out of or shrink into their center.
+/// Paired subpaths are given the same number of manipulator groups by splitting their longest segments,
+/// and closed subpaths are turned to run in the same direction from the best matching starting point.
+#[node_macro::node_fn(MorphNode)]
+fn morph(mut source: VectorData, mut target: VectorData, time: f64) -> VectorData {
+	let time = time.clamp(0., 1.);
+
+	// Subpaths without any manipulator groups have no shape to morph, so they are treated as missing
+	source.subpaths.retain(|subpath| !subpath.is_empty());
+	target.subpaths.retain(|subpath| !subpath.is_empty());
+
+	// Blending the geometry of inputs with different transforms in their own local spaces would distort every frame between them
+	for vector_data in [&mut source, &mut target] {
+		let transform = std::mem::replace(&mut vector_data.transform, DAffine2::IDENTITY);
+		vector_data.subpaths.iter_mut().for_each(|subpath| subpath.apply_transform(transform));
+	}
+
+	// Stand in for each missing subpath with its partner collapsed to a point at its center
+	let count = source.subpaths.len().max(target.subpaths.len());
+	for index in 0..count {
+		if index >= target.subpaths.len() {
+			target.subpaths.push(collapsed_subpath(&source.subpaths[index]));
+		} else if index >= source.subpaths.len() {
+			source.subpaths.push(collapsed_subpath(&target.subpaths[index]));
+		}
+	}
+
+	for index in 0..count {
+		if source.subpaths[index].closed() && target.subpaths[index].closed() && source.subpaths[index].is_clockwise() != target.subpaths[index].is_clockwise() {
+			target.subpaths[index] = target.subpaths[index].reverse();
+		}
+		let group_count = source.subpaths[index].len().max(target.subpaths[index].len());
+		for vector_data in [&mut source, &mut target] {
+			while vector_data.subpaths[index].len() < group_count {
+				let previous_count = vector_data.subpaths[index].len();
+				split_longest_segment(vector_data, index);
+				// Stop instead of looping forever if no group could be added, which leaves the extra groups of the partner unmatched
+				if vector_data.subpaths[index].len() <= previous_count {
+					break;
+				}
+			}
+		}
+		if source.subpaths[index].closed() && target.subpaths[index].closed() {
+			align_starting_point(&source.subpaths[index], &mut target.subpaths[index]);
+		}
+	}
+
+	let lerp_handle = |a: &ManipulatorGroup<ManipulatorGroupId>, a_handle: Option<DVec2>, b: &ManipulatorGroup<ManipulatorGroupId>, b_handle: Option<DVec2>| match (a_handle, b_handle) {
+		(None, None) => None,
+		_ => Some(a_handle.unwrap_or(a.anchor).lerp(b_handle.unwrap_or(b.anchor), time)),
+	};
+	let subpaths = source
+		.subpaths
+		.iter()
+		.zip(&target.subpaths)
+		.map(|(a, b)| {
+			let groups = a
+				.manipulator_groups()
+				.iter()
+				.zip(b.manipulator_groups())
+				.map(|(a, b)| {
+					ManipulatorGroup::new_with_id(
+						a.anchor.lerp(b.anchor, time),
+						lerp_handle(a, a.in_handle, b, b.in_handle),
+						lerp_handle(a, a.out_handle, b, b.out_handle),
+						a.id,
+					)
+				})
+				.collect();
+			Subpath::new(groups, if time < 0.5 { a.closed() } else { b.closed() })
+		})
+		.collect();
+
+	// Point attributes which both sides have are blended, and the others keep the values of the input
+	let mut attributes = source.attributes.clone();
+	let pairs: Vec<_> = source.manipulator_groups().zip(target.manipulator_groups()).map(|(a, b)| (a.id, b.id)).collect();
+	for (name, target_channel) in target.attributes.iter() {
+		let Some(channel) = attributes
+			.get_mut(name)
+			.filter(|channel| channel.domain() == AttributeDomain::Point && target_channel.domain() == AttributeDomain::Point)
+		else {
+			continue;
+		};
+		for &(a, b) in &pairs {
+			let value = channel.get(a).lerp(target_channel.get(b), time);
+			channel.set(a, value);
+		}
+	}
+
+	let mut result = VectorData {
+		subpaths,
+		transform: DAffine2::IDENTITY,
+		style: source.style.lerp(&target.style, time),
+		mirror_angle: source.mirror_angle,
+		attributes,
+	};
+	result.retain_existing_ids();
+	result
+}
+
+/// A copy of the subpath with every anchor and handle moved to the center of its bounding box.
+fn collapsed_subpath(subpath: &Subpath<ManipulatorGroupId>) -> Subpath<ManipulatorGroupId> {
+	let center = subpath.bounding_box().map_or(DVec2::ZERO, |[min, max]| (min + max) / 2.);
+	let groups = subpath
+		.manipulator_groups()
+		.iter()
+		.map(|group| ManipulatorGroup::new_with_id(center, group.in_handle.map(|_| center), group.out_handle.map(|_| center), ManipulatorGroupId::new()))
+		.collect();
+	Subpath::new(groups, subpath.closed())
+}
+
+/// Adds a manipulator group to a subpath of the vector data by splitting its longest segment in half.
+fn split_longest_segment(vector_data: &mut VectorData, subpath_index: usize) {
+	let subpath = &vector_data.subpaths[subpath_index];
+	let longest = subpath.iter().map(|bezier| bezier.length(None)).enumerate().max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+	if let Some((segment_index, _)) = longest {
+		if vector_data.split_segment(subpath_index, segment_index, 0.5).is_some() {
+			return;
+		}
+	}
+
+	// Without a segment that can be split, such as for a single point, a copy of the last anchor is added instead
+	let subpath = &vector_data.subpaths[subpath_index];
+	let Some(last) = subpath.manipulator_groups().last() else { return };
+	let index = subpath.len();
+	let copy = ManipulatorGroup::new_with_id(last.anchor, None, None, ManipulatorGroupId::new());
+	vector_data.insert_manipulator_group(subpath_index, index, copy, 1.);
+}
+
+/// Rotates the manipulator groups of a closed target subpath so they line up best with those of the source, comparing their offsets from the centers of the shapes.
+fn align_starting_point(source: &Subpath<ManipulatorGroupId>, target: &mut Subpath<ManipulatorGroupId>) {
+	let relative_anchors = |subpath: &Subpath<ManipulatorGroupId>| {
+		let anchors = subpath.anchors();
+		let center = anchors.iter().sum::<DVec2>() / anchors.len().max(1) as f64;
+		anchors.into_iter().map(|anchor| anchor - center).collect::<Vec<_>>()
+	};
+	let source_anchors = relative_anchors(source);
+	let target_anchors = relative_anchors(target);
+
+	let len = target_anchors.len();
+	let cost = |offset: usize| {
+		source_anchors
+			.iter()
+			.enumerate()
+			.map(|(index, anchor)| anchor.distance_squared(target_anchors[(index + offset) % len]))
+			.sum::<f64>()
+	};
+	let Some(best_offset) = (0..len).min_by(|&a, &b| cost(a).total_cmp(&cost(b))) else { return };
+
+	if best_offset != 0 {
+		let mut groups = target.manipulator_groups().to_vec();
+		groups.rotate_left(best_offset);
+		*target = Subpath::new(groups, true);
+	}
+}
+
+/// The shape of the curve which replaces each corner rounded by the [RoundCornersNode].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Hash, DynAny, specta::Type)]
+pub enum FilletType {
+	/// An arc of a circle with the given radius.
+	#[default]
+	Circular,
+	/// A curve which eases into the corner more gradually than a circular arc.
+	Smooth,
+}
+
+impl FilletType {
+	pub fn list() -> [FilletType; 2] {
+		[FilletType::Circular, FilletType::Smooth]
+	}
+}
+
+impl Display for FilletType {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FilletType::Circular => write!(f, "Circular"),
+			FilletType::Smooth => write!(f, "Smooth"),
+		}
+	}
+}
+
+impl From<FilletType> for bezier_rs::FilletType {
+	fn from(fillet_type: FilletType) -> Self {
+		match fillet_type {
+			FilletType::Circular => bezier_rs::FilletType::Circular,
+			FilletType::Smooth => bezier_rs::FilletType::Smooth,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RoundCornersNode<Radius, FilletType, MinAngle, SelectionAttribute> {
+	radius: Radius,
+	fillet_type: FilletType,
+	min_angle: MinAngle,
+	selection_attribute: SelectionAttribute,
+}
+
+/// Replaces the corners of the paths with fillets of the given radius, measured in the space containing the vector data.
+/// Only corners where the path turns by more than the minimum angle (in degrees) are rounded. If a selection attribute is named,
+/// only the anchors where that attribute is greater than one half are rounded.
+#[node_macro::node_fn(RoundCornersNode)]
+fn round_corners(mut vector_data: VectorData, radius: f64, fillet_type: FilletType, min_angle: f64, selection_attribute: String) -> VectorData {
+	if radius <= 0. || !radius.is_finite() {
+		return vector_data;
+	}
+
+	let selection = vector_data.attributes.get(&selection_attribute).filter(|channel| channel.domain() == AttributeDomain::Point);
+	let mut fillet_ends = Vec::new();
+	for subpath in &mut vector_data.subpaths {
+		subpath.apply_transform(vector_data.transform);
+
+		let ids: Vec<_> = subpath.manipulator_groups().iter().map(|group| group.id).collect();
+		let selected = |index: usize| match selection {
+			Some(channel) => channel.get(ids[index]).as_number() > 0.5,
+			None => true,
+		};
+		*subpath = subpath.round_corners(radius, fillet_type.into(), min_angle.to_radians(), selected);
+
+		// Each new manipulator group ends the fillet of the corner before it, so it takes on that corner's attributes
+		let groups = subpath.manipulator_groups();
+		for (index, group) in groups.iter().enumerate().filter(|(_, group)| !ids.contains(&group.id)) {
+			let corner = groups[(index + groups.len() - 1) % groups.len()].id;
+			fillet_ends.push((corner, group.id));
+		}
+
+		subpath.apply_transform(vector_data.transform.inverse());
+	}
+	for (corner, fillet_end) in fillet_ends {
+		vector_data.attributes.copy_id(corner, fillet_end);
+	}
+	vector_data.retain_existing_ids();
+	vector_data
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::value::ClonedNode;
+
+	#[test]
+	fn copies_get_their_own_ids_and_attributes() {
+		let mut instance = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		let ids: Vec<_> = instance.manipulator_groups().map(|group| group.id).collect();
+		let width = instance.attributes.channel_mut("width", AttributeDomain::Point, AttributeValue::Number(1.));
+		width.set(ids[0], 3.);
+		instance.mirror_angle.push(ids[0]);
+
+		let copies = instance.copies([DAffine2::IDENTITY, DAffine2::from_translation(DVec2::X * 2.)].into_iter());
+		let copy_ids: Vec<_> = copies.manipulator_groups().map(|group| group.id).collect();
+		assert_eq!(copy_ids.len(), 8);
+		assert!(copy_ids.iter().all(|id| !ids.contains(id)));
+		assert_eq!(copy_ids.iter().collect::<std::collections::HashSet<_>>().len(), 8);
+
+		let width = copies.attributes.get("width").unwrap();
+		assert_eq!(width.get(copy_ids[0]), AttributeValue::Number(3.));
+		assert_eq!(width.get(copy_ids[4]), AttributeValue::Number(3.));
+		assert_eq!(width.get(copy_ids[1]), AttributeValue::Number(1.));
+		assert_eq!(copies.mirror_angle, vec![copy_ids[0], copy_ids[4]]);
+		assert!(width.iter().all(|(id, _)| copy_ids.contains(&id)));
+	}
+
+	#[test]
+	fn morph_ignores_empty_subpaths() {
+		let triangle = Subpath::from_anchors([DVec2::ZERO, DVec2::X, DVec2::Y], true);
+		let source = VectorData::from_subpaths(vec![Subpath::new(Vec::new(), false), triangle]);
+		let target = VectorData::from_subpaths(vec![Subpath::new(Vec::new(), false), Subpath::new_ellipse(DVec2::ZERO, DVec2::splat(2.))]);
+
+		let morphed = MorphNode::new(ClonedNode::new(target), ClonedNode::new(0.5)).eval(source);
+		assert_eq!(morphed.subpaths.len(), 1);
+		assert_eq!(morphed.subpaths[0].len(), 4);
+		assert!(morphed.subpaths[0].closed());
+	}
+
+	#[test]
+	fn dashes_are_not_filled() {
+		let mut vector_data = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		vector_data.style.set_fill(Fill::Solid(Color::BLACK));
+		vector_data.style.set_stroke(Stroke::new(Some(Color::BLACK), 1.).with_dash_lengths("2 3").unwrap());
+
+		let dashed = DashStrokeNode {}.eval(vector_data);
+		assert_eq!(dashed.subpaths.len(), 8);
+		assert!(dashed.subpaths.iter().all(|subpath| !subpath.closed()));
+		assert_eq!(*dashed.style.fill(), Fill::None);
+		assert!(dashed.style.stroke().unwrap().dash_lengths.is_empty());
+	}
+
+	#[test]
+	fn morph_blends_in_the_space_containing_the_vector_data() {
+		let mut source = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+		source.transform = DAffine2::from_scale(DVec2::splat(100.));
+		let target = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::ONE));
+
+		let morphed = MorphNode::new(ClonedNode::new(target), ClonedNode::new(0.5)).eval(source);
+		let [min, max] = morphed.bounding_box_with_transform(morphed.transform).unwrap();
+		assert!(min.abs_diff_eq(DVec2::ZERO, 1e-9), "{min}");
+		assert!(max.abs_diff_eq(DVec2::splat(50.5), 1e-9), "{max}");
+	}
+
+	#[test]
+	fn resampled_point_count_is_limited() {
+		let line = VectorData::from_subpath(Subpath::from_anchors([DVec2::ZERO, DVec2::X * 1e6], false));
+		let roughened = RoughenNode::new(ClonedNode::new(1.), ClonedNode::new(1e9), ClonedNode::new(0)).eval(line);
+		assert_eq!(roughened.subpaths[0].len(), MAX_RESAMPLED_POINTS + 1);
+	}
+
+	#[test]
+	fn fillets_keep_the_attributes_of_their_corners() {
+		let mut square = VectorData::from_subpath(Subpath::new_rect(DVec2::ZERO, DVec2::splat(10.)));
+		let ids: Vec<_> = square.manipulator_groups().map(|group| group.id).collect();
+		let width = square.attributes.channel_mut("width", AttributeDomain::Point, AttributeValue::Number(1.));
+		for (index, &id) in ids.iter().enumerate() {
+			width.set(id, index as f64);
+		}
+
+		let rounded = RoundCornersNode::new(ClonedNode::new(2.), ClonedNode::new(FilletType::Circular), ClonedNode::new(0.), ClonedNode::new(String::new())).eval(square);
+		let groups = rounded.subpaths[0].manipulator_groups();
+		assert_eq!(groups.len(), 8);
+		let width = rounded.attributes.get("width").unwrap();
+		for (index, &id) in ids.iter().enumerate() {
+			assert_eq!(groups[index * 2].id, id);
+			assert_eq!(width.get(groups[index * 2 + 1].id), AttributeValue::Number(index as f64));
+		}
+	}
+}
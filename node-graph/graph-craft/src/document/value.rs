@@ -45,12 +45,23 @@ pub enum TaggedValue {
 	VecDVec2(Vec<DVec2>),
 	RedGreenBlue(graphene_core::raster::RedGreenBlue),
 	NoiseType(graphene_core::raster::NoiseType),
+	FractalType(graphene_core::raster::FractalType),
+	InterpolationMode(graphene_core::raster::interpolation::InterpolationMode),
 	RelativeAbsolute(graphene_core::raster::RelativeAbsolute),
 	SelectiveColorChoice(graphene_core::raster::SelectiveColorChoice),
 	LineCap(graphene_core::vector::style::LineCap),
 	LineJoin(graphene_core::vector::style::LineJoin),
 	FillType(graphene_core::vector::style::FillType),
+	FillRule(graphene_core::vector::style::FillRule),
 	GradientType(graphene_core::vector::style::GradientType),
+	BooleanOperation(graphene_core::vector::BooleanOperation),
+	FilletType(graphene_core::vector::FilletType),
+	EdgeDetectOperator(graphene_core::raster::filters::EdgeDetectOperator),
+	AttributeDomain(graphene_core::vector::AttributeDomain),
+	VectorAttributes(graphene_core::vector::VectorAttributes),
+	TextPathAlignment(graphene_core::text::TextPathAlignment),
+	TextPathOverflow(graphene_core::text::TextPathOverflow),
+	TextAlignment(graphene_core::text::TextAlignment),
 	GradientPositions(Vec<(f64, Option<graphene_core::Color>)>),
 	Quantization(graphene_core::quantization::QuantizationChannels),
 	OptionalColor(Option<graphene_core::raster::color::Color>),
@@ -106,12 +117,23 @@ impl Hash for TaggedValue {
 			Self::VecDVec2(vec_dvec2) => vec_dvec2.iter().for_each(|val| val.to_array().iter().for_each(|x| x.to_bits().hash(state))),
 			Self::RedGreenBlue(red_green_blue) => red_green_blue.hash(state),
 			Self::NoiseType(noise_type) => noise_type.hash(state),
+			Self::FractalType(fractal_type) => fractal_type.hash(state),
+			Self::InterpolationMode(interpolation_mode) => interpolation_mode.hash(state),
 			Self::RelativeAbsolute(relative_absolute) => relative_absolute.hash(state),
 			Self::SelectiveColorChoice(selective_color_choice) => selective_color_choice.hash(state),
 			Self::LineCap(line_cap) => line_cap.hash(state),
 			Self::LineJoin(line_join) => line_join.hash(state),
 			Self::FillType(fill_type) => fill_type.hash(state),
+			Self::FillRule(fill_rule) => fill_rule.hash(state),
 			Self::GradientType(gradient_type) => gradient_type.hash(state),
+			Self::BooleanOperation(boolean_operation) => boolean_operation.hash(state),
+			Self::FilletType(fillet_type) => fillet_type.hash(state),
+			Self::EdgeDetectOperator(edge_detect_operator) => edge_detect_operator.hash(state),
+			Self::AttributeDomain(attribute_domain) => attribute_domain.hash(state),
+			Self::VectorAttributes(attributes) => attributes.hash(state),
+			Self::TextPathAlignment(text_path_alignment) => text_path_alignment.hash(state),
+			Self::TextPathOverflow(text_path_overflow) => text_path_overflow.hash(state),
+			Self::TextAlignment(text_alignment) => text_alignment.hash(state),
 			Self::GradientPositions(gradient_positions) => {
 				gradient_positions.len().hash(state);
 				for (position, color) in gradient_positions {
@@ -174,12 +196,23 @@ impl<'a> TaggedValue {
 			TaggedValue::VecDVec2(x) => Box::new(x),
 			TaggedValue::RedGreenBlue(x) => Box::new(x),
 			TaggedValue::NoiseType(x) => Box::new(x),
+			TaggedValue::FractalType(x) => Box::new(x),
+			TaggedValue::InterpolationMode(x) => Box::new(x),
 			TaggedValue::RelativeAbsolute(x) => Box::new(x),
 			TaggedValue::SelectiveColorChoice(x) => Box::new(x),
 			TaggedValue::LineCap(x) => Box::new(x),
 			TaggedValue::LineJoin(x) => Box::new(x),
 			TaggedValue::FillType(x) => Box::new(x),
+			TaggedValue::FillRule(x) => Box::new(x),
 			TaggedValue::GradientType(x) => Box::new(x),
+			TaggedValue::BooleanOperation(x) => Box::new(x),
+			TaggedValue::FilletType(x) => Box::new(x),
+			TaggedValue::EdgeDetectOperator(x) => Box::new(x),
+			TaggedValue::AttributeDomain(x) => Box::new(x),
+			TaggedValue::VectorAttributes(x) => Box::new(x),
+			TaggedValue::TextPathAlignment(x) => Box::new(x),
+			TaggedValue::TextPathOverflow(x) => Box::new(x),
+			TaggedValue::TextAlignment(x) => Box::new(x),
 			TaggedValue::GradientPositions(x) => Box::new(x),
 			TaggedValue::Quantization(x) => Box::new(x),
 			TaggedValue::OptionalColor(x) => Box::new(x),
@@ -243,12 +276,23 @@ impl<'a> TaggedValue {
 			TaggedValue::VecDVec2(_) => concrete!(Vec<DVec2>),
 			TaggedValue::RedGreenBlue(_) => concrete!(graphene_core::raster::RedGreenBlue),
 			TaggedValue::NoiseType(_) => concrete!(graphene_core::raster::NoiseType),
+			TaggedValue::FractalType(_) => concrete!(graphene_core::raster::FractalType),
+			TaggedValue::InterpolationMode(_) => concrete!(graphene_core::raster::interpolation::InterpolationMode),
 			TaggedValue::RelativeAbsolute(_) => concrete!(graphene_core::raster::RelativeAbsolute),
 			TaggedValue::SelectiveColorChoice(_) => concrete!(graphene_core::raster::SelectiveColorChoice),
 			TaggedValue::LineCap(_) => concrete!(graphene_core::vector::style::LineCap),
 			TaggedValue::LineJoin(_) => concrete!(graphene_core::vector::style::LineJoin),
 			TaggedValue::FillType(_) => concrete!(graphene_core::vector::style::FillType),
+			TaggedValue::FillRule(_) => concrete!(graphene_core::vector::style::FillRule),
 			TaggedValue::GradientType(_) => concrete!(graphene_core::vector::style::GradientType),
+			TaggedValue::BooleanOperation(_) => concrete!(graphene_core::vector::BooleanOperation),
+			TaggedValue::FilletType(_) => concrete!(graphene_core::vector::FilletType),
+			TaggedValue::EdgeDetectOperator(_) => concrete!(graphene_core::raster::filters::EdgeDetectOperator),
+			TaggedValue::AttributeDomain(_) => concrete!(graphene_core::vector::AttributeDomain),
+			TaggedValue::VectorAttributes(_) => concrete!(graphene_core::vector::VectorAttributes),
+			TaggedValue::TextPathAlignment(_) => concrete!(graphene_core::text::TextPathAlignment),
+			TaggedValue::TextPathOverflow(_) => concrete!(graphene_core::text::TextPathOverflow),
+			TaggedValue::TextAlignment(_) => concrete!(graphene_core::text::TextAlignment),
 			TaggedValue::GradientPositions(_) => concrete!(Vec<(f64, Option<graphene_core::Color>)>),
 			TaggedValue::Quantization(_) => concrete!(graphene_core::quantization::QuantizationChannels),
 			TaggedValue::OptionalColor(_) => concrete!(Option<graphene_core::Color>),
@@ -301,12 +345,23 @@ impl<'a> TaggedValue {
 			x if x == TypeId::of::<Vec<DVec2>>() => Ok(TaggedValue::VecDVec2(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::RedGreenBlue>() => Ok(TaggedValue::RedGreenBlue(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::NoiseType>() => Ok(TaggedValue::NoiseType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::FractalType>() => Ok(TaggedValue::FractalType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::interpolation::InterpolationMode>() => Ok(TaggedValue::InterpolationMode(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::RelativeAbsolute>() => Ok(TaggedValue::RelativeAbsolute(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::raster::SelectiveColorChoice>() => Ok(TaggedValue::SelectiveColorChoice(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineCap>() => Ok(TaggedValue::LineCap(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::LineJoin>() => Ok(TaggedValue::LineJoin(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::FillType>() => Ok(TaggedValue::FillType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::style::FillRule>() => Ok(TaggedValue::FillRule(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::vector::style::GradientType>() => Ok(TaggedValue::GradientType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::BooleanOperation>() => Ok(TaggedValue::BooleanOperation(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::FilletType>() => Ok(TaggedValue::FilletType(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::raster::filters::EdgeDetectOperator>() => Ok(TaggedValue::EdgeDetectOperator(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::AttributeDomain>() => Ok(TaggedValue::AttributeDomain(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::vector::VectorAttributes>() => Ok(TaggedValue::VectorAttributes(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::TextPathAlignment>() => Ok(TaggedValue::TextPathAlignment(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::TextPathOverflow>() => Ok(TaggedValue::TextPathOverflow(*downcast(input).unwrap())),
+			x if x == TypeId::of::<graphene_core::text::TextAlignment>() => Ok(TaggedValue::TextAlignment(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Vec<(f64, Option<graphene_core::Color>)>>() => Ok(TaggedValue::GradientPositions(*downcast(input).unwrap())),
 			x if x == TypeId::of::<graphene_core::quantization::QuantizationChannels>() => Ok(TaggedValue::Quantization(*downcast(input).unwrap())),
 			x if x == TypeId::of::<Option<graphene_core::Color>>() => Ok(TaggedValue::OptionalColor(*downcast(input).unwrap())),
@@ -2,7 +2,8 @@ use dyn_any::{DynAny, StaticType};
 use glam::{DAffine2, DVec2, Vec2};
 use graph_craft::imaginate_input::{ImaginateController, ImaginateMaskStartingFill, ImaginateSamplingMethod};
 use graph_craft::proto::DynFuture;
-use graphene_core::raster::{Alpha, BlendMode, BlendNode, Image, ImageFrame, Linear, LinearChannel, Luminance, NoiseType, Pixel, RGBMut, Raster, RasterMut, RedGreenBlue, Sample};
+use graphene_core::raster::interpolation::InterpolationMode;
+use graphene_core::raster::{Alpha, BlendMode, BlendNode, FractalType, Image, ImageFrame, Linear, LinearChannel, Luminance, NoiseType, Pixel, RGBMut, Raster, RasterMut, RedGreenBlue, Sample};
 use graphene_core::transform::{Footprint, Transform};
 
 use crate::wasm_application_io::WasmEditorApi;
@@ -58,12 +59,16 @@ fn buffer_node<R: std::io::Read>(reader: R) -> Result<Vec<u8>, Error> {
 	Ok(std::io::Read::bytes(reader).collect::<Result<Vec<_>, _>>()?)
 }
 
-pub struct SampleNode<ImageFrame> {
+pub struct SampleNode<ImageFrame, Interpolation> {
 	image_frame: ImageFrame,
+	interpolation: Interpolation,
 }
 
+/// Crops the image to the part that is visible in the footprint and resamples it to the resolution it is displayed at, scaling it down when zoomed out and up when zoomed in.
+///
+/// The interpolation mode is chosen per image, with [`InterpolationMode::Auto`] picking a filter based on the quality requested by the footprint.
 #[node_macro::node_fn(SampleNode)]
-fn sample(footprint: Footprint, image_frame: ImageFrame<Color>) -> ImageFrame<Color> {
+fn sample(footprint: Footprint, image_frame: ImageFrame<Color>, interpolation: InterpolationMode) -> ImageFrame<Color> {
 	// resize the image using the image crate
 	let image = image_frame.image;
 	let data = bytemuck::cast_vec(image.data);
@@ -76,7 +81,7 @@ fn sample(footprint: Footprint, image_frame: ImageFrame<Color>) -> ImageFrame<Co
 	let size_px = image_size.transform_vector2(size).as_uvec2();
 
 	// If the image would not be visible, return an empty image
-	if size.x <= 0. || size.y <= 0. {
+	if size.x <= 0. || size.y <= 0. || size_px.x == 0 || size_px.y == 0 {
 		return ImageFrame::empty();
 	}
 
@@ -89,15 +94,17 @@ fn sample(footprint: Footprint, image_frame: ImageFrame<Color>) -> ImageFrame<Co
 
 	let viewport_resolution_x = footprint.transform.transform_vector2(DVec2::X * size.x).length();
 	let viewport_resolution_y = footprint.transform.transform_vector2(DVec2::Y * size.y).length();
-	let mut nwidth = size_px.x;
-	let mut nheight = size_px.y;
-
-	// Only downscale the image for now
-	let resized = if nwidth < image.width || nheight < image.height {
-		nwidth = viewport_resolution_x as u32;
-		nheight = viewport_resolution_y as u32;
-		// TODO: choose filter based on quality reqirements
-		cropped.resize_exact(nwidth, nheight, image::imageops::Triangle)
+	let nwidth = (viewport_resolution_x.round() as u32).max(1);
+	let nheight = (viewport_resolution_y.round() as u32).max(1);
+
+	let resized = if nwidth != size_px.x || nheight != size_px.y {
+		let filter = match interpolation.for_quality(footprint.quality) {
+			InterpolationMode::NearestNeighbor => image::imageops::Nearest,
+			InterpolationMode::Bilinear => image::imageops::Triangle,
+			InterpolationMode::Bicubic => image::imageops::CatmullRom,
+			InterpolationMode::Auto | InterpolationMode::Lanczos => image::imageops::Lanczos3,
+		};
+		cropped.resize_exact(nwidth, nheight, filter)
 	} else {
 		cropped
 	};
@@ -556,7 +563,8 @@ fn pixel_noise(width: u32, height: u32, seed: u32, noise_type: NoiseType) -> gra
 		for x in 0..width {
 			let pixel = image.get_pixel_mut(x, y).unwrap();
 			let luminance = match noise_type {
-				NoiseType::WhiteNoise => rng.gen_range(0.0..1.0) as f32,
+				// Coherent noise sampled once per pixel would only hit its lattice points, where it is flat, so it's left to the Noise Pattern node which gives it a feature scale
+				NoiseType::WhiteNoise | NoiseType::Perlin | NoiseType::Simplex | NoiseType::Worley => rng.gen_range(0.0..1.0) as f32,
 			};
 			*pixel = Color::from_luminance(luminance);
 		}
@@ -567,6 +575,68 @@ fn pixel_noise(width: u32, height: u32, seed: u32, noise_type: NoiseType) -> gra
 	}
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct NoisePatternNode<Dimensions, Seed, Scale, NoiseType, FractalType, Octaves, Tileable> {
+	dimensions: Dimensions,
+	seed: Seed,
+	scale: Scale,
+	noise_type: NoiseType,
+	fractal_type: FractalType,
+	octaves: Octaves,
+	tileable: Tileable,
+}
+
+/// Renders procedural noise covering a rectangle of the given dimensions in layer space, with features about `scale` units across.
+///
+/// The noise is evaluated for each pixel of the visible part of the rectangle at the footprint's resolution, so it stays sharp when zoomed in instead of magnifying pixels.
+/// The octaves are fixed by the input, so zooming in doesn't add detail beyond the finest of them.
+/// When tileable, the number of cells is rounded to fit the rectangle exactly, so the rectangle repeats seamlessly when placed next to copies of itself.
+#[node_macro::node_fn(NoisePatternNode)]
+fn noise_pattern(footprint: Footprint, dimensions: DVec2, seed: u32, scale: f64, noise_type: NoiseType, fractal_type: FractalType, octaves: u32, tileable: bool) -> ImageFrame<Color> {
+	let viewport_bounds = footprint.viewport_bounds_in_local_space();
+
+	let image_bounds = Bbox::from_transform(DAffine2::from_scale(dimensions)).to_axis_aligned_bbox();
+	let intersection = viewport_bounds.intersect(&image_bounds);
+	let size = intersection.size();
+
+	// If the noise would not be visible, return an empty image
+	if size.x <= 0. || size.y <= 0. || scale <= 0. {
+		return ImageFrame::empty();
+	}
+
+	let offset = intersection.start;
+
+	let width = footprint.transform.transform_vector2(DVec2::X * size.x).length() as u32;
+	let height = footprint.transform.transform_vector2(DVec2::Y * size.y).length() as u32;
+	if width == 0 || height == 0 {
+		return ImageFrame::empty();
+	}
+
+	let (cell_size, period) = if tileable {
+		let cells = (dimensions / scale).round().max(DVec2::ONE);
+		(dimensions / cells, Some(cells.as_ivec2()))
+	} else {
+		(DVec2::splat(scale), None)
+	};
+
+	let pixel_size = size / DVec2::new(width as f64, height as f64);
+	let mut data = Vec::with_capacity(width as usize * height as usize);
+	for y in 0..height {
+		for x in 0..width {
+			let position = offset + (DVec2::new(x as f64, y as f64) + 0.5) * pixel_size;
+			let value = graphene_core::raster::noise::fractal(noise_type, fractal_type, octaves, position / cell_size, seed, period);
+
+			// Treat the noise as perceptual lightness so that its midpoint looks like middle gray
+			data.push(Color::from_luminance(value as f32).to_linear_srgb());
+		}
+	}
+
+	ImageFrame {
+		image: Image { width, height, data },
+		transform: DAffine2::from_translation(offset) * DAffine2::from_scale(size),
+	}
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct MandelbrotNode;
 
@@ -630,6 +700,35 @@ fn map_color(iter: usize, max_iter: usize) -> Color {
 
 #[cfg(test)]
 mod test {
+	use super::*;
+	use graphene_core::value::ClonedNode;
+
+	#[test]
+	fn sample_upsamples_to_the_viewport_resolution() {
+		let image = Image {
+			width: 2,
+			height: 1,
+			data: vec![Color::BLACK, Color::WHITE],
+		};
+		let image_frame = ImageFrame { image, transform: DAffine2::IDENTITY };
+		let footprint = Footprint {
+			transform: DAffine2::from_scale(DVec2::new(8., 4.)),
+			..Default::default()
+		};
+
+		let nearest = SampleNode::new(ClonedNode::new(image_frame.clone()), ClonedNode::new(InterpolationMode::NearestNeighbor)).eval(footprint);
+		assert_eq!((nearest.image.width, nearest.image.height), (8, 4));
+		assert_eq!(nearest.transform, DAffine2::IDENTITY);
+		for row in nearest.image.data.chunks(8) {
+			assert_eq!(row[..4], [Color::BLACK; 4]);
+			assert_eq!(row[4..], [Color::WHITE; 4]);
+		}
+
+		// Filtering blends the two pixels where they meet, which nearest-neighbor must not do
+		let bilinear = SampleNode::new(ClonedNode::new(image_frame), ClonedNode::new(InterpolationMode::Bilinear)).eval(footprint);
+		assert_eq!((bilinear.image.width, bilinear.image.height), (8, 4));
+		assert!(bilinear.image.data[3] != Color::BLACK && bilinear.image.data[3] != Color::WHITE);
+	}
 
 	#[test]
 	fn load_image() {
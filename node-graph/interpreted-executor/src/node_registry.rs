@@ -444,6 +444,13 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::raster::IndexNode<_>, input: Vec<ImageFrame<Color>>, params: [u32]),
 		register_node!(graphene_core::raster::adjustments::ColorFillNode<_>, input: ImageFrame<Color>, params: [Color]),
 		register_node!(graphene_core::raster::adjustments::ColorOverlayNode<_, _, _>, input: ImageFrame<Color>, params: [Color, BlendMode, f32]),
+		register_node!(graphene_core::raster::blur::GaussianBlurNode<_>, input: ImageFrame<Color>, params: [f64]),
+		register_node!(graphene_core::raster::blur::BoxBlurNode<_>, input: ImageFrame<Color>, params: [f64]),
+		register_node!(graphene_core::raster::blur::MotionBlurNode<_, _>, input: ImageFrame<Color>, params: [f64, f64]),
+		register_node!(graphene_core::raster::filters::UnsharpMaskNode<_, _, _>, input: ImageFrame<Color>, params: [f32, f64, f32]),
+		register_node!(graphene_core::raster::filters::HighPassNode<_>, input: ImageFrame<Color>, params: [f64]),
+		register_node!(graphene_core::raster::filters::EdgeDetectNode<_>, input: ImageFrame<Color>, params: [graphene_core::raster::filters::EdgeDetectOperator]),
+		register_node!(graphene_core::raster::filters::EmbossNode<_, _>, input: ImageFrame<Color>, params: [f64, f32]),
 		vec![(
 			NodeIdentifier::new("graphene_core::raster::BlendNode<_, _, _, _>"),
 			|args| {
@@ -775,11 +782,14 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [ImageFrame<Color>]),
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: VectorData, params: [DAffine2]),
 		register_node!(graphene_core::transform::SetTransformNode<_>, input: ImageFrame<Color>, params: [DAffine2]),
-		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>]),
+		register_node!(graphene_core::raster::resample::ResampleImageNode<_, _, _>, input: ImageFrame<Color>, params: [DVec2, graphene_core::raster::interpolation::InterpolationMode, bool]),
+		register_node!(graphene_core::vector::SetFillNode<_, _, _, _, _, _, _, _>, input: VectorData, params: [graphene_core::vector::style::FillType, Option<graphene_core::Color>, graphene_core::vector::style::GradientType, DVec2, DVec2, DAffine2, Vec<(f64, Option<graphene_core::Color>)>, graphene_core::vector::style::FillRule]),
 		register_node!(graphene_core::vector::SetStrokeNode<_, _, _, _, _, _, _>, input: VectorData, params: [Option<graphene_core::Color>, f32, Vec<f32>, f32, graphene_core::vector::style::LineCap, graphene_core::vector::style::LineJoin, f32]),
 		register_node!(graphene_core::vector::RepeatNode<_, _>, input: VectorData, params: [DVec2, u32]),
 		register_node!(graphene_core::vector::BoundingBoxNode, input: VectorData, params: []),
 		register_node!(graphene_core::vector::CircularRepeatNode<_, _, _>, input: VectorData, params: [f32, f32, u32]),
+		register_node!(graphene_core::vector::CopyToPointsNode<_, _, _, _, _, _, _, _>, input: VectorData, params: [VectorData, bool, String, String, f64, f64, f64, u32]),
+		register_node!(graphene_core::vector::CopyToPointsNode<_, _, _, _, _, _, _, _>, input: VectorData, params: [GraphicGroup, bool, String, String, f64, f64, f64, u32]),
 		vec![(
 			NodeIdentifier::new("graphene_core::transform::CullNode<_>"),
 			|args| {
@@ -820,23 +830,53 @@ fn node_registry() -> HashMap<NodeIdentifier, HashMap<NodeIOTypes, NodeConstruct
 				node_io
 			},
 		)],
-		register_node!(graphene_std::raster::SampleNode<_>, input: Footprint, params: [ImageFrame<Color>]),
+		register_node!(graphene_std::raster::SampleNode<_, _>, input: Footprint, params: [ImageFrame<Color>, graphene_core::raster::interpolation::InterpolationMode]),
 		register_node!(graphene_std::raster::MandelbrotNode, input: Footprint, params: []),
+		register_node!(graphene_std::raster::NoisePatternNode<_, _, _, _, _, _, _>, input: Footprint, params: [DVec2, u32, f64, NoiseType, FractalType, u32, bool]),
 		register_node!(graphene_core::vector::ResamplePoints<_>, input: VectorData, params: [f64]),
+		register_node!(graphene_core::vector::RoughenNode<_, _, _>, input: VectorData, params: [f64, f64, u32]),
+		register_node!(graphene_core::vector::ZigZagNode<_, _>, input: VectorData, params: [f64, f64]),
+		register_node!(graphene_core::vector::TwistNode<_, _>, input: VectorData, params: [f64, f64]),
+		register_node!(graphene_core::vector::PuckerBloatNode<_, _>, input: VectorData, params: [f64, f64]),
+		register_node!(graphene_core::vector::SimplifyPathNode<_, _>, input: VectorData, params: [f64, f64]),
+		register_node!(graphene_core::vector::ReduceSegmentsNode<_>, input: VectorData, params: [f64]),
 		register_node!(graphene_core::vector::SplineFromPointsNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::DashStrokeNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::OutlineStrokeNode, input: VectorData, params: []),
+		register_node!(graphene_core::vector::BooleanOperationNode<_, _>, input: VectorData, params: [VectorData, graphene_core::vector::BooleanOperation]),
+		register_node!(graphene_core::vector::SetAttributeNode<_, _, _>, input: VectorData, params: [String, graphene_core::vector::AttributeDomain, f64]),
+		register_node!(graphene_core::vector::SetAttributeNode<_, _, _>, input: VectorData, params: [String, graphene_core::vector::AttributeDomain, DVec2]),
+		register_node!(graphene_core::vector::SetAttributeNode<_, _, _>, input: VectorData, params: [String, graphene_core::vector::AttributeDomain, Color]),
+		register_node!(graphene_core::vector::ReadAttributeNode<_, _>, input: VectorData, params: [String, u32]),
+		register_node!(graphene_core::vector::MapAttributeNode<_, _, _, _, _, _>, input: VectorData, params: [String, f64, f64, f64, f64, bool]),
+		register_node!(graphene_core::vector::BooleanOperationNode<_, _>, input: VectorData, params: [GraphicGroup, graphene_core::vector::BooleanOperation]),
+		register_node!(graphene_core::vector::BooleanOperationNode<_, _>, input: GraphicGroup, params: [VectorData, graphene_core::vector::BooleanOperation]),
+		register_node!(graphene_core::vector::BooleanOperationNode<_, _>, input: GraphicGroup, params: [GraphicGroup, graphene_core::vector::BooleanOperation]),
+		register_node!(graphene_core::vector::MorphNode<_, _>, input: VectorData, params: [VectorData, f64]),
+		register_node!(graphene_core::vector::RoundCornersNode<_, _, _, _>, input: VectorData, params: [f64, graphene_core::vector::FilletType, f64, String]),
+		register_node!(graphene_core::vector::OffsetPathNode<_, _, _>, input: VectorData, params: [f64, graphene_core::vector::style::LineJoin, f64]),
 		register_node!(graphene_core::vector::generator_nodes::CircleGenerator<_>, input: (), params: [f32]),
 		register_node!(graphene_core::vector::generator_nodes::EllipseGenerator<_, _>, input: (), params: [f32, f32]),
-		register_node!(graphene_core::vector::generator_nodes::RectangleGenerator<_, _>, input: (), params: [f32, f32]),
-		register_node!(graphene_core::vector::generator_nodes::RegularPolygonGenerator<_, _>, input: (), params: [u32, f32]),
-		register_node!(graphene_core::vector::generator_nodes::StarGenerator<_, _, _>, input: (), params: [u32, f32, f32]),
+		register_node!(graphene_core::vector::generator_nodes::RectangleGenerator<_, _, _>, input: (), params: [f32, f32, f32]),
+		register_node!(graphene_core::vector::generator_nodes::RegularPolygonGenerator<_, _, _>, input: (), params: [u32, f32, f32]),
+		register_node!(graphene_core::vector::generator_nodes::StarGenerator<_, _, _, _>, input: (), params: [u32, f32, f32, f32]),
 		register_node!(graphene_core::vector::generator_nodes::LineGenerator<_, _>, input: (), params: [DVec2, DVec2]),
 		register_node!(graphene_core::vector::generator_nodes::SplineGenerator<_>, input: (), params: [Vec<DVec2>]),
 		register_node!(
-			graphene_core::vector::generator_nodes::PathGenerator<_>,
+			graphene_core::vector::generator_nodes::PathGenerator<_, _>,
 			input: Vec<graphene_core::vector::bezier_rs::Subpath<graphene_core::uuid::ManipulatorGroupId>>,
-			params: [Vec<graphene_core::uuid::ManipulatorGroupId>]
+			params: [Vec<graphene_core::uuid::ManipulatorGroupId>, graphene_core::vector::VectorAttributes]
+		),
+		register_node!(
+			graphene_core::text::TextGenerator<_, _, _, _, _, _, _, _, _, _>,
+			input: WasmEditorApi,
+			params: [String, graphene_core::text::Font, f64, f64, f64, f64, graphene_core::text::TextAlignment, bool, bool, String]
+		),
+		register_node!(
+			graphene_core::text::TextOnPathGenerator<_, _, _, _, _, _, _>,
+			input: WasmEditorApi,
+			params: [String, graphene_core::text::Font, f64, VectorData, f64, graphene_core::text::TextPathAlignment, graphene_core::text::TextPathOverflow]
 		),
-		register_node!(graphene_core::text::TextGenerator<_, _, _>, input: WasmEditorApi, params: [String, graphene_core::text::Font, f64]),
 		register_node!(graphene_std::brush::VectorPointsNode, input: VectorData, params: []),
 		register_node!(graphene_core::ExtractImageFrame, input: WasmEditorApi, params: []),
 		async_node!(graphene_core::ConstructLayerNode<_, _, _, _, _, _, _, _>, input: Footprint, output: GraphicGroup, fn_params: [Footprint => graphene_core::GraphicElementData, () => String, () => BlendMode, () => f32,  () => bool, () => bool, () => bool, Footprint => GraphicGroup]),